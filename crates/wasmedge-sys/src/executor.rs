@@ -16,8 +16,27 @@ use crate::{
 };
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
 use std::os::raw::c_void;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
 use wasmedge_types::error::WasmEdgeError;
 
+/// The statistics of the executors, keyed by the address of the executor context. The runtime offers no way to get the statistics from an executor context, so the statistics are looked up here for the [calling frames](crate::CallingFrame) of host functions. An entry also keeps the statistics context alive as long as the executor refers to it.
+static EXECUTOR_STATISTICS: OnceLock<Mutex<HashMap<usize, Statistics>>> = OnceLock::new();
+
+fn executor_statistics() -> std::sync::MutexGuard<'static, HashMap<usize, Statistics>> {
+    EXECUTOR_STATISTICS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Returns the [statistics](crate::Statistics) the given executor context was created with.
+pub(crate) fn statistics_of(ctx: *const ffi::WasmEdge_ExecutorContext) -> Option<Statistics> {
+    executor_statistics().get(&(ctx as usize)).cloned()
+}
+
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
 pub(crate) struct JmpState {
     pub(crate) sigjmp_buf: *mut setjmp::sigjmp_buf,
@@ -94,6 +113,7 @@ pub struct Executor {
 impl Drop for Executor {
     fn drop(&mut self) {
         unsafe { ffi::WasmEdge_ExecutorDelete(self.inner.0) }
+        executor_statistics().remove(&(self.inner.0 as usize));
     }
 }
 
@@ -104,16 +124,17 @@ impl Executor {
     ///
     /// * `config` - The configuration of the new [executor](crate::Executor).
     ///
-    /// * `stat` - The [statistics](crate::Statistics) needed by the new [executor](crate::Executor).
+    /// * `stat` - The [statistics](crate::Statistics) needed by the new [executor](crate::Executor). The executor shares the statistics context with the given [Statistics](crate::Statistics) and its clones, so the data collected in execution can be read from any of them. Host functions reach the statistics through [CallingFrame::statistics](crate::CallingFrame::statistics).
     ///
     /// # Error
     ///
     /// If fail to create a [executor](crate::Executor), then an error is returned.
-    pub fn create(config: Option<&Config>, stat: Option<Statistics>) -> WasmEdgeResult<Self> {
+    pub fn create(config: Option<&Config>, stat: Option<&mut Statistics>) -> WasmEdgeResult<Self> {
         let conf_ctx = config
             .map(|cfg| cfg.inner.0)
             .unwrap_or(std::ptr::null_mut());
         let stat_ctx = stat
+            .as_ref()
             .map(|stat| stat.inner.0)
             .unwrap_or(std::ptr::null_mut());
 
@@ -136,6 +157,10 @@ impl Executor {
                 );
             }
 
+            if let Some(stat) = stat {
+                executor_statistics().insert(ctx as usize, stat.clone());
+            }
+
             Ok(Executor {
                 inner: InnerExecutor(ctx),
             })
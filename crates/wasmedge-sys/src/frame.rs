@@ -1,9 +1,10 @@
 //! Defines WasmEdge CallingFrame.
 
 use crate::{
+    executor::{statistics_of, InnerExecutor},
     ffi,
     instance::{memory::InnerMemory, InnerRef},
-    Memory,
+    Executor, Memory, Statistics,
 };
 
 /// Represents a calling frame on top of stack.
@@ -77,6 +78,35 @@ impl CallingFrame {
             }
         }
     }
+
+    /// Returns a mutable smart pointer borrowing the [executor](crate::Executor) running the current calling frame, through which a host function can call other functions. If the executor is not found, returns `None`.
+    ///
+    /// A function called this way runs on a new stack, so a host function called through it sees the module instance of that function instead of the current one.
+    pub fn executor_mut(&mut self) -> Option<InnerRef<Executor, &mut Self>> {
+        unsafe {
+            let ctx = ffi::WasmEdge_CallingFrameGetExecutor(self.inner.0);
+
+            if ctx.is_null() {
+                None
+            } else {
+                let executor = Executor {
+                    inner: InnerExecutor(ctx as _),
+                };
+                Some(InnerRef::create_from_mut(
+                    std::mem::ManuallyDrop::new(executor),
+                    self,
+                ))
+            }
+        }
+    }
+
+    /// Returns the [statistics](crate::Statistics) of the [executor](crate::Executor) running the current calling frame, or `None` if the executor is created without statistics.
+    ///
+    /// A host function can [charge](crate::Statistics::charge) the cost of the work it does through the returned statistics.
+    pub fn statistics(&self) -> Option<Statistics> {
+        let executor = unsafe { ffi::WasmEdge_CallingFrameGetExecutor(self.inner.0) };
+        statistics_of(executor)
+    }
 }
 
 #[derive(Debug)]
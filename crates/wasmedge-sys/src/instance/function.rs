@@ -5,6 +5,10 @@ use crate::{
     CallingFrame, Instance, WasmEdgeResult, WasmValue,
 };
 use core::ffi::c_void;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, OnceLock},
+};
 
 use wasmedge_types::{
     error::{CoreError, FuncError, WasmEdgeError},
@@ -30,6 +34,42 @@ pub type CustomFnWrapper = unsafe extern "C" fn(
     return_len: u32,
 ) -> ffi::WasmEdge_Result;
 
+/// The bindings of the host functions created by [Function::create_with_custom_wrapper], keyed by the address of the function instance context. The runtime offers no way to get the binding back from a function instance context, so it is looked up here by [Function::rebind].
+static FUNCTION_BINDINGS: OnceLock<Mutex<HashMap<usize, Binding>>> = OnceLock::new();
+
+#[derive(Clone, Copy)]
+struct Binding {
+    wrapper: CustomFnWrapper,
+    real_fn: usize,
+    data: usize,
+    cost: u64,
+}
+
+fn function_bindings() -> MutexGuard<'static, HashMap<usize, Binding>> {
+    FUNCTION_BINDINGS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Forgets the bindings of the host functions exported by the given module instance, which is about to be deleted together with its functions.
+pub(crate) fn forget_bindings(ctx: *const ffi::WasmEdge_ModuleInstanceContext) {
+    let mut bindings = function_bindings();
+    if bindings.is_empty() {
+        return;
+    }
+    unsafe {
+        let len = ffi::WasmEdge_ModuleInstanceListFunctionLength(ctx);
+        let mut names = Vec::with_capacity(len as usize);
+        ffi::WasmEdge_ModuleInstanceListFunction(ctx, names.as_mut_ptr(), len);
+        names.set_len(len as usize);
+        for name in names {
+            let func = ffi::WasmEdge_ModuleInstanceFindFunction(ctx, name);
+            bindings.remove(&(func as usize));
+        }
+    }
+}
+
 // Wrapper function for thread-safe scenarios.
 unsafe extern "C" fn wrap_fn<Data>(
     key_ptr: *mut c_void,
@@ -185,12 +225,50 @@ impl Function {
         if ctx.is_null() {
             Err(Box::new(WasmEdgeError::Func(FuncError::Create)))
         } else {
+            function_bindings().insert(
+                ctx as usize,
+                Binding {
+                    wrapper: fn_wrapper,
+                    real_fn: real_fn as usize,
+                    data: data as usize,
+                    cost,
+                },
+            );
             Ok(Self {
                 inner: InnerFunc(ctx),
             })
         }
     }
 
+    /// Creates a new [host function](crate::Function) which calls the same host function with the same host data as the given one, so that the same host function can be exported under another name or by another module instance. Unlike a host function calling the given one, the new function is called with the [calling frame](crate::CallingFrame) of its caller, so it reaches the memory of the calling module instance.
+    ///
+    /// Returns `None` if the given function is not a host function created by this crate, such as a wasm function, or a host function built into the runtime or a plugin.
+    ///
+    /// # Argument
+    ///
+    /// * `func` - The host function to rebind.
+    ///
+    /// # Safety
+    ///
+    /// The host data of the given function is shared, so the new function must not outlive the given one.
+    pub unsafe fn rebind(func: &impl AsFunc) -> Option<Self> {
+        let ctx = func.get_func_raw();
+        let binding = function_bindings().get(&(ctx as usize)).copied()?;
+        // a stale entry of a deleted function may share the address of another function
+        if ffi::WasmEdge_FunctionInstanceGetData(ctx) as usize != binding.data {
+            return None;
+        }
+        let ty = func.ty()?;
+        Self::create_with_custom_wrapper(
+            &ty,
+            binding.wrapper,
+            binding.real_fn as *mut c_void,
+            binding.data as *mut c_void,
+            binding.cost,
+        )
+        .ok()
+    }
+
     /// # Safety
     ///
     /// The lifetime of the returned pointer must not exceed that of the object itself.
@@ -209,6 +287,7 @@ impl Function {
 }
 impl Drop for Function {
     fn drop(&mut self) {
+        function_bindings().remove(&(self.inner.0 as usize));
         unsafe { ffi::WasmEdge_FunctionInstanceDelete(self.inner.0) };
     }
 }
@@ -9,6 +9,13 @@ use crate::{
 use wasmedge_types::error::{InstanceError, WasmEdgeError};
 
 use super::{function::AsFunc, InnerRef};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock, Weak},
+};
+
+/// The tokens handed out by [Instance::liveness], keyed by the context addresses of the module instances, which are removed when the instances are dropped.
+static LIVENESS_TOKENS: OnceLock<Mutex<HashMap<usize, Arc<()>>>> = OnceLock::new();
 
 /// An [Instance] represents an instantiated module. In the instantiation process, An [Instance] is created from al[Module](crate::Module). From an [Instance] the exported [functions](crate::Function), [tables](crate::Table), [memories](crate::Memory), and [globals](crate::Global) can be fetched.
 #[derive(Debug)]
@@ -17,11 +24,28 @@ pub struct Instance {
 }
 impl Drop for Instance {
     fn drop(&mut self) {
+        super::function::forget_bindings(self.inner.0);
+        if let Some(tokens) = LIVENESS_TOKENS.get() {
+            tokens
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&(self.inner.0 as usize));
+        }
         unsafe {
             ffi::WasmEdge_ModuleInstanceDelete(self.inner.0);
         }
     }
 }
+impl Instance {
+    /// Returns a weak token which can no longer be upgraded after this module instance is dropped, so that the code which hands out a module instance can observe its lifetime without keeping it.
+    pub fn liveness(&self) -> Weak<()> {
+        let mut tokens = LIVENESS_TOKENS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        Arc::downgrade(tokens.entry(self.inner.0 as usize).or_default())
+    }
+}
 impl AsInstance for Instance {
     unsafe fn as_ptr(&self) -> *const ffi::WasmEdge_ModuleInstanceContext {
         self.inner.0
@@ -87,6 +111,26 @@ pub trait AsInstance {
         }
     }
 
+    fn get_table_mut(&mut self, name: impl AsRef<str>) -> WasmEdgeResult<InnerRef<Table, &mut Self>>
+    where
+        Self: Sized,
+    {
+        let table_name: WasmEdgeString = name.as_ref().into();
+        let ctx =
+            unsafe { ffi::WasmEdge_ModuleInstanceFindTable(self.as_ptr(), table_name.as_raw()) };
+        match ctx.is_null() {
+            true => Err(Box::new(WasmEdgeError::Instance(
+                InstanceError::NotFoundTable(name.as_ref().to_string()),
+            ))),
+            false => {
+                let table = std::mem::ManuallyDrop::new(Table {
+                    inner: InnerTable(ctx),
+                });
+                Ok(unsafe { InnerRef::create_from_mut(table, self) })
+            }
+        }
+    }
+
     /// Returns the exported [memory instance](crate::Memory) by name.
     ///
     /// # Argument
@@ -389,6 +433,7 @@ pub struct ImportModule<T: ?Sized> {
 }
 impl<T: ?Sized> Drop for ImportModule<T> {
     fn drop(&mut self) {
+        super::function::forget_bindings(self.inner.0);
         unsafe {
             ffi::WasmEdge_ModuleInstanceDelete(self.inner.0);
         }
@@ -808,6 +853,19 @@ mod tests {
 
     #[test]
     #[allow(clippy::assertions_on_result_states)]
+    fn test_instance_liveness() {
+        let result = WasiModule::create(None, None, None);
+        assert!(result.is_ok());
+        let wasi = result.unwrap();
+        let token = wasi.as_ref().liveness();
+        assert!(token.upgrade().is_some());
+        assert!(wasi.as_ref().liveness().ptr_eq(&token));
+
+        drop(wasi);
+        assert!(token.upgrade().is_none());
+    }
+
+    #[test]
     fn test_instance_find_xxx() -> Result<(), Box<dyn std::error::Error>> {
         let module_name = "extern_module";
 
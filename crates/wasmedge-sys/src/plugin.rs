@@ -147,6 +147,276 @@ impl PluginManager {
             )
         }
     }
+
+    /// Returns the path of the WasmEdge library file this process uses, which is the executable itself if the library is linked statically.
+    #[cfg(unix)]
+    pub fn library_path() -> Option<std::path::PathBuf> {
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut info: libc::Dl_info = unsafe { std::mem::zeroed() };
+        let found = unsafe {
+            libc::dladdr(
+                ffi::WasmEdge_VersionGet as *const std::os::raw::c_void,
+                &mut info,
+            )
+        };
+        if found == 0 || info.dli_fname.is_null() {
+            return None;
+        }
+        let path = unsafe { std::ffi::CStr::from_ptr(info.dli_fname) };
+        Some(std::path::PathBuf::from(std::ffi::OsStr::from_bytes(
+            path.to_bytes(),
+        )))
+    }
+
+    /// Returns the metadata of the plugins defined with the C API, which are loaded into this process from the given path.
+    ///
+    /// The plugin libraries are not loaded by this method. A library file which is not loaded yet, or does not export the `WasmEdge_Plugin_GetDescriptor` symbol, is skipped.
+    ///
+    /// # Argument
+    ///
+    /// * `path` - A path to a plugin file or a directory holding plugin files.
+    #[cfg(unix)]
+    pub fn loaded_plugin_metadata(path: impl AsRef<std::path::Path>) -> Vec<PluginMetadata> {
+        Self::loaded_plugin_descriptors(path)
+            .into_iter()
+            .map(|(file, descriptor)| unsafe { PluginMetadata::from_raw(&*descriptor, file) })
+            .collect()
+    }
+
+    /// Sets the value of a typed program option of a plugin defined with the C API, which is loaded into this process from the given path.
+    ///
+    /// Returns `None` if no such plugin is loaded from the path. Otherwise, see [set_raw_program_option] for the returned value.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A path to a plugin file or a directory holding plugin files.
+    ///
+    /// * `plugin` - The name of the plugin.
+    ///
+    /// * `name` - The name of the program option.
+    ///
+    /// * `value` - The string representation of the value.
+    #[cfg(unix)]
+    pub fn set_loaded_plugin_option(
+        path: impl AsRef<std::path::Path>,
+        plugin: &str,
+        name: &str,
+        value: &str,
+    ) -> Option<WasmEdgeResult<Option<String>>> {
+        Self::loaded_plugin_descriptors(path)
+            .into_iter()
+            .map(|(_, descriptor)| unsafe { &*descriptor })
+            .find(|descriptor| {
+                !descriptor.Name.is_null()
+                    && unsafe { std::ffi::CStr::from_ptr(descriptor.Name) }.to_str() == Ok(plugin)
+            })
+            .map(|descriptor| unsafe { set_raw_program_option(descriptor, name, value) })
+    }
+
+    /// Returns the descriptors of the plugins defined with the C API, which are loaded into this process from the given path.
+    ///
+    /// The descriptors stay valid since the runtime never unloads a plugin.
+    #[cfg(unix)]
+    fn loaded_plugin_descriptors(
+        path: impl AsRef<std::path::Path>,
+    ) -> Vec<(std::path::PathBuf, *const ffi::WasmEdge_PluginDescriptor)> {
+        let path = path.as_ref();
+        let files = match path.is_dir() {
+            true => match std::fs::read_dir(path) {
+                Ok(entries) => entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| {
+                        path.extension().and_then(|ext| ext.to_str())
+                            == Some(std::env::consts::DLL_EXTENSION)
+                    })
+                    .collect(),
+                Err(_) => Vec::new(),
+            },
+            false => vec![path.to_path_buf()],
+        };
+
+        let mut plugins = Vec::new();
+        for file in files {
+            let c_path = match utils::path_to_cstring(&file) {
+                Ok(c_path) => c_path,
+                Err(_) => continue,
+            };
+            unsafe {
+                // returns null if the library is not loaded
+                let handle = libc::dlopen(c_path.as_ptr(), libc::RTLD_LAZY | libc::RTLD_NOLOAD);
+                if handle.is_null() {
+                    continue;
+                }
+                let symbol = libc::dlsym(
+                    handle,
+                    b"WasmEdge_Plugin_GetDescriptor\0".as_ptr() as *const std::os::raw::c_char,
+                );
+                if !symbol.is_null() {
+                    let get_descriptor: unsafe extern "C" fn() -> *const ffi::WasmEdge_PluginDescriptor =
+                        std::mem::transmute(symbol);
+                    let descriptor = get_descriptor();
+                    if !descriptor.is_null() {
+                        plugins.push((file, descriptor));
+                    }
+                }
+                libc::dlclose(handle);
+            }
+        }
+        plugins
+    }
+}
+
+/// Parses the given value according to the type of a program option of the given plugin descriptor, and writes it into the storage of the option.
+///
+/// If the option is a string option, then the storage refers to the returned string, which must be kept alive as long as the plugin may read the option.
+///
+/// # Arguments
+///
+/// * `descriptor` - The plugin descriptor.
+///
+/// * `name` - The name of the program option.
+///
+/// * `value` - The string representation of the value.
+///
+/// # Error
+///
+/// * If the descriptor has no program option of the given name with storage, then [PluginError::NotFoundOption](wasmedge_types::error::PluginError::NotFoundOption) is returned.
+///
+/// * If the value cannot be parsed, then [PluginError::InvalidOptionValue](wasmedge_types::error::PluginError::InvalidOptionValue) is returned.
+///
+/// # Safety
+///
+/// The pointers in the descriptor must be valid, and the storage of each program option must hold a value of the type of the option.
+pub unsafe fn set_raw_program_option(
+    descriptor: &ffi::WasmEdge_PluginDescriptor,
+    name: &str,
+    value: &str,
+) -> WasmEdgeResult<Option<String>> {
+    unsafe fn write<T: ProgramOptionValue>(
+        option: &ffi::WasmEdge_ProgramOption,
+        value: &str,
+    ) -> Option<T> {
+        let value = T::parse(value)?;
+        *(option.Storage as *mut T::Raw) = value.to_raw();
+        Some(value)
+    }
+
+    let options = match descriptor.ProgramOptions.is_null() {
+        true => &[][..],
+        false => std::slice::from_raw_parts(
+            descriptor.ProgramOptions,
+            descriptor.ProgramOptionCount as usize,
+        ),
+    };
+    let option = match options.iter().find(|option| {
+        !option.Name.is_null()
+            && !option.Storage.is_null()
+            && std::ffi::CStr::from_ptr(option.Name).to_str() == Ok(name)
+    }) {
+        Some(option) => option,
+        None => {
+            return Err(Box::new(WasmEdgeError::Plugin(
+                PluginError::NotFoundOption(name.to_string()),
+            )))
+        }
+    };
+
+    let written = match ProgramOptionType::from(option.Type) {
+        ProgramOptionType::None => write::<()>(option, value).map(|_| None),
+        ProgramOptionType::Toggle => write::<bool>(option, value).map(|_| None),
+        ProgramOptionType::I8 => write::<i8>(option, value).map(|_| None),
+        ProgramOptionType::I16 => write::<i16>(option, value).map(|_| None),
+        ProgramOptionType::I32 => write::<i32>(option, value).map(|_| None),
+        ProgramOptionType::I64 => write::<i64>(option, value).map(|_| None),
+        ProgramOptionType::U8 => write::<u8>(option, value).map(|_| None),
+        ProgramOptionType::U16 => write::<u16>(option, value).map(|_| None),
+        ProgramOptionType::U32 => write::<u32>(option, value).map(|_| None),
+        ProgramOptionType::U64 => write::<u64>(option, value).map(|_| None),
+        ProgramOptionType::F32 => write::<f32>(option, value).map(|_| None),
+        ProgramOptionType::F64 => write::<f64>(option, value).map(|_| None),
+        // the heap buffer of the string does not move with the string
+        ProgramOptionType::String => write::<String>(option, value).map(Some),
+    };
+    written.ok_or_else(|| {
+        Box::new(WasmEdgeError::Plugin(PluginError::InvalidOptionValue {
+            name: name.to_string(),
+            value: value.to_string(),
+        }))
+    })
+}
+
+/// Describes a plugin defined with the C API by its descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginMetadata {
+    /// The name of the plugin.
+    pub name: String,
+    /// The description of the plugin.
+    pub description: String,
+    /// The plugin API version the plugin is built with.
+    pub api_version: u32,
+    /// The version of the plugin.
+    pub version: PluginVersion,
+    /// The names and the descriptions of the modules.
+    pub modules: Vec<(String, String)>,
+    /// The names, the descriptions, and the types of the program options.
+    pub options: Vec<(String, String, ProgramOptionType)>,
+    /// The path of the library file.
+    pub path: std::path::PathBuf,
+}
+impl PluginMetadata {
+    /// # Safety
+    ///
+    /// The pointers in the descriptor must be valid.
+    #[cfg(unix)]
+    unsafe fn from_raw(
+        descriptor: &ffi::WasmEdge_PluginDescriptor,
+        path: std::path::PathBuf,
+    ) -> Self {
+        unsafe fn to_string(ptr: *const std::os::raw::c_char) -> String {
+            match ptr.is_null() {
+                true => String::new(),
+                false => std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned(),
+            }
+        }
+
+        let modules = match descriptor.ModuleDescriptions.is_null() {
+            true => &[][..],
+            false => std::slice::from_raw_parts(
+                descriptor.ModuleDescriptions,
+                descriptor.ModuleCount as usize,
+            ),
+        };
+        let options = match descriptor.ProgramOptions.is_null() {
+            true => &[][..],
+            false => std::slice::from_raw_parts(
+                descriptor.ProgramOptions,
+                descriptor.ProgramOptionCount as usize,
+            ),
+        };
+
+        Self {
+            name: to_string(descriptor.Name),
+            description: to_string(descriptor.Description),
+            api_version: descriptor.APIVersion,
+            version: descriptor.Version.into(),
+            modules: modules
+                .iter()
+                .map(|module| (to_string(module.Name), to_string(module.Description)))
+                .collect(),
+            options: options
+                .iter()
+                .map(|option| {
+                    (
+                        to_string(option.Name),
+                        to_string(option.Description),
+                        option.Type.into(),
+                    )
+                })
+                .collect(),
+            path,
+        }
+    }
 }
 
 /// Represents a loaded plugin. It provides the APIs for accessing the plugin.
@@ -275,15 +545,128 @@ impl From<ProgramOptionType> for ffi::WasmEdge_ProgramOptionType {
     }
 }
 
+/// Defines the value types of the [program options](crate::plugin::ProgramOption).
+///
+/// The value of a program option is stored in the C representation given by `Raw`, which is read and written by the WasmEdge runtime.
+pub trait ProgramOptionValue: Sized + 'static {
+    /// The type of the program option.
+    const TYPE: ProgramOptionType;
+
+    /// The C representation of the value.
+    type Raw: Copy + 'static;
+
+    /// Returns the C representation of this value. The returned value may refer to the memory owned by this value.
+    fn to_raw(&self) -> Self::Raw;
+
+    /// Returns the value from its C representation.
+    ///
+    /// # Safety
+    ///
+    /// The memory referred by `raw` must be valid.
+    unsafe fn from_raw(raw: &Self::Raw) -> Self;
+
+    /// Parses the value from a string.
+    fn parse(s: &str) -> Option<Self>;
+}
+impl ProgramOptionValue for () {
+    const TYPE: ProgramOptionType = ProgramOptionType::None;
+    type Raw = ();
+
+    fn to_raw(&self) -> Self::Raw {}
+
+    unsafe fn from_raw(_raw: &Self::Raw) -> Self {}
+
+    fn parse(_s: &str) -> Option<Self> {
+        Some(())
+    }
+}
+impl ProgramOptionValue for bool {
+    const TYPE: ProgramOptionType = ProgramOptionType::Toggle;
+    type Raw = bool;
+
+    fn to_raw(&self) -> Self::Raw {
+        *self
+    }
+
+    unsafe fn from_raw(raw: &Self::Raw) -> Self {
+        *raw
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "" | "1" | "true" | "on" | "yes" => Some(true),
+            "0" | "false" | "off" | "no" => Some(false),
+            _ => None,
+        }
+    }
+}
+macro_rules! impl_program_option_value {
+    ($t:ty, $ty:expr) => {
+        impl ProgramOptionValue for $t {
+            const TYPE: ProgramOptionType = $ty;
+            type Raw = $t;
+
+            fn to_raw(&self) -> Self::Raw {
+                *self
+            }
+
+            unsafe fn from_raw(raw: &Self::Raw) -> Self {
+                *raw
+            }
+
+            fn parse(s: &str) -> Option<Self> {
+                s.trim().parse().ok()
+            }
+        }
+    };
+}
+impl_program_option_value!(i8, ProgramOptionType::I8);
+impl_program_option_value!(i16, ProgramOptionType::I16);
+impl_program_option_value!(i32, ProgramOptionType::I32);
+impl_program_option_value!(i64, ProgramOptionType::I64);
+impl_program_option_value!(u8, ProgramOptionType::U8);
+impl_program_option_value!(u16, ProgramOptionType::U16);
+impl_program_option_value!(u32, ProgramOptionType::U32);
+impl_program_option_value!(u64, ProgramOptionType::U64);
+impl_program_option_value!(f32, ProgramOptionType::F32);
+impl_program_option_value!(f64, ProgramOptionType::F64);
+impl ProgramOptionValue for String {
+    const TYPE: ProgramOptionType = ProgramOptionType::String;
+    type Raw = ffi::WasmEdge_String;
+
+    fn to_raw(&self) -> Self::Raw {
+        ffi::WasmEdge_String {
+            Length: self.len() as u32,
+            Buf: self.as_ptr() as *const _,
+        }
+    }
+
+    unsafe fn from_raw(raw: &Self::Raw) -> Self {
+        if raw.Buf.is_null() {
+            return String::new();
+        }
+        let bytes = std::slice::from_raw_parts(raw.Buf as *const u8, raw.Length as usize);
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Some(s.to_string())
+    }
+}
+
 /// Defines the program option for plugins.
-#[derive(Debug)]
-pub struct ProgramOption {
+///
+/// A typed program option created by [ProgramOption::new](crate::plugin::ProgramOption::new) owns the storage of its value, which is initialized with the default value, and is written by the WasmEdge runtime when the option is given.
+pub struct ProgramOption<T: ProgramOptionValue = ()> {
     name: CString,
     desc: CString,
+    default: Option<Box<(T, T::Raw)>>,
+    value: Option<T>,
+    storage: Option<Box<std::cell::UnsafeCell<T::Raw>>>,
     pub inner: ffi::WasmEdge_ProgramOption,
 }
 impl ProgramOption {
-    /// Creates a new program option.
+    /// Creates a new program option without storage.
     pub fn create(
         name: impl AsRef<str>,
         desc: impl AsRef<str>,
@@ -296,6 +679,9 @@ impl ProgramOption {
         let mut po = Self {
             name,
             desc,
+            default: None,
+            value: None,
+            storage: None,
             inner: ffi::WasmEdge_ProgramOption {
                 Name: std::ptr::null(),
                 Description: std::ptr::null(),
@@ -310,8 +696,125 @@ impl ProgramOption {
         Ok(po)
     }
 }
-unsafe impl Send for ProgramOption {}
-unsafe impl Sync for ProgramOption {}
+impl<T: ProgramOptionValue> ProgramOption<T> {
+    /// Creates a new typed program option with the given default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the program option.
+    ///
+    /// * `desc` - The description of the program option.
+    ///
+    /// * `default` - The default value of the program option.
+    ///
+    /// # Error
+    ///
+    /// If the name or the description contains a nul byte, then an error is returned.
+    pub fn new(name: impl AsRef<str>, desc: impl AsRef<str>, default: T) -> WasmEdgeResult<Self> {
+        let name = std::ffi::CString::new(name.as_ref()).map_err(WasmEdgeError::FoundNulByte)?;
+
+        let desc = std::ffi::CString::new(desc.as_ref()).map_err(WasmEdgeError::FoundNulByte)?;
+
+        // the raw default value refers to the memory of the boxed default value
+        let raw = default.to_raw();
+        let default = Box::new((default, raw));
+        // the storage is written by the runtime
+        let storage = Box::new(std::cell::UnsafeCell::new(default.1));
+
+        let inner = ffi::WasmEdge_ProgramOption {
+            Name: name.as_ptr(),
+            Description: desc.as_ptr(),
+            Type: T::TYPE.into(),
+            Storage: storage.get() as *mut _,
+            DefaultValue: &default.1 as *const T::Raw as *const _,
+        };
+
+        Ok(Self {
+            name,
+            desc,
+            default: Some(default),
+            value: None,
+            storage: Some(storage),
+            inner,
+        })
+    }
+
+    /// Returns the name of this program option.
+    pub fn name(&self) -> &str {
+        self.name.to_str().unwrap_or_default()
+    }
+
+    /// Returns the current value of this program option, or `None` if the option has no storage.
+    pub fn value(&self) -> Option<T> {
+        self.storage
+            .as_ref()
+            .map(|storage| unsafe { T::from_raw(&*storage.get()) })
+    }
+
+    /// Returns the default value of this program option, or `None` if the option has no storage.
+    pub fn default_value(&self) -> Option<&T> {
+        self.default.as_ref().map(|default| &default.0)
+    }
+
+    /// Sets the value of this program option.
+    ///
+    /// # Argument
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Error
+    ///
+    /// If the option has no storage, then an error is returned.
+    pub fn set_value(&mut self, value: T) -> WasmEdgeResult<()> {
+        let storage = match self.storage.as_ref() {
+            Some(storage) => storage,
+            None => {
+                return Err(Box::new(WasmEdgeError::Plugin(
+                    PluginError::NotFoundOption(self.name().to_string()),
+                )))
+            }
+        };
+        let value = self.value.insert(value);
+        unsafe { *storage.get() = value.to_raw() };
+        Ok(())
+    }
+}
+impl<T: ProgramOptionValue> std::fmt::Debug for ProgramOption<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgramOption")
+            .field("name", &self.name)
+            .field("desc", &self.desc)
+            .field("ty", &T::TYPE)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+unsafe impl<T: ProgramOptionValue> Send for ProgramOption<T> {}
+unsafe impl<T: ProgramOptionValue> Sync for ProgramOption<T> {}
+
+/// The type-erased typed program options owned by a [PluginDescriptor].
+trait OwnedProgramOption: std::fmt::Debug {
+    fn name(&self) -> &str;
+
+    fn set_from_str(&mut self, value: &str) -> WasmEdgeResult<()>;
+}
+impl<T: ProgramOptionValue> OwnedProgramOption for ProgramOption<T> {
+    fn name(&self) -> &str {
+        ProgramOption::name(self)
+    }
+
+    fn set_from_str(&mut self, value: &str) -> WasmEdgeResult<()> {
+        match T::parse(value) {
+            Some(value) => self.set_value(value),
+            None => Err(Box::new(WasmEdgeError::Plugin(
+                PluginError::InvalidOptionValue {
+                    name: self.name().to_string(),
+                    value: value.to_string(),
+                },
+            ))),
+        }
+    }
+}
 
 /// Defines the module descriptor for plugins.
 #[derive(Debug)]
@@ -365,7 +868,7 @@ pub type ModuleInstanceCreateFn = unsafe extern "C" fn(
 ) -> *mut ffi::WasmEdge_ModuleInstanceContext;
 
 /// Defines the version of a plugin.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PluginVersion {
     pub major: u32,
     pub minor: u32,
@@ -383,6 +886,20 @@ impl PluginVersion {
         }
     }
 }
+impl std::fmt::Display for PluginVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.patch, self.build
+        )
+    }
+}
+impl From<ffi::WasmEdge_PluginVersionData> for PluginVersion {
+    fn from(value: ffi::WasmEdge_PluginVersionData) -> Self {
+        Self::create(value.Major, value.Minor, value.Patch, value.Build)
+    }
+}
 impl From<PluginVersion> for ffi::WasmEdge_PluginVersionData {
     fn from(value: PluginVersion) -> Self {
         Self {
@@ -403,6 +920,7 @@ pub struct PluginDescriptor {
     module_descriptors: Vec<ffi::WasmEdge_ModuleDescriptor>,
     program_options_name_desc: Vec<(CString, CString)>,
     program_options: Vec<ffi::WasmEdge_ProgramOption>,
+    owned_program_options: Vec<Box<dyn OwnedProgramOption>>,
     inner: ffi::WasmEdge_PluginDescriptor,
 }
 impl PluginDescriptor {
@@ -424,6 +942,7 @@ impl PluginDescriptor {
             module_descriptors: Vec::new(),
             program_options_name_desc: Vec::new(),
             program_options: Vec::new(),
+            owned_program_options: Vec::new(),
             inner: ffi::WasmEdge_PluginDescriptor {
                 Name: std::ptr::null(),
                 Description: std::ptr::null(),
@@ -492,6 +1011,79 @@ impl PluginDescriptor {
         Ok(self)
     }
 
+    /// Adds a typed [program option](crate::plugin::ProgramOption), whose storage is owned by this descriptor.
+    ///
+    /// # Argument
+    ///
+    /// * `option` - The program option to add.
+    pub fn add_typed_program_option<T: ProgramOptionValue>(
+        mut self,
+        option: ProgramOption<T>,
+    ) -> Self {
+        // the strings and the storage of the option are boxed, so the raw option stays valid after moving it
+        self.program_options.push(option.inner);
+        self.owned_program_options.push(Box::new(option));
+
+        self.inner.ProgramOptionCount = self.program_options.len() as u32;
+        self.inner.ProgramOptions = self.program_options.as_mut_ptr();
+
+        self
+    }
+
+    /// Returns the current value of the program option of the given name and type, or `None` if no such option has storage.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the program option.
+    pub fn program_option<T: ProgramOptionValue>(&self, name: impl AsRef<str>) -> Option<T> {
+        self.program_options.iter().find_map(|option| {
+            let option_name = unsafe { std::ffi::CStr::from_ptr(option.Name) };
+            match option_name.to_str() == Ok(name.as_ref())
+                && option.Type == T::TYPE.into()
+                && !option.Storage.is_null()
+            {
+                true => Some(unsafe { T::from_raw(&*(option.Storage as *const T::Raw)) }),
+                false => None,
+            }
+        })
+    }
+
+    /// Sets the value of a typed program option from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the program option.
+    ///
+    /// * `value` - The string representation of the value.
+    ///
+    /// # Error
+    ///
+    /// If the typed program option is not found, or the value cannot be parsed, then an error is returned.
+    pub fn set_program_option(
+        &mut self,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> WasmEdgeResult<()> {
+        match self
+            .owned_program_options
+            .iter_mut()
+            .find(|option| option.name() == name.as_ref())
+        {
+            Some(option) => option.set_from_str(value.as_ref()),
+            None => Err(Box::new(WasmEdgeError::Plugin(
+                PluginError::NotFoundOption(name.as_ref().to_string()),
+            ))),
+        }
+    }
+
+    /// Returns the names of the typed program options.
+    pub fn typed_program_option_names(&self) -> Vec<&str> {
+        self.owned_program_options
+            .iter()
+            .map(|option| option.name())
+            .collect()
+    }
+
     /// Returns the raw pointer to the inner `WasmEdge_PluginDescriptor`.
     #[cfg(feature = "ffi")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
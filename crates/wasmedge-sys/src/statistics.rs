@@ -1,11 +1,16 @@
 //! Defines WasmEdge Statistics struct.
 
 use crate::{ffi, WasmEdgeResult};
-use std::sync::Arc;
-use wasmedge_types::error::WasmEdgeError;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+use wasmedge_types::error::{CoreCommonError, CoreError, WasmEdgeError};
 
 #[derive(Debug, Clone)]
 /// Struct of WasmEdge Statistics.
+///
+/// The clones of a [Statistics] share the same statistics context.
 pub struct Statistics {
     pub(crate) inner: Arc<InnerStat>,
 }
@@ -20,7 +25,13 @@ impl Statistics {
         match ctx.is_null() {
             true => Err(Box::new(WasmEdgeError::StatisticsCreate)),
             false => Ok(Statistics {
-                inner: Arc::new(InnerStat(ctx)),
+                inner: Arc::new(InnerStat(
+                    ctx,
+                    HostCost {
+                        charged: AtomicU64::new(0),
+                        limit: AtomicU64::new(u64::MAX),
+                    },
+                )),
             }),
         }
     }
@@ -55,11 +66,49 @@ impl Statistics {
         unsafe { ffi::WasmEdge_StatisticsGetInstrPerSecond(self.inner.0) }
     }
 
-    /// Returns the total cost in execution.
+    /// Returns the total cost of the instructions in execution, excluding the cost charged by host functions.
     pub fn cost_in_total(&self) -> u64 {
         unsafe { ffi::WasmEdge_StatisticsGetTotalCost(self.inner.0) }
     }
 
+    /// Returns the cost charged by host functions through [Statistics::charge](crate::Statistics::charge).
+    pub fn charged_cost(&self) -> u64 {
+        self.inner.1.charged.load(Ordering::Acquire)
+    }
+
+    /// Charges the given cost, which is counted against the cost limit like the cost of the instructions. The cost is only charged if the limit is not exceeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `cost` - The cost to charge.
+    ///
+    /// # Error
+    ///
+    /// If the total cost exceeds the cost limit, then a [CostLimitExceeded](wasmedge_types::error::CoreCommonError::CostLimitExceeded) error is returned.
+    pub fn charge(&self, cost: u64) -> Result<(), CoreError> {
+        let host_cost = &self.inner.1;
+        let limit = host_cost.limit.load(Ordering::Acquire);
+        let mut charged = host_cost.charged.load(Ordering::Acquire);
+        loop {
+            let new_charged = charged.saturating_add(cost);
+            if self.cost_in_total().saturating_add(new_charged) > limit {
+                return Err(CoreError::Common(CoreCommonError::CostLimitExceeded));
+            }
+            match host_cost.charged.compare_exchange_weak(
+                charged,
+                new_charged,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(current) => charged = current,
+            }
+        }
+        self.sync_cost_limit();
+
+        Ok(())
+    }
+
     /// Sets the cost of instructions.
     ///
     /// # Arguments
@@ -81,12 +130,22 @@ impl Statistics {
     ///
     /// * `limit` - The cost limit.
     pub fn set_cost_limit(&mut self, limit: u64) {
-        unsafe { ffi::WasmEdge_StatisticsSetCostLimit(self.inner.0, limit) }
+        self.inner.1.limit.store(limit, Ordering::Release);
+        self.sync_cost_limit();
     }
 
-    /// Clears the data in this statistics.
+    /// Clears the data in this statistics, including the cost charged by host functions. The cost limit is kept.
     pub fn clear(&mut self) {
         unsafe { ffi::WasmEdge_StatisticsClear(self.inner.0) }
+        self.inner.1.charged.store(0, Ordering::Release);
+        self.sync_cost_limit();
+    }
+
+    /// Lowers the cost limit of the runtime by the charged cost, so that the instructions executed afterwards are checked against the remaining budget.
+    fn sync_cost_limit(&self) {
+        let limit = self.inner.1.limit.load(Ordering::Acquire);
+        let charged = self.inner.1.charged.load(Ordering::Acquire);
+        unsafe { ffi::WasmEdge_StatisticsSetCostLimit(self.inner.0, limit.saturating_sub(charged)) }
     }
 
     /// Provides a raw pointer to the inner Statistics context.
@@ -96,13 +155,19 @@ impl Statistics {
         self.inner.0 as *const _
     }
 }
-impl Drop for Statistics {
+#[derive(Debug)]
+pub(crate) struct InnerStat(pub(crate) *mut ffi::WasmEdge_StatisticsContext, HostCost);
+impl Drop for InnerStat {
     fn drop(&mut self) {
-        unsafe { ffi::WasmEdge_StatisticsDelete(self.inner.0) }
+        unsafe { ffi::WasmEdge_StatisticsDelete(self.0) }
     }
 }
-
-#[derive(Debug)]
-pub(crate) struct InnerStat(pub(crate) *mut ffi::WasmEdge_StatisticsContext);
 unsafe impl Send for InnerStat {}
 unsafe impl Sync for InnerStat {}
+
+/// The cost charged by host functions, shared by the clones of a [Statistics].
+#[derive(Debug)]
+struct HostCost {
+    charged: AtomicU64,
+    limit: AtomicU64,
+}
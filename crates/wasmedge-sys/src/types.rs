@@ -292,6 +292,14 @@ impl WasmValue {
         }
     }
 
+    /// Creates a null [WasmValue] with a [FuncRef](wasmedge_types::RefType::FuncRef).
+    pub fn null_func_ref() -> Self {
+        Self {
+            ctx: unsafe { ffi::WasmEdge_ValueGenFuncRef(std::ptr::null()) },
+            ty: ValType::FuncRef,
+        }
+    }
+
     /// Returns the FuncRef(crate::FuncRef).
     ///
     /// If the [WasmValue] is a `NullRef`, then `None` is returned.
@@ -459,6 +467,15 @@ mod tests {
         assert_eq!(value.ty(), ValType::ExternRef);
         assert!(value.extern_ref::<Table>().is_some());
 
+        // null references
+        let value = WasmValue::null_extern_ref();
+        assert_eq!(value.ty(), ValType::ExternRef);
+        assert!(value.is_null_ref());
+        let value = WasmValue::null_func_ref();
+        assert_eq!(value.ty(), ValType::FuncRef);
+        assert!(value.is_null_ref());
+        assert!(value.func_ref().is_none());
+
         let val1 = WasmValue::from_i32(1314);
         let val2 = WasmValue::from_i32(1314);
         assert_eq!(val1.to_i32(), val2.to_i32());
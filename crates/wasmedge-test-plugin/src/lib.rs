@@ -0,0 +1,46 @@
+//! A WasmEdge plugin used by the tests of the WasmEdge Rust SDK.
+//!
+//! The plugin provides a working module and a module which always fails to be created.
+
+use wasmedge_sdk::{
+    error::{CoreError, WasmEdgeError},
+    plugin::program_option,
+    wasmedge_plugin, CallingFrame, ImportObject, ImportObjectBuilder, Instance, WasmEdgeResult,
+    WasmValue,
+};
+
+fn scale(
+    factor: &mut i32,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    inputs: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Ok(vec![WasmValue::from_i32(inputs[0].to_i32() * *factor)])
+}
+
+fn create_math() -> WasmEdgeResult<ImportObject<i32>> {
+    let factor = program_option::<i32>("test-scale").unwrap_or(1);
+    let mut builder = ImportObjectBuilder::new("test_math", factor)?;
+    builder.with_func::<i32, i32>("scale", scale)?;
+    Ok(builder.build())
+}
+
+fn create_broken() -> WasmEdgeResult<ImportObject<()>> {
+    Err(Box::new(WasmEdgeError::Operation(String::from(
+        "no device",
+    ))))
+}
+
+wasmedge_plugin! {
+    name: "test_plugin",
+    description: "A plugin for the tests",
+    version: (1, 2, 3, 4),
+    modules: [
+        { name: "test_math", description: "The math functions", create: create_math },
+        { name: "test_broken", description: "Always fails", create: create_broken },
+    ],
+    options: [
+        { name: "test-scale", description: "Scales the results", default: 1i32 },
+        { name: "test-verbose", description: "Prints the calls", ty: Toggle },
+    ],
+}
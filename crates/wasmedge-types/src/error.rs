@@ -5,6 +5,7 @@ use thiserror::Error;
 
 /// The error types used by both wasmedge-sys and wasmedge crates.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum WasmEdgeError {
     // For general operation error
     #[error("{0}")]
@@ -66,6 +67,8 @@ pub enum WasmEdgeError {
     Instance(#[from] InstanceError),
     #[error("{0}")]
     Plugin(#[from] PluginError),
+    #[error("{0}")]
+    Linker(#[from] LinkerError),
 
     // std
     #[error("Found an internal 0 byte")]
@@ -95,6 +98,7 @@ pub enum FuncError {
 
 /// The error types for WasmEdge Memory.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MemError {
     #[error("Fail to create Memory instance")]
     Create,
@@ -108,6 +112,8 @@ pub enum MemError {
     MutPtr,
     #[error("Fail to convert a raw pointer to a reference")]
     Ptr2Ref,
+    #[error("The memory growth is denied by the resource limiter")]
+    GrowDenied,
 }
 
 /// The error types for WasmEdge Global.
@@ -125,11 +131,14 @@ pub enum GlobalError {
 
 /// The error types for WasmEdge Table.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TableError {
     #[error("Fail to create Table instance")]
     Create,
     #[error("Fail to get the table type")]
     Type,
+    #[error("The table growth is denied by the resource limiter")]
+    GrowDenied,
 }
 
 /// The error types for WasmEdge ImportType.
@@ -193,15 +202,33 @@ pub enum InstanceError {
 
 /// The error types for WasmEdge plugin.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum PluginError {
     #[error("Failed to create a plugin instance named '{0}'. Make sure that the plugin instance name is correct.")]
     Create(String),
     #[error("Not found the plugin named '{0}'. Make sure that the plugin name is correct.")]
     NotFound(String),
+    #[error("Not found the program option named '{0}'")]
+    NotFoundOption(String),
+    #[error("Invalid value '{value}' for the program option '{name}'")]
+    InvalidOptionValue { name: String, value: String },
+    #[error(
+        "The plugin named '{name}' has the version {found}, but the version {expected} is expected"
+    )]
+    VersionMismatch {
+        name: String,
+        expected: String,
+        found: String,
+    },
+    #[error("Fail to verify the version of the plugin named '{0}'. Only the versions of the plugins defined with the C API can be verified.")]
+    UnverifiedVersion(String),
+    #[error("The plugin '{0}' is not allowed")]
+    NotAllowed(String),
 }
 
 /// The error types for WasmEdge Store.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum StoreError {
     #[error("Fail to create Store context")]
     Create,
@@ -231,10 +258,46 @@ pub enum StoreError {
     NotFoundModule(String),
     #[error("Not found the active module")]
     NotFoundActiveModule,
+    #[error("The module ({name}) is still imported by: {}", .dependents.join(", "))]
+    InUse {
+        name: String,
+        dependents: Vec<String>,
+    },
+    #[error("The import ({module_name}::{name}) cannot be added to the module ({module_name}), which is not owned by the store")]
+    SealedNamespace { module_name: String, name: String },
+    #[error("The stub of the unresolved import ({module_name}::{name}) is called")]
+    StubCalled { module_name: String, name: String },
+    #[error("Fail to read the type of the import ({module_name}::{name}): {reason}")]
+    UnreadableImport {
+        module_name: String,
+        name: String,
+        reason: String,
+    },
+}
+
+/// The error types for WasmEdge Linker.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LinkerError {
+    #[error("The module ({0}) is already defined. Enable shadowing to replace it.")]
+    DuplicateModule(String),
+    #[error("The item ({name}) is already defined in the module ({mod_name}). Enable shadowing to replace it.")]
+    DuplicateDefinition { mod_name: String, name: String },
+    #[error("Fail to find the module ({0})")]
+    NotFoundModule(String),
+    #[error("The module ({0}) cannot be aliased. The functions built into the runtime or a plugin cannot be exported under another name.")]
+    UnsupportedAlias(String),
+    #[error("The item ({name}) cannot be defined in the module ({mod_name}), which is provided by a module instance. Only the items defined individually can be shadowed.")]
+    UnsupportedShadowing { mod_name: String, name: String },
+    #[error("Fail to link an anonymous module instance")]
+    AnonymousInstance,
+    #[error("Fail to resolve the imports: {}", .0.join("; "))]
+    UnresolvedImports(Vec<String>),
 }
 
 /// The error types for WasmEdge Vm.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum VmError {
     #[error("Fail to create Vm context")]
     Create,
@@ -244,6 +307,8 @@ pub enum VmError {
     NotFoundFuncType(String),
     #[error("Fail to get module instance ({0})")]
     NotFoundModule(String),
+    #[error("Fail to get the module instance of the handle ({0})")]
+    NotFoundInstance(u64),
     #[error("Fail to get Wasi module instance")]
     NotFoundWasiModule,
     #[error("Fail to get WasmEdge_Process module instance")]
@@ -115,6 +115,7 @@ impl From<Mutability> for i32 {
 
 /// Defines WasmEdge AOT compiler optimization level.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CompilerOptimizationLevel {
     /// Disable as many optimizations as possible.
     O0,
@@ -188,6 +189,7 @@ impl From<CompilerOptimizationLevel> for i32 {
 
 /// Defines WasmEdge AOT compiler output binary format.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CompilerOutputFormat {
     /// Native dynamic library format.
     Native,
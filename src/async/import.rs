@@ -38,6 +38,34 @@ impl<Data: Send> ImportObjectBuilder<Data> {
         name: impl AsRef<str>,
         real_func: AsyncFn<Data>,
     ) -> WasmEdgeResult<&mut Self>
+    where
+        Args: WasmValTypeList,
+        Rets: WasmValTypeList,
+    {
+        self.with_func_and_cost::<Args, Rets>(name, real_func, 0)
+    }
+
+    /// Adds a [host function](crate::Func) with a static cost to the [ImportObject] to create. See [ImportObjectBuilder::with_func_and_cost](crate::ImportObjectBuilder::with_func_and_cost) for how the cost is counted.
+    ///
+    /// N.B. that this function can be used in thread-safe scenarios.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [host function](crate::Func) to add.
+    ///
+    /// * `real_func` - The native function.
+    ///
+    /// * `cost` - The cost of each call to the function.
+    ///
+    /// # error
+    ///
+    /// If fail to create or add the [host function](crate::Func), then an error is returned.
+    pub fn with_func_and_cost<Args, Rets>(
+        &mut self,
+        name: impl AsRef<str>,
+        real_func: AsyncFn<Data>,
+        cost: u64,
+    ) -> WasmEdgeResult<&mut Self>
     where
         Args: WasmValTypeList,
         Rets: WasmValTypeList,
@@ -45,15 +73,7 @@ impl<Data: Send> ImportObjectBuilder<Data> {
         let args = Args::wasm_types();
         let returns = Rets::wasm_types();
         let ty = FuncType::new(args.to_vec(), returns.to_vec());
-        let func = AsyncFunction::create_async_func(
-            &ty,
-            real_func,
-            self.import_object.get_host_data_mut(),
-            0,
-        )?;
-
-        self.import_object.add_async_func(name, func);
-        Ok(self)
+        self.with_func_by_type_and_cost(name, ty, real_func, cost)
     }
 
     /// Adds a [host function](crate::Func) to the [ImportObject] to create.
@@ -78,12 +98,39 @@ impl<Data: Send> ImportObjectBuilder<Data> {
         name: impl AsRef<str>,
         ty: FuncType,
         real_func: AsyncFn<Data>,
+    ) -> WasmEdgeResult<&mut Self> {
+        self.with_func_by_type_and_cost(name, ty, real_func, 0)
+    }
+
+    /// Adds a [host function](crate::Func) of the given type with a static cost to the [ImportObject] to create. See [ImportObjectBuilder::with_func_and_cost](crate::ImportObjectBuilder::with_func_and_cost) for how the cost is counted.
+    ///
+    /// N.B. that this function can be used in thread-safe scenarios.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [host function](crate::Func) to add.
+    ///
+    /// * `ty` - The function type.
+    ///
+    /// * `real_func` - The native function.
+    ///
+    /// * `cost` - The cost of each call to the function.
+    ///
+    /// # error
+    ///
+    /// If fail to create or add the [host function](crate::Func), then an error is returned.
+    pub fn with_func_by_type_and_cost(
+        &mut self,
+        name: impl AsRef<str>,
+        ty: FuncType,
+        real_func: AsyncFn<Data>,
+        cost: u64,
     ) -> WasmEdgeResult<&mut Self> {
         let func = AsyncFunction::create_async_func(
             &ty,
             real_func,
             self.import_object.get_host_data_mut(),
-            0,
+            cost,
         )?;
 
         self.import_object.add_async_func(name, func);
@@ -0,0 +1,351 @@
+//! Defines WasmEdge CompilationCache struct.
+
+use crate::{
+    compiler::Compiler, config::Config, error::WasmEdgeError, CompilerOutputFormat, WasmEdgeResult,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use wasmedge_sys as sys;
+
+/// Defines a content-addressed cache of the AOT-compiled artifacts.
+///
+/// An artifact is keyed by the SHA-256 hash of the wasm bytes, the proposal, statistics and compiler options of the [Config](crate::config::Config) used to compile it, and the version of the WasmEdge runtime. A cached artifact is reused as long as all of them are unchanged, so the cache directory can be shared by multiple processes and kept across runtime upgrades.
+///
+/// A new artifact is compiled into a private temporary directory in the cache directory, which is only accessible by the owner on Unix-like systems, and then renamed to its final name, so a reader never observes a partially written artifact. An artifact is named after its key, with the extension `wasm` for the [universal wasm format](crate::CompilerOutputFormat::Wasm) or the extension of the native shared libraries of the platform.
+///
+/// The SHA-256 hash of each artifact is stored next to it, in a file with the additional extension `sha256`, and the artifact is verified against it whenever it is looked up. An artifact which is truncated, corrupted or replaced without its hash is treated as not cached, and compiled again by [get_or_compile](crate::CompilationCache::get_or_compile). The hash does not protect against a writer who can replace both files, so the cache directory must only be writable by trusted users.
+#[derive(Debug, Clone)]
+pub struct CompilationCache {
+    dir: PathBuf,
+}
+impl CompilationCache {
+    /// Creates a new [CompilationCache] storing the artifacts in the given directory. The directory is created if it does not exist.
+    ///
+    /// # Argument
+    ///
+    /// * `dir` - The directory to store the artifacts.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the directory, then an error is returned.
+    pub fn new(dir: impl AsRef<Path>) -> WasmEdgeResult<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            WasmEdgeError::Operation(format!(
+                "Failed to create the cache directory {}: {e}",
+                dir.display()
+            ))
+        })?;
+
+        Ok(Self { dir })
+    }
+
+    /// Returns the directory storing the artifacts.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the cache key of the given wasm bytes compiled with the given configuration, which is a hex-encoded SHA-256 hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration used to compile the wasm bytes. If `None`, then the default configuration is used.
+    ///
+    /// * `bytes` - The in-memory wasm bytes.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the default configuration, then an error is returned.
+    pub fn key(&self, config: Option<&Config>, bytes: impl AsRef<[u8]>) -> WasmEdgeResult<String> {
+        let default_config;
+        let config = match config {
+            Some(config) => config,
+            None => {
+                default_config = Config {
+                    inner: Arc::new(sys::Config::create()?),
+                };
+                &default_config
+            }
+        };
+        let inner = &config.inner;
+
+        let options = [
+            inner.mutable_globals_enabled(),
+            inner.non_trap_conversions_enabled(),
+            inner.sign_extension_operators_enabled(),
+            inner.multi_value_enabled(),
+            inner.bulk_memory_operations_enabled(),
+            inner.reference_types_enabled(),
+            inner.simd_enabled(),
+            inner.tail_call_enabled(),
+            inner.annotations_enabled(),
+            inner.memory64_enabled(),
+            inner.threads_enabled(),
+            inner.gc_enabled(),
+            inner.exception_handling_enabled(),
+            inner.function_references_enabled(),
+            inner.multi_memories_enabled(),
+            inner.is_instruction_counting(),
+            inner.is_cost_measuring(),
+            inner.is_time_measuring(),
+            inner.generic_binary_enabled(),
+            inner.interruptible_enabled(),
+        ];
+
+        let mut hasher = Sha256::new();
+        hasher.update(sys::utils::version_string().as_bytes());
+        hasher.update([0u8]);
+        hasher.update(options.map(u8::from));
+        hasher.update(u32::from(inner.get_aot_optimization_level()).to_le_bytes());
+        hasher.update(u32::from(inner.get_aot_compiler_output_format()).to_le_bytes());
+        hasher.update(bytes.as_ref());
+
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|x| format!("{x:02x}"))
+            .collect())
+    }
+
+    /// Returns the path of the cached artifact of the given wasm bytes compiled with the given configuration. If the artifact is not cached yet, then the wasm bytes are compiled and the artifact is stored into the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration used to compile the wasm bytes. If `None`, then the default configuration is used.
+    ///
+    /// * `bytes` - The in-memory wasm bytes.
+    ///
+    /// # Error
+    ///
+    /// If fail to compile the wasm bytes or store the artifact, then an error is returned.
+    pub fn get_or_compile(
+        &self,
+        config: Option<&Config>,
+        bytes: impl AsRef<[u8]>,
+    ) -> WasmEdgeResult<PathBuf> {
+        let key = self.key(config, bytes.as_ref())?;
+        let path = self.artifact_path(config, &key);
+        if is_verified(&path) {
+            return Ok(path);
+        }
+
+        // compile into a private temporary directory, then move the hash and the artifact to their
+        // final paths atomically, the hash first, so a reader never observes an artifact without it
+        let mut builder = tempfile::Builder::new();
+        builder.prefix(".tmp-");
+        #[cfg(unix)]
+        builder.permissions(std::os::unix::fs::PermissionsExt::from_mode(0o700));
+        let temp_dir = builder.tempdir_in(&self.dir).map_err(|e| {
+            WasmEdgeError::Operation(format!(
+                "Failed to create a temporary directory in {}: {e}",
+                self.dir.display()
+            ))
+        })?;
+        let store = |from: &Path, to: &Path| {
+            std::fs::rename(from, to).map_err(|e| {
+                Box::new(WasmEdgeError::Operation(format!(
+                    "Failed to store the compiled artifact {}: {e}",
+                    to.display()
+                )))
+            })
+        };
+        let temp_path = Compiler::new(config)?.compile_from_bytes(bytes, &key, temp_dir.path())?;
+        let temp_hash = hash_path(&temp_path);
+        std::fs::write(&temp_hash, file_hash(&temp_path)?).map_err(|e| {
+            WasmEdgeError::Operation(format!(
+                "Failed to write the hash of the compiled artifact {}: {e}",
+                temp_hash.display()
+            ))
+        })?;
+        store(&temp_hash, &hash_path(&path))?;
+        store(&temp_path, &path)?;
+
+        Ok(path)
+    }
+
+    /// Returns the path of the cached artifact of the given wasm bytes compiled with the given configuration, or `None` if the artifact is not cached or does not match its hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration used to compile the wasm bytes. If `None`, then the default configuration is used.
+    ///
+    /// * `bytes` - The in-memory wasm bytes.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the default configuration, then an error is returned.
+    pub fn get(
+        &self,
+        config: Option<&Config>,
+        bytes: impl AsRef<[u8]>,
+    ) -> WasmEdgeResult<Option<PathBuf>> {
+        let path = self.artifact_path(config, &self.key(config, bytes)?);
+        Ok(is_verified(&path).then_some(path))
+    }
+
+    /// Removes all cached artifacts.
+    ///
+    /// Only the artifacts and the hashes named after a cache key are removed, so the other files in the directory and the artifacts being compiled are kept.
+    ///
+    /// # Error
+    ///
+    /// If fail to remove an artifact, then an error is returned.
+    pub fn clear(&self) -> WasmEdgeResult<()> {
+        let entries = std::fs::read_dir(&self.dir).map_err(|e| {
+            WasmEdgeError::Operation(format!(
+                "Failed to read the cache directory {}: {e}",
+                self.dir.display()
+            ))
+        })?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_file() && is_artifact_name(&path) {
+                std::fs::remove_file(&path).map_err(|e| {
+                    WasmEdgeError::Operation(format!(
+                        "Failed to remove the cached artifact {}: {e}",
+                        path.display()
+                    ))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn artifact_path(&self, config: Option<&Config>, key: &str) -> PathBuf {
+        let extension = match config.map(|cfg| cfg.inner.get_aot_compiler_output_format()) {
+            Some(CompilerOutputFormat::Native) => NATIVE_EXTENSION,
+            _ => WASM_EXTENSION,
+        };
+        self.dir.join(format!("{key}.{extension}"))
+    }
+}
+
+/// Returns the path of the file holding the hash of the given artifact.
+fn hash_path(artifact: &Path) -> PathBuf {
+    let mut name = artifact.as_os_str().to_os_string();
+    name.push(".");
+    name.push(HASH_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Returns the hex-encoded SHA-256 hash of the content of the given file.
+fn file_hash(path: &Path) -> WasmEdgeResult<String> {
+    let mut file = std::fs::File::open(path).map_err(|e| {
+        WasmEdgeError::Operation(format!(
+            "Failed to read the compiled artifact {}: {e}",
+            path.display()
+        ))
+    })?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(|e| {
+        WasmEdgeError::Operation(format!(
+            "Failed to read the compiled artifact {}: {e}",
+            path.display()
+        ))
+    })?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|x| format!("{x:02x}"))
+        .collect())
+}
+
+/// Checks if the given artifact exists and matches its stored hash.
+fn is_verified(artifact: &Path) -> bool {
+    match std::fs::read_to_string(hash_path(artifact)) {
+        Ok(expected) => file_hash(artifact).is_ok_and(|found| found == expected.trim()),
+        Err(_) => false,
+    }
+}
+
+/// Checks if the given file is named after a cache key, that is, 64 lowercase hex digits with an artifact extension, optionally followed by the hash extension.
+fn is_artifact_name(path: &Path) -> bool {
+    let path = match path.extension().and_then(|x| x.to_str()) {
+        Some(HASH_EXTENSION) => path.with_extension(""),
+        _ => path.to_path_buf(),
+    };
+    let path = path.as_path();
+    let is_key = path.file_stem().and_then(|x| x.to_str()).is_some_and(|x| {
+        x.len() == 64 && x.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    });
+    let is_artifact = matches!(
+        path.extension().and_then(|x| x.to_str()),
+        Some(WASM_EXTENSION | NATIVE_EXTENSION)
+    );
+    is_key && is_artifact
+}
+
+const WASM_EXTENSION: &str = "wasm";
+const HASH_EXTENSION: &str = "sha256";
+#[cfg(target_os = "macos")]
+const NATIVE_EXTENSION: &str = "dylib";
+#[cfg(target_os = "windows")]
+const NATIVE_EXTENSION: &str = "dll";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const NATIVE_EXTENSION: &str = "so";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{CompilerConfigOptions, ConfigBuilder},
+        wat2wasm, CompilerOptimizationLevel,
+    };
+
+    #[test]
+    fn test_compilation_cache() -> Result<(), Box<dyn std::error::Error>> {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+                (func (export "add") (param i32 i32) (result i32)
+                    (i32.add (local.get 0) (local.get 1))))"#,
+        )?;
+
+        let temp_dir = tempfile::tempdir()?;
+        let dir = temp_dir.path().join("cache");
+        let cache = CompilationCache::new(&dir)?;
+        assert_eq!(cache.dir(), dir.as_path());
+
+        // the key is stable and depends on the config
+        let key = cache.key(None, &wasm_bytes)?;
+        assert_eq!(key.len(), 64);
+        assert_eq!(key, cache.key(None, &wasm_bytes)?);
+        let config = ConfigBuilder::default()
+            .with_compiler_config(
+                CompilerConfigOptions::new().optimization_level(CompilerOptimizationLevel::O0),
+            )
+            .build()?;
+        assert_ne!(key, cache.key(Some(&config), &wasm_bytes)?);
+
+        // compile once, then hit the cache
+        assert!(cache.get(None, &wasm_bytes)?.is_none());
+        let path = cache.get_or_compile(None, &wasm_bytes)?;
+        assert!(path.exists());
+        assert_eq!(cache.get(None, &wasm_bytes)?, Some(path.clone()));
+        assert_eq!(cache.get_or_compile(None, &wasm_bytes)?, path);
+
+        assert_eq!(path.extension().unwrap(), "wasm");
+        assert!(hash_path(&path).exists());
+        // no temporary directory is left
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 2);
+
+        // a corrupted artifact is compiled again
+        std::fs::write(&path, b"corrupted")?;
+        assert!(cache.get(None, &wasm_bytes)?.is_none());
+        assert_eq!(cache.get_or_compile(None, &wasm_bytes)?, path);
+        assert_eq!(cache.get(None, &wasm_bytes)?, Some(path.clone()));
+
+        // clear the cache, which keeps the files not named after a key
+        let foreign = dir.join("foreign.so");
+        std::fs::write(&foreign, b"")?;
+        cache.clear()?;
+        assert!(!path.exists());
+        assert!(!hash_path(&path).exists());
+        assert!(foreign.exists());
+
+        Ok(())
+    }
+}
@@ -1,13 +1,21 @@
 //! Defines WasmEdge ahead-of-time compiler.
 
-use crate::{config::Config, WasmEdgeResult};
-use std::path::{Path, PathBuf};
+use crate::{config::Config, error::WasmEdgeError, CompilerOutputFormat, WasmEdgeResult};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    time::{Duration, Instant},
+};
 use wasmedge_sys as sys;
 
 /// Defines WasmEdge ahead-of-time(AOT) compiler and the relevant APIs.
 #[derive(Debug)]
 pub struct Compiler {
     pub(crate) inner: sys::Compiler,
+    config: Option<Config>,
 }
 impl Compiler {
     /// Creates a new AOT compiler.
@@ -21,7 +29,10 @@ impl Compiler {
             None => sys::Compiler::create(None)?,
         };
 
-        Ok(Self { inner })
+        Ok(Self {
+            inner,
+            config: config.cloned(),
+        })
     }
 
     /// Compiles the given wasm file into a shared library file (*.so in Linux, *.dylib in macOS, or *.dll in Windows). The file path of the generated shared library file will be returned if the method works successfully.
@@ -89,6 +100,280 @@ impl Compiler {
 
         Ok(aot_file)
     }
+
+    /// Compiles the given wasm bytes into an in-memory universal wasm binary, which is the original wasm binary with the native code embedded in a custom section. The returned bytes can be stored anywhere and loaded later by [Module::from_compiled_bytes](crate::Module::from_compiled_bytes).
+    ///
+    /// The compiler must be created with a [Config](crate::config::Config) whose output format is [CompilerOutputFormat::Wasm](crate::CompilerOutputFormat::Wasm), which is the default.
+    ///
+    /// Note that the WasmEdge AOT compiler can only write its output to a file, so this method does not avoid the file system: the artifact is written to a file in a new temporary directory, which is only accessible by the current user, and removed after it is read back.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - A in-memory WASM bytes.
+    ///
+    /// # Error
+    ///
+    /// If the output format of the compiler is not [CompilerOutputFormat::Wasm](crate::CompilerOutputFormat::Wasm), or fail to create the temporary directory or compile, then an error is returned.
+    pub fn compile_to_bytes(&self, bytes: impl AsRef<[u8]>) -> WasmEdgeResult<Vec<u8>> {
+        let out_format = match &self.config {
+            Some(cfg) => cfg.inner.get_aot_compiler_output_format(),
+            None => CompilerOutputFormat::Wasm,
+        };
+        if out_format != CompilerOutputFormat::Wasm {
+            return Err(Box::new(WasmEdgeError::Operation(String::from(
+                "Compiling to bytes requires the `Wasm` compiler output format",
+            ))));
+        }
+
+        // the underlying compiler only writes to files, so the artifact goes through a file in a
+        // private directory with a random name, which cannot be pre-created or redirected by others
+        let temp_dir = tempfile::Builder::new()
+            .prefix("wasmedge-aot-")
+            .tempdir()
+            .map_err(|e| {
+                Box::new(WasmEdgeError::Operation(format!(
+                    "Failed to create the temporary directory: {e}"
+                )))
+            })?;
+        let aot_file = temp_dir.path().join("module.wasm");
+        self.inner.compile_from_bytes(bytes, &aot_file)?;
+        std::fs::read(&aot_file).map_err(|e| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "Failed to read the compiled artifact: {e}"
+            )))
+        })
+    }
+
+    /// Compiles the given inputs into shared library files in parallel. The results are returned in the order of the inputs.
+    ///
+    /// Each worker thread owns a separate AOT compiler created with the configuration of this compiler. The generated file of an input is named after the input, see [CompileInput].
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The wasm files or in-memory wasm bytes to compile.
+    ///
+    /// * `out_dir` - The target directory to save the generated shared library files.
+    ///
+    /// * `parallelism` - The number of the worker threads. If `0`, then the available parallelism of the host is used.
+    ///
+    /// # Error
+    ///
+    /// If an input has an empty name, or two inputs have the same name, then an error is returned before any input is compiled.
+    pub fn compile_many(
+        &self,
+        inputs: impl IntoIterator<Item = CompileInput>,
+        out_dir: impl AsRef<Path>,
+        parallelism: usize,
+    ) -> WasmEdgeResult<Vec<CompileReport>> {
+        self.compile_many_with_progress(inputs, out_dir, parallelism, |_| {})
+    }
+
+    /// Compiles the given inputs into shared library files in parallel, and reports the progress after each input is compiled. The results are returned in the order of the inputs.
+    ///
+    /// The progress callback is invoked on the calling thread, in the order of completion.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The wasm files or in-memory wasm bytes to compile.
+    ///
+    /// * `out_dir` - The target directory to save the generated shared library files.
+    ///
+    /// * `parallelism` - The number of the worker threads. If `0`, then the available parallelism of the host is used.
+    ///
+    /// * `progress` - The callback receiving the progress of the batch.
+    ///
+    /// # Error
+    ///
+    /// If an input has an empty name, a name containing a path separator or `..`, or two inputs have the same name, then an error is returned before any input is compiled.
+    pub fn compile_many_with_progress<F>(
+        &self,
+        inputs: impl IntoIterator<Item = CompileInput>,
+        out_dir: impl AsRef<Path>,
+        parallelism: usize,
+        mut progress: F,
+    ) -> WasmEdgeResult<Vec<CompileReport>>
+    where
+        F: FnMut(CompileProgress<'_>),
+    {
+        let inputs: Vec<CompileInput> = inputs.into_iter().collect();
+
+        // the generated files are named after the inputs, so an empty name or a duplicate one
+        // would produce a nameless file or let the inputs overwrite each other's output, and a
+        // name with a path separator or `..` would write the output outside of `out_dir`
+        let mut names = std::collections::HashSet::with_capacity(inputs.len());
+        for input in &inputs {
+            let name = input.name();
+            if name.is_empty() {
+                return Err(Box::new(WasmEdgeError::Operation(format!(
+                    "The compile input {input:?} has no name to name the generated file after"
+                ))));
+            }
+            if name.contains(['/', '\\']) || name.contains("..") {
+                return Err(Box::new(WasmEdgeError::Operation(format!(
+                    "The name `{name}` of a compile input must not contain a path separator or `..`"
+                ))));
+            }
+            if !names.insert(name) {
+                return Err(Box::new(WasmEdgeError::Operation(format!(
+                    "Multiple compile inputs are named `{}`",
+                    input.name()
+                ))));
+            }
+        }
+
+        let total = inputs.len();
+        let parallelism = match parallelism {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        }
+        .min(total);
+        let out_dir = out_dir.as_ref();
+        let next = AtomicUsize::new(0);
+        let mut reports: Vec<Option<CompileReport>> = (0..total).map(|_| None).collect();
+
+        std::thread::scope(|scope| {
+            let (sender, receiver) = mpsc::channel();
+            for _ in 0..parallelism {
+                let sender = sender.clone();
+                let (inputs, next) = (&inputs, &next);
+                scope.spawn(move || {
+                    let compiler = Compiler::new(self.config.as_ref());
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let input = match inputs.get(index) {
+                            Some(input) => input,
+                            None => break,
+                        };
+                        let report = match &compiler {
+                            Ok(compiler) => compiler.compile_input(input, out_dir),
+                            Err(e) => CompileReport {
+                                name: input.name(),
+                                result: Err(e.clone()),
+                                elapsed: Duration::ZERO,
+                                output_size: None,
+                            },
+                        };
+                        if sender.send((index, report)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            for (completed, (index, report)) in receiver.iter().enumerate() {
+                progress(CompileProgress {
+                    report: &report,
+                    completed: completed + 1,
+                    total,
+                });
+                reports[index] = Some(report);
+            }
+        });
+
+        Ok(reports.into_iter().flatten().collect())
+    }
+
+    fn compile_input(&self, input: &CompileInput, out_dir: &Path) -> CompileReport {
+        let name = input.name();
+        let start = Instant::now();
+        let result = match input {
+            CompileInput::File(path) => self.compile_from_file(path, &name, out_dir),
+            CompileInput::Bytes { bytes, .. } => self.compile_from_bytes(bytes, &name, out_dir),
+        };
+        let elapsed = start.elapsed();
+        let output_size = result
+            .as_ref()
+            .ok()
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len());
+
+        CompileReport {
+            name,
+            result,
+            elapsed,
+            output_size,
+        }
+    }
+}
+
+/// Defines an input of [Compiler::compile_many](crate::Compiler::compile_many).
+#[derive(Debug, Clone)]
+pub enum CompileInput {
+    /// A wasm file. The generated file is named after the file stem of the wasm file.
+    File(PathBuf),
+    /// In-memory wasm bytes. The generated file is named after the given name.
+    Bytes {
+        /// The filename of the generated shared library file, without the extension. It must not contain a path separator or `..`.
+        name: String,
+        /// The in-memory wasm bytes.
+        bytes: Vec<u8>,
+    },
+}
+impl CompileInput {
+    fn name(&self) -> String {
+        match self {
+            CompileInput::File(path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            CompileInput::Bytes { name, .. } => name.clone(),
+        }
+    }
+}
+impl From<PathBuf> for CompileInput {
+    fn from(path: PathBuf) -> Self {
+        CompileInput::File(path)
+    }
+}
+
+/// Describes the compilation result of an input of [Compiler::compile_many](crate::Compiler::compile_many).
+#[derive(Debug)]
+pub struct CompileReport {
+    name: String,
+    result: WasmEdgeResult<PathBuf>,
+    elapsed: Duration,
+    output_size: Option<u64>,
+}
+impl CompileReport {
+    /// Returns the name of the input, which is also the filename of the generated shared library file without the extension.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path of the generated shared library file, or the error if the input failed to compile.
+    pub fn result(&self) -> Result<&Path, &WasmEdgeError> {
+        match &self.result {
+            Ok(path) => Ok(path.as_path()),
+            Err(e) => Err(e.as_ref()),
+        }
+    }
+
+    /// Consumes the report and returns the path of the generated shared library file, or the error if the input failed to compile.
+    pub fn into_result(self) -> WasmEdgeResult<PathBuf> {
+        self.result
+    }
+
+    /// Returns the time spent on compiling the input.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the size in bytes of the generated shared library file, or `None` if the input failed to compile.
+    pub fn output_size(&self) -> Option<u64> {
+        self.output_size
+    }
+}
+
+/// Describes the progress of [Compiler::compile_many_with_progress](crate::Compiler::compile_many_with_progress).
+#[derive(Debug, Clone, Copy)]
+pub struct CompileProgress<'a> {
+    /// The report of the input just compiled.
+    pub report: &'a CompileReport,
+    /// The number of the inputs compiled so far, including the current one.
+    pub completed: usize,
+    /// The total number of the inputs.
+    pub total: usize,
 }
 
 #[cfg(not(feature = "async"))]
@@ -97,7 +382,7 @@ mod tests {
     use super::*;
     use crate::{
         config::{CompilerConfigOptions, ConfigBuilder},
-        params, wat2wasm, CompilerOutputFormat, VmBuilder, WasmVal,
+        params, wat2wasm, CompilerOutputFormat, Module, VmBuilder, WasmVal,
     };
     use std::io::Read;
 
@@ -218,4 +503,137 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_compiler_compile_to_bytes() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{vm::SyncInst, Store, Vm};
+        use std::collections::HashMap;
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+                (func (export "add") (param i32 i32) (result i32)
+                    (i32.add (local.get 0) (local.get 1))))"#,
+        )?;
+
+        // compile into an in-memory universal wasm binary
+        let compiler = Compiler::new(None)?;
+        let compiled_bytes = compiler.compile_to_bytes(&wasm_bytes)?;
+        assert!(compiled_bytes.starts_with(&[0x00, 0x61, 0x73, 0x6D]));
+        assert!(compiled_bytes.len() > wasm_bytes.len());
+
+        // load the compiled module from memory
+        let module = Module::from_compiled_bytes(None, &compiled_bytes)?;
+        let mut vm = Vm::new(Store::new(
+            None,
+            HashMap::<String, &mut dyn SyncInst>::new(),
+        )?);
+        let res = vm
+            .register_module(None, module)?
+            .run_func(None, "add", params!(2, 3))?;
+        assert_eq!(res[0].to_i32(), 5);
+
+        // plain wasm bytes carry no compiled code
+        assert!(Module::from_compiled_bytes(None, &wasm_bytes).is_err());
+
+        // the native output format cannot be kept in memory
+        let config = ConfigBuilder::default()
+            .with_compiler_config(
+                CompilerConfigOptions::new().out_format(CompilerOutputFormat::Native),
+            )
+            .build()?;
+        let compiler = Compiler::new(Some(&config))?;
+        assert!(compiler.compile_to_bytes(&wasm_bytes).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compiler_compile_many() -> Result<(), Box<dyn std::error::Error>> {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+                (func (export "add") (param i32 i32) (result i32)
+                    (i32.add (local.get 0) (local.get 1))))"#,
+        )?;
+        let mut inputs: Vec<CompileInput> = (0..4)
+            .map(|i| CompileInput::Bytes {
+                name: format!("aot_batch_{i}"),
+                bytes: wasm_bytes.clone(),
+            })
+            .collect();
+        inputs.push(CompileInput::Bytes {
+            name: String::from("aot_batch_invalid"),
+            bytes: b"not a wasm module".to_vec(),
+        });
+
+        let compiler = Compiler::new(None)?;
+        let temp_dir = tempfile::tempdir()?;
+        let out_dir = temp_dir.path().to_path_buf();
+        let mut completed = Vec::new();
+        let reports = compiler.compile_many_with_progress(inputs, &out_dir, 2, |progress| {
+            assert_eq!(progress.total, 5);
+            completed.push(progress.completed);
+        })?;
+        assert_eq!(completed, vec![1, 2, 3, 4, 5]);
+
+        // the reports are in the order of the inputs
+        assert_eq!(reports.len(), 5);
+        for (i, report) in reports.iter().take(4).enumerate() {
+            assert_eq!(report.name(), format!("aot_batch_{i}"));
+            let path = report.result().expect("compilation should succeed");
+            assert!(path.exists());
+            assert_eq!(report.output_size(), Some(std::fs::metadata(path)?.len()));
+            assert!(std::fs::remove_file(path).is_ok());
+        }
+        assert_eq!(reports[4].name(), "aot_batch_invalid");
+        assert!(reports[4].result().is_err());
+        assert!(reports[4].output_size().is_none());
+
+        // duplicate names are rejected before anything is compiled
+        let inputs = vec![
+            CompileInput::Bytes {
+                name: String::from("aot_batch_dup"),
+                bytes: wasm_bytes.clone(),
+            },
+            CompileInput::File(out_dir.join("aot_batch_dup.wasm")),
+        ];
+        let result = compiler.compile_many(inputs, &out_dir, 2);
+        assert!(result.is_err());
+        assert!(!out_dir
+            .join(format!("aot_batch_dup.{}", std::env::consts::DLL_EXTENSION))
+            .exists());
+
+        // so are empty names
+        let inputs = vec![CompileInput::Bytes {
+            name: String::new(),
+            bytes: wasm_bytes.clone(),
+        }];
+        assert!(compiler.compile_many(inputs, &out_dir, 2).is_err());
+        assert!(compiler
+            .compile_many(vec![CompileInput::File(PathBuf::from("/"))], &out_dir, 2)
+            .is_err());
+
+        // and names escaping the output directory
+        for name in [
+            "../aot_batch_escape",
+            "nested/aot_batch_escape",
+            "nested\\aot_batch_escape",
+        ] {
+            let inputs = vec![CompileInput::Bytes {
+                name: String::from(name),
+                bytes: wasm_bytes.clone(),
+            }];
+            assert!(compiler.compile_many(inputs, &out_dir, 2).is_err());
+        }
+        assert!(!temp_dir
+            .path()
+            .parent()
+            .unwrap()
+            .join(format!(
+                "aot_batch_escape.{}",
+                std::env::consts::DLL_EXTENSION
+            ))
+            .exists());
+
+        Ok(())
+    }
 }
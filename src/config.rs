@@ -2,13 +2,15 @@
 
 use std::sync::Arc;
 
-use crate::WasmEdgeResult;
+use crate::{error::WasmEdgeError, WasmEdgeResult};
 #[cfg(feature = "aot")]
 use crate::{CompilerOptimizationLevel, CompilerOutputFormat};
 use wasmedge_sys as sys;
 
 /// Defines a builder for creating a [Config].
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ConfigBuilder {
     common_config: CommonConfigOptions,
     stat_config: Option<StatisticsConfigOptions>,
@@ -85,6 +87,9 @@ impl ConfigBuilder {
         inner.gc(self.common_config.gc);
         inner.tail_call(self.common_config.tail_call);
         inner.function_references(self.common_config.function_references);
+        inner.annotations(self.common_config.annotations);
+        inner.memory64(self.common_config.memory64);
+        inner.exception_handling(self.common_config.exception_handling);
         inner.interpreter_mode(self.common_config.interpreter_mode);
 
         if let Some(stat_config) = self.stat_config {
@@ -210,6 +215,146 @@ impl Config {
         self.inner.function_references_enabled()
     }
 
+    /// Checks if the GC option turns on or not.
+    pub fn gc_enabled(&self) -> bool {
+        self.inner.gc_enabled()
+    }
+
+    /// Checks if the Annotations option turns on or not.
+    pub fn annotations_enabled(&self) -> bool {
+        self.inner.annotations_enabled()
+    }
+
+    /// Checks if the Memory64 option turns on or not.
+    pub fn memory64_enabled(&self) -> bool {
+        self.inner.memory64_enabled()
+    }
+
+    /// Checks if the ExceptionHandling option turns on or not.
+    pub fn exception_handling_enabled(&self) -> bool {
+        self.inner.exception_handling_enabled()
+    }
+
+    /// Checks if the given proposal turns on or not.
+    ///
+    /// # Argument
+    ///
+    /// * `proposal` - The proposal to check.
+    pub fn proposal_enabled(&self, proposal: Proposal) -> bool {
+        match proposal {
+            Proposal::ImportExportMutGlobals => self.inner.mutable_globals_enabled(),
+            Proposal::NonTrapFloatToIntConversions => self.inner.non_trap_conversions_enabled(),
+            Proposal::SignExtensionOperators => self.inner.sign_extension_operators_enabled(),
+            Proposal::MultiValue => self.inner.multi_value_enabled(),
+            Proposal::BulkMemoryOperations => self.inner.bulk_memory_operations_enabled(),
+            Proposal::ReferenceTypes => self.inner.reference_types_enabled(),
+            Proposal::Simd => self.inner.simd_enabled(),
+            Proposal::TailCall => self.inner.tail_call_enabled(),
+            Proposal::MultiMemories => self.inner.multi_memories_enabled(),
+            Proposal::Annotations => self.inner.annotations_enabled(),
+            Proposal::Memory64 => self.inner.memory64_enabled(),
+            Proposal::ExceptionHandling => self.inner.exception_handling_enabled(),
+            Proposal::Threads => self.inner.threads_enabled(),
+            Proposal::FunctionReferences => self.inner.function_references_enabled(),
+            Proposal::Gc => self.inner.gc_enabled(),
+        }
+    }
+
+    /// Returns the proposals turned on.
+    pub fn proposals(&self) -> Vec<Proposal> {
+        Proposal::ALL
+            .iter()
+            .copied()
+            .filter(|proposal| self.proposal_enabled(*proposal))
+            .collect()
+    }
+
+    /// Creates a new [Config] with exactly the given proposals turned on. The proposals not listed are turned off, and the other options keep their default values.
+    ///
+    /// # Argument
+    ///
+    /// * `proposals` - The proposals to turn on.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a [Config], then an error is returned.
+    pub fn from_features(proposals: &[Proposal]) -> WasmEdgeResult<Self> {
+        let mut inner = sys::Config::create()?;
+        for proposal in Proposal::ALL {
+            let enable = proposals.contains(proposal);
+            match proposal {
+                Proposal::ImportExportMutGlobals => inner.mutable_globals(enable),
+                Proposal::NonTrapFloatToIntConversions => inner.non_trap_conversions(enable),
+                Proposal::SignExtensionOperators => inner.sign_extension_operators(enable),
+                Proposal::MultiValue => inner.multi_value(enable),
+                Proposal::BulkMemoryOperations => inner.bulk_memory_operations(enable),
+                Proposal::ReferenceTypes => inner.reference_types(enable),
+                Proposal::Simd => inner.simd(enable),
+                Proposal::TailCall => inner.tail_call(enable),
+                Proposal::MultiMemories => inner.multi_memories(enable),
+                Proposal::Annotations => inner.annotations(enable),
+                Proposal::Memory64 => inner.memory64(enable),
+                Proposal::ExceptionHandling => inner.exception_handling(enable),
+                Proposal::Threads => inner.threads(enable),
+                Proposal::FunctionReferences => inner.function_references(enable),
+                Proposal::Gc => inner.gc(enable),
+            }
+        }
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    /// Returns a [ConfigBuilder] holding the current options of this [Config], which builds an equivalent [Config].
+    ///
+    /// With the `serde` feature enabled, the returned [ConfigBuilder] can be serialized to record or compare the configuration.
+    pub fn to_options(&self) -> ConfigBuilder {
+        let inner = &self.inner;
+        let common_config = CommonConfigOptions {
+            mutable_globals: inner.mutable_globals_enabled(),
+            non_trap_conversions: inner.non_trap_conversions_enabled(),
+            sign_extension_operators: inner.sign_extension_operators_enabled(),
+            multi_value: inner.multi_value_enabled(),
+            bulk_memory_operations: inner.bulk_memory_operations_enabled(),
+            reference_types: inner.reference_types_enabled(),
+            simd: inner.simd_enabled(),
+            multi_memories: inner.multi_memories_enabled(),
+            threads: inner.threads_enabled(),
+            gc: inner.gc_enabled(),
+            tail_call: inner.tail_call_enabled(),
+            function_references: inner.function_references_enabled(),
+            annotations: inner.annotations_enabled(),
+            memory64: inner.memory64_enabled(),
+            exception_handling: inner.exception_handling_enabled(),
+            interpreter_mode: inner.interpreter_mode_enabled(),
+        };
+        let stat_config = StatisticsConfigOptions {
+            count_instructions: inner.is_instruction_counting(),
+            measure_cost: inner.is_cost_measuring(),
+            measure_time: inner.is_time_measuring(),
+        };
+        #[cfg(feature = "aot")]
+        let compiler_config = CompilerConfigOptions {
+            out_format: inner.get_aot_compiler_output_format(),
+            opt_level: inner.get_aot_optimization_level(),
+            dump_ir: inner.dump_ir_enabled(),
+            generic_binary: inner.generic_binary_enabled(),
+            interruptible: inner.interruptible_enabled(),
+        };
+        let runtime_config = RuntimeConfigOptions {
+            max_memory_pages: inner.get_max_memory_pages(),
+        };
+
+        ConfigBuilder {
+            common_config,
+            stat_config: Some(stat_config),
+            #[cfg(feature = "aot")]
+            compiler_config: Some(compiler_config),
+            runtime_config: Some(runtime_config),
+        }
+    }
+
     /// Checks if the `ForceInterpreter` option turns on or not.
     pub fn interpreter_mode_enabled(&self) -> bool {
         self.inner.interpreter_mode_enabled()
@@ -266,6 +411,110 @@ impl Config {
     }
 }
 
+/// Defines the WebAssembly proposals supported by WasmEdge.
+///
+/// A [Proposal] is displayed as its kebab-case name, such as `multi-memories` or `memory64`, and can be parsed from the same name. Parsing is case-insensitive and also accepts underscores in place of dashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Proposal {
+    /// The [Import/Export Mutable Globals](https://github.com/WebAssembly/mutable-global) proposal.
+    ImportExportMutGlobals,
+    /// The [Non-trapping Float-to-int Conversions](https://github.com/WebAssembly/nontrapping-float-to-int-conversions) proposal.
+    NonTrapFloatToIntConversions,
+    /// The [Sign-extension Operators](https://github.com/WebAssembly/sign-extension-ops) proposal.
+    SignExtensionOperators,
+    /// The [Multi-value](https://github.com/WebAssembly/multi-value) proposal.
+    MultiValue,
+    /// The [Bulk Memory Operations](https://github.com/WebAssembly/bulk-memory-operations) proposal.
+    BulkMemoryOperations,
+    /// The [Reference Types](https://github.com/WebAssembly/reference-types) proposal.
+    ReferenceTypes,
+    /// The [SIMD](https://github.com/WebAssembly/simd) proposal.
+    Simd,
+    /// The [Tail Call](https://github.com/WebAssembly/tail-call) proposal.
+    TailCall,
+    /// The [Multiple Memories](https://github.com/WebAssembly/multi-memory) proposal.
+    MultiMemories,
+    /// The [Custom Annotation Syntax](https://github.com/WebAssembly/annotations) proposal.
+    Annotations,
+    /// The [Memory64](https://github.com/WebAssembly/memory64) proposal.
+    Memory64,
+    /// The [Exception Handling](https://github.com/WebAssembly/exception-handling) proposal.
+    ExceptionHandling,
+    /// The [Threads](https://github.com/WebAssembly/threads) proposal.
+    Threads,
+    /// The [Typed Function References](https://github.com/WebAssembly/function-references) proposal.
+    FunctionReferences,
+    /// The [GC](https://github.com/WebAssembly/gc) proposal.
+    Gc,
+}
+impl Proposal {
+    const ALL: &'static [Proposal] = &[
+        Proposal::ImportExportMutGlobals,
+        Proposal::NonTrapFloatToIntConversions,
+        Proposal::SignExtensionOperators,
+        Proposal::MultiValue,
+        Proposal::BulkMemoryOperations,
+        Proposal::ReferenceTypes,
+        Proposal::Simd,
+        Proposal::TailCall,
+        Proposal::MultiMemories,
+        Proposal::Annotations,
+        Proposal::Memory64,
+        Proposal::ExceptionHandling,
+        Proposal::Threads,
+        Proposal::FunctionReferences,
+        Proposal::Gc,
+    ];
+
+    /// Returns all the proposals supported by WasmEdge.
+    pub fn all() -> &'static [Proposal] {
+        Self::ALL
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Proposal::ImportExportMutGlobals => "import-export-mut-globals",
+            Proposal::NonTrapFloatToIntConversions => "non-trap-float-to-int-conversions",
+            Proposal::SignExtensionOperators => "sign-extension-operators",
+            Proposal::MultiValue => "multi-value",
+            Proposal::BulkMemoryOperations => "bulk-memory-operations",
+            Proposal::ReferenceTypes => "reference-types",
+            Proposal::Simd => "simd",
+            Proposal::TailCall => "tail-call",
+            Proposal::MultiMemories => "multi-memories",
+            Proposal::Annotations => "annotations",
+            Proposal::Memory64 => "memory64",
+            Proposal::ExceptionHandling => "exception-handling",
+            Proposal::Threads => "threads",
+            Proposal::FunctionReferences => "function-references",
+            Proposal::Gc => "gc",
+        }
+    }
+}
+impl std::fmt::Display for Proposal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+impl std::str::FromStr for Proposal {
+    type Err = Box<WasmEdgeError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase().replace('_', "-");
+        Proposal::ALL
+            .iter()
+            .copied()
+            .find(|proposal| proposal.name() == name)
+            .ok_or_else(|| {
+                Box::new(WasmEdgeError::Operation(format!(
+                    "Unknown WebAssembly proposal: {s}"
+                )))
+            })
+    }
+}
+
 /// Defines the common configuration options.
 ///
 /// [CommonConfigOptions] is used to set the common configuration options, which are
@@ -297,7 +546,9 @@ impl Config {
 ///  - `SIMD` supports 128-bit packed SIMD extension to WebAssembly.
 ///
 ///    Also see [SIMD Proposal](https://github.com/WebAssembly/spec/blob/main/proposals/simd/SIMD.md).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct CommonConfigOptions {
     mutable_globals: bool,
     non_trap_conversions: bool,
@@ -311,6 +562,9 @@ pub struct CommonConfigOptions {
     gc: bool,
     tail_call: bool,
     function_references: bool,
+    annotations: bool,
+    memory64: bool,
+    exception_handling: bool,
     interpreter_mode: bool,
 }
 impl CommonConfigOptions {
@@ -329,6 +583,9 @@ impl CommonConfigOptions {
     /// * gc: false,
     /// * tail_call: false,
     /// * function_references: false,
+    /// * annotations: false,
+    /// * memory64: false,
+    /// * exception_handling: false,
     /// * interpreter_mode: false,
     pub fn new() -> Self {
         Self {
@@ -344,6 +601,9 @@ impl CommonConfigOptions {
             gc: false,
             tail_call: false,
             function_references: false,
+            annotations: false,
+            memory64: false,
+            exception_handling: false,
             interpreter_mode: false,
         }
     }
@@ -489,6 +749,42 @@ impl CommonConfigOptions {
         }
     }
 
+    /// Enables or disables the Annotations option.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn annotations(self, enable: bool) -> Self {
+        Self {
+            annotations: enable,
+            ..self
+        }
+    }
+
+    /// Enables or disables the Memory64 option.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn memory64(self, enable: bool) -> Self {
+        Self {
+            memory64: enable,
+            ..self
+        }
+    }
+
+    /// Enables or disables the ExceptionHandling option.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn exception_handling(self, enable: bool) -> Self {
+        Self {
+            exception_handling: enable,
+            ..self
+        }
+    }
+
     /// Enables or disables the `ForceInterpreter` option.
     ///
     /// # Argument
@@ -516,6 +812,9 @@ impl Default for CommonConfigOptions {
     /// * threads: false,
     /// * tail_call: false,
     /// * function_references: false,
+    /// * annotations: false,
+    /// * memory64: false,
+    /// * exception_handling: false,
     /// * interpreter_mode: false,
     fn default() -> Self {
         Self::new()
@@ -547,7 +846,9 @@ impl Default for CommonConfigOptions {
 ///  The configuration options above are only effective to [AOT compiler](crate::Compiler).
 #[cfg(feature = "aot")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct CompilerConfigOptions {
     out_format: CompilerOutputFormat,
     opt_level: CompilerOptimizationLevel,
@@ -645,7 +946,9 @@ impl Default for CompilerConfigOptions {
 ///
 /// - `maximum_memory_page` limits the page size of [Memory](crate::Memory). This option is only effective to
 ///       [Executor](crate::Executor).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RuntimeConfigOptions {
     max_memory_pages: u32,
 }
@@ -683,7 +986,9 @@ impl Default for RuntimeConfigOptions {
 ///  - `measure_cost` determines if measuring the instruction costs when running a compiled or pure WASM.
 ///   
 ///  - `measure_time` determines if measuring the running time when running a compiled or pure WASM.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct StatisticsConfigOptions {
     count_instructions: bool,
     measure_cost: bool,
@@ -833,4 +1138,93 @@ mod tests {
         assert!(!config.time_measuring_enabled());
         assert_eq!(config_copied.max_memory_pages(), 1024);
     }
+
+    #[test]
+    fn test_config_proposals() {
+        let common_options = CommonConfigOptions::default()
+            .annotations(true)
+            .memory64(true)
+            .exception_handling(true)
+            .gc(true);
+        let result = ConfigBuilder::new(common_options).build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert!(config.annotations_enabled());
+        assert!(config.memory64_enabled());
+        assert!(config.exception_handling_enabled());
+        assert!(config.gc_enabled());
+        assert!(config.proposal_enabled(Proposal::Memory64));
+
+        // create a config with exactly the given proposals
+        let result = Config::from_features(&[Proposal::Simd, Proposal::ExceptionHandling]);
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(
+            config.proposals(),
+            vec![Proposal::Simd, Proposal::ExceptionHandling]
+        );
+        assert!(!config.bulk_memory_operations_enabled());
+
+        // display and parse proposals
+        for proposal in Proposal::all() {
+            assert_eq!(proposal.to_string().parse::<Proposal>().unwrap(), *proposal);
+        }
+        assert_eq!(Proposal::MultiMemories.to_string(), "multi-memories");
+        assert_eq!(
+            "Exception_Handling".parse::<Proposal>().unwrap(),
+            Proposal::ExceptionHandling
+        );
+        assert!("unknown".parse::<Proposal>().is_err());
+    }
+
+    #[test]
+    fn test_config_to_options() {
+        let common_options = CommonConfigOptions::default().simd(false).memory64(true);
+        let compiler_options =
+            CompilerConfigOptions::default().optimization_level(CompilerOptimizationLevel::O1);
+        let runtime_options = RuntimeConfigOptions::default().max_memory_pages(256);
+        let builder = ConfigBuilder::new(common_options)
+            .with_statistics_config(StatisticsConfigOptions::default())
+            .with_compiler_config(compiler_options)
+            .with_runtime_config(runtime_options);
+
+        let result = builder.clone().build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+
+        // the options of the config round-trip
+        let options = config.to_options();
+        assert_eq!(options, builder);
+        let result = options.build();
+        assert!(result.is_ok());
+        let copied = result.unwrap();
+        assert!(!copied.simd_enabled());
+        assert!(copied.memory64_enabled());
+        assert_eq!(copied.optimization_level(), CompilerOptimizationLevel::O1);
+        assert_eq!(copied.max_memory_pages(), 256);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_serde() {
+        let config = ConfigBuilder::new(CommonConfigOptions::default().threads(true))
+            .build()
+            .unwrap();
+        let options = config.to_options();
+
+        // serialize and deserialize the options
+        let json = serde_json::to_string(&options).unwrap();
+        let deserialized: ConfigBuilder = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, options);
+
+        // the missing options take their default values
+        let partial: ConfigBuilder = serde_json::from_str(
+            r#"{"common_config": {"memory64": true}, "runtime_config": {"max_memory_pages": 128}}"#,
+        )
+        .unwrap();
+        let config = partial.build().unwrap();
+        assert!(config.memory64_enabled());
+        assert!(config.simd_enabled());
+        assert_eq!(config.max_memory_pages(), 128);
+    }
 }
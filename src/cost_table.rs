@@ -0,0 +1,1092 @@
+//! Defines WasmEdge CostTable struct.
+
+use crate::{error::WasmEdgeError, utils::CoreVersion, WasmEdgeResult};
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+/// Defines the categories of WebAssembly instructions in a [CostTable].
+///
+/// Each instruction belongs to exactly one category. Memory accesses are in [OpcodeCategory::Memory] regardless of the value type, and all instructions of the [SIMD](https://github.com/WebAssembly/simd), [Threads](https://github.com/webassembly/threads) and [GC](https://github.com/WebAssembly/gc) proposals are in their own categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpcodeCategory {
+    /// The structured control instructions and branches, such as `block`, `br_if` and `return`.
+    Control,
+    /// The call instructions, such as `call`, `call_indirect` and `return_call`.
+    Call,
+    /// The exception handling instructions, such as `throw` and `try_table`.
+    Exception,
+    /// The parametric instructions `drop` and `select`.
+    Parametric,
+    /// The local and global variable instructions.
+    Variable,
+    /// The table instructions, such as `table.get` and `table.grow`.
+    Table,
+    /// The memory instructions, such as the loads, the stores and `memory.grow`.
+    Memory,
+    /// The integer numeric instructions, such as `i32.add` and `i64.extend_i32_s`.
+    Integer,
+    /// The floating-point numeric instructions, including the conversions from or to floating-point numbers, such as `f64.mul` and `i32.trunc_f32_s`.
+    Float,
+    /// The reference instructions, such as `ref.null` and `ref.func`.
+    Reference,
+    /// The SIMD and relaxed SIMD instructions.
+    Simd,
+    /// The atomic memory instructions.
+    Atomic,
+    /// The GC instructions, such as `struct.new` and `ref.i31`.
+    Gc,
+}
+impl OpcodeCategory {
+    const ALL: &'static [OpcodeCategory] = &[
+        OpcodeCategory::Control,
+        OpcodeCategory::Call,
+        OpcodeCategory::Exception,
+        OpcodeCategory::Parametric,
+        OpcodeCategory::Variable,
+        OpcodeCategory::Table,
+        OpcodeCategory::Memory,
+        OpcodeCategory::Integer,
+        OpcodeCategory::Float,
+        OpcodeCategory::Reference,
+        OpcodeCategory::Simd,
+        OpcodeCategory::Atomic,
+        OpcodeCategory::Gc,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            OpcodeCategory::Control => "control",
+            OpcodeCategory::Call => "call",
+            OpcodeCategory::Exception => "exception",
+            OpcodeCategory::Parametric => "parametric",
+            OpcodeCategory::Variable => "variable",
+            OpcodeCategory::Table => "table",
+            OpcodeCategory::Memory => "memory",
+            OpcodeCategory::Integer => "integer",
+            OpcodeCategory::Float => "float",
+            OpcodeCategory::Reference => "reference",
+            OpcodeCategory::Simd => "simd",
+            OpcodeCategory::Atomic => "atomic",
+            OpcodeCategory::Gc => "gc",
+        }
+    }
+}
+impl std::fmt::Display for OpcodeCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+impl FromStr for OpcodeCategory {
+    type Err = Box<WasmEdgeError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
+        OpcodeCategory::ALL
+            .iter()
+            .copied()
+            .find(|category| category.name() == name)
+            .ok_or_else(|| {
+                Box::new(WasmEdgeError::Operation(format!(
+                    "Unknown opcode category: {s}"
+                )))
+            })
+    }
+}
+
+/// Defines a builder of the cost table used by [Statistics::set_cost_table](crate::Statistics::set_cost_table), keyed by the instruction names in the WebAssembly text format.
+///
+/// The cost of an instruction is determined in the following order:
+///
+/// * the cost set for the instruction by [CostTable::with_opcode];
+///
+/// * the cost set for the category of the instruction by [CostTable::with_category]; if the category is set more than once, the last one wins;
+///
+/// * the default cost, which is `1` unless set by [CostTable::with_default].
+///
+/// The dense array returned by [CostTable::to_vec] follows the `OpCode` enumeration of the WasmEdge 0.14 runtime, which the C API does not expose, so it is refused for the runtimes of other versions.
+///
+/// # Example
+///
+/// ```rust
+/// use wasmedge_sdk::{CostTable, OpcodeCategory, Statistics};
+///
+/// let table = CostTable::new()
+///     .with_category(OpcodeCategory::Simd, 4)
+///     .with_category(OpcodeCategory::Float, 2)
+///     .with_opcode("memory.grow", 100)
+///     .unwrap()
+///     .with_opcode("call_indirect", 10)
+///     .unwrap();
+/// assert_eq!(table.cost_of("memory.grow"), Some(100));
+/// assert_eq!(table.cost_of("f32.add"), Some(2));
+/// assert_eq!(table.cost_of("i32.add"), Some(1));
+///
+/// let mut stat = Statistics::new().unwrap();
+/// stat.set_cost_table(table.to_vec().unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostTable {
+    default: u64,
+    categories: Vec<(OpcodeCategory, u64)>,
+    opcodes: HashMap<String, u64>,
+}
+impl CostTable {
+    /// Creates a new [CostTable] in which every instruction costs `1`.
+    pub fn new() -> Self {
+        Self {
+            default: 1,
+            categories: Vec::new(),
+            opcodes: HashMap::new(),
+        }
+    }
+
+    /// Sets the cost of the instructions not covered by any category or instruction setting.
+    ///
+    /// # Argument
+    ///
+    /// * `cost` - The default cost.
+    pub fn with_default(self, cost: u64) -> Self {
+        Self {
+            default: cost,
+            ..self
+        }
+    }
+
+    /// Sets the cost of all instructions in the given category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the instructions.
+    ///
+    /// * `cost` - The cost of each instruction in the category.
+    pub fn with_category(mut self, category: OpcodeCategory, cost: u64) -> Self {
+        self.categories.retain(|(c, _)| *c != category);
+        self.categories.push((category, cost));
+        self
+    }
+
+    /// Sets the cost of the instruction with the given name in the WebAssembly text format, such as `memory.grow` or `i32.add`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the instruction.
+    ///
+    /// * `cost` - The cost of the instruction.
+    ///
+    /// # Error
+    ///
+    /// If the instruction is unknown, then an error is returned.
+    pub fn with_opcode(mut self, name: impl AsRef<str>, cost: u64) -> WasmEdgeResult<Self> {
+        let name = name.as_ref();
+        if !OPCODES.iter().any(|(opcode, _)| *opcode == name) {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "Unknown WebAssembly instruction: {name}"
+            ))));
+        }
+        self.opcodes.insert(name.to_string(), cost);
+
+        Ok(self)
+    }
+
+    /// Returns the cost of the instruction with the given name, or `None` if the instruction is unknown.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the instruction in the WebAssembly text format.
+    pub fn cost_of(&self, name: impl AsRef<str>) -> Option<u64> {
+        let name = name.as_ref();
+        OPCODES
+            .iter()
+            .find(|(opcode, _)| *opcode == name)
+            .map(|(opcode, category)| self.cost(opcode, *category))
+    }
+
+    /// Returns the dense cost array indexed by the WasmEdge opcode numbering, which can be passed to [Statistics::set_cost_table](crate::Statistics::set_cost_table).
+    ///
+    /// # Error
+    ///
+    /// If the version of the WasmEdge runtime is not 0.14, whose opcode numbering the array follows, then an error is returned.
+    pub fn to_vec(&self) -> WasmEdgeResult<Vec<u64>> {
+        let (major, minor) = OPCODES_VERSION;
+        if CoreVersion::major() != major || CoreVersion::minor() != minor {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "The cost table follows the opcode numbering of WasmEdge {major}.{minor}, but the runtime is WasmEdge {}",
+                CoreVersion::version_string()
+            ))));
+        }
+
+        Ok(OPCODES
+            .iter()
+            .map(|(opcode, category)| self.cost(opcode, *category))
+            .collect())
+    }
+
+    /// Loads a [CostTable] from the given file. See [CostTable::from_str] for the format.
+    ///
+    /// # Argument
+    ///
+    /// * `path` - The path to the cost table file.
+    ///
+    /// # Error
+    ///
+    /// If fail to read or parse the file, then an error is returned.
+    pub fn from_file(path: impl AsRef<Path>) -> WasmEdgeResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            WasmEdgeError::Operation(format!(
+                "Failed to read the cost table {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        content.parse()
+    }
+
+    fn cost(&self, opcode: &str, category: OpcodeCategory) -> u64 {
+        if let Some(cost) = self.opcodes.get(opcode) {
+            return *cost;
+        }
+        self.categories
+            .iter()
+            .find(|(c, _)| *c == category)
+            .map(|(_, cost)| *cost)
+            .unwrap_or(self.default)
+    }
+}
+impl Default for CostTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl FromStr for CostTable {
+    type Err = Box<WasmEdgeError>;
+
+    /// Parses a [CostTable] from the text with one `key = cost` entry per line. A key is `default`, a category name prefixed with `@` such as `@simd`, or an instruction name such as `memory.grow`. Blank lines and the lines starting with `#` are ignored.
+    ///
+    /// ```text
+    /// # every instruction costs 1 unless specified below
+    /// default = 1
+    /// @simd = 4
+    /// memory.grow = 100
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table = CostTable::new();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                Box::new(WasmEdgeError::Operation(format!(
+                    "Invalid cost table entry at line {}: {line}",
+                    index + 1
+                )))
+            };
+            let (key, cost) = line.split_once('=').ok_or_else(invalid)?;
+            let cost: u64 = cost.trim().parse().map_err(|_| invalid())?;
+            let key = key.trim();
+            table = match key {
+                "default" => table.with_default(cost),
+                _ => match key.strip_prefix('@') {
+                    Some(category) => table.with_category(category.parse()?, cost),
+                    None => table.with_opcode(key, cost)?,
+                },
+            };
+        }
+
+        Ok(table)
+    }
+}
+
+/// The major and minor version of the WasmEdge runtime whose `OpCode` enumeration [OPCODES] follows.
+const OPCODES_VERSION: (u32, u32) = (0, 14);
+
+/// The WebAssembly instructions in the order of the `OpCode` enumeration of the WasmEdge runtime, which follows the binary encoding of the instructions: the single-byte opcodes first, then the `0xFB`, `0xFC`, `0xFD` and `0xFE` prefixed ones. The instructions sharing a name, such as the two forms of `select`, have separate entries.
+const OPCODES: &[(&str, OpcodeCategory)] = &[
+    ("unreachable", OpcodeCategory::Control),
+    ("nop", OpcodeCategory::Control),
+    ("block", OpcodeCategory::Control),
+    ("loop", OpcodeCategory::Control),
+    ("if", OpcodeCategory::Control),
+    ("else", OpcodeCategory::Control),
+    ("try", OpcodeCategory::Exception),
+    ("catch", OpcodeCategory::Exception),
+    ("throw", OpcodeCategory::Exception),
+    ("rethrow", OpcodeCategory::Exception),
+    ("throw_ref", OpcodeCategory::Exception),
+    ("end", OpcodeCategory::Control),
+    ("br", OpcodeCategory::Control),
+    ("br_if", OpcodeCategory::Control),
+    ("br_table", OpcodeCategory::Control),
+    ("return", OpcodeCategory::Control),
+    ("call", OpcodeCategory::Call),
+    ("call_indirect", OpcodeCategory::Call),
+    ("return_call", OpcodeCategory::Call),
+    ("return_call_indirect", OpcodeCategory::Call),
+    ("call_ref", OpcodeCategory::Call),
+    ("return_call_ref", OpcodeCategory::Call),
+    ("delegate", OpcodeCategory::Exception),
+    ("catch_all", OpcodeCategory::Exception),
+    ("drop", OpcodeCategory::Parametric),
+    ("select", OpcodeCategory::Parametric),
+    ("select", OpcodeCategory::Parametric),
+    ("try_table", OpcodeCategory::Exception),
+    ("local.get", OpcodeCategory::Variable),
+    ("local.set", OpcodeCategory::Variable),
+    ("local.tee", OpcodeCategory::Variable),
+    ("global.get", OpcodeCategory::Variable),
+    ("global.set", OpcodeCategory::Variable),
+    ("table.get", OpcodeCategory::Table),
+    ("table.set", OpcodeCategory::Table),
+    ("i32.load", OpcodeCategory::Memory),
+    ("i64.load", OpcodeCategory::Memory),
+    ("f32.load", OpcodeCategory::Memory),
+    ("f64.load", OpcodeCategory::Memory),
+    ("i32.load8_s", OpcodeCategory::Memory),
+    ("i32.load8_u", OpcodeCategory::Memory),
+    ("i32.load16_s", OpcodeCategory::Memory),
+    ("i32.load16_u", OpcodeCategory::Memory),
+    ("i64.load8_s", OpcodeCategory::Memory),
+    ("i64.load8_u", OpcodeCategory::Memory),
+    ("i64.load16_s", OpcodeCategory::Memory),
+    ("i64.load16_u", OpcodeCategory::Memory),
+    ("i64.load32_s", OpcodeCategory::Memory),
+    ("i64.load32_u", OpcodeCategory::Memory),
+    ("i32.store", OpcodeCategory::Memory),
+    ("i64.store", OpcodeCategory::Memory),
+    ("f32.store", OpcodeCategory::Memory),
+    ("f64.store", OpcodeCategory::Memory),
+    ("i32.store8", OpcodeCategory::Memory),
+    ("i32.store16", OpcodeCategory::Memory),
+    ("i64.store8", OpcodeCategory::Memory),
+    ("i64.store16", OpcodeCategory::Memory),
+    ("i64.store32", OpcodeCategory::Memory),
+    ("memory.size", OpcodeCategory::Memory),
+    ("memory.grow", OpcodeCategory::Memory),
+    ("i32.const", OpcodeCategory::Integer),
+    ("i64.const", OpcodeCategory::Integer),
+    ("f32.const", OpcodeCategory::Float),
+    ("f64.const", OpcodeCategory::Float),
+    ("i32.eqz", OpcodeCategory::Integer),
+    ("i32.eq", OpcodeCategory::Integer),
+    ("i32.ne", OpcodeCategory::Integer),
+    ("i32.lt_s", OpcodeCategory::Integer),
+    ("i32.lt_u", OpcodeCategory::Integer),
+    ("i32.gt_s", OpcodeCategory::Integer),
+    ("i32.gt_u", OpcodeCategory::Integer),
+    ("i32.le_s", OpcodeCategory::Integer),
+    ("i32.le_u", OpcodeCategory::Integer),
+    ("i32.ge_s", OpcodeCategory::Integer),
+    ("i32.ge_u", OpcodeCategory::Integer),
+    ("i64.eqz", OpcodeCategory::Integer),
+    ("i64.eq", OpcodeCategory::Integer),
+    ("i64.ne", OpcodeCategory::Integer),
+    ("i64.lt_s", OpcodeCategory::Integer),
+    ("i64.lt_u", OpcodeCategory::Integer),
+    ("i64.gt_s", OpcodeCategory::Integer),
+    ("i64.gt_u", OpcodeCategory::Integer),
+    ("i64.le_s", OpcodeCategory::Integer),
+    ("i64.le_u", OpcodeCategory::Integer),
+    ("i64.ge_s", OpcodeCategory::Integer),
+    ("i64.ge_u", OpcodeCategory::Integer),
+    ("f32.eq", OpcodeCategory::Float),
+    ("f32.ne", OpcodeCategory::Float),
+    ("f32.lt", OpcodeCategory::Float),
+    ("f32.gt", OpcodeCategory::Float),
+    ("f32.le", OpcodeCategory::Float),
+    ("f32.ge", OpcodeCategory::Float),
+    ("f64.eq", OpcodeCategory::Float),
+    ("f64.ne", OpcodeCategory::Float),
+    ("f64.lt", OpcodeCategory::Float),
+    ("f64.gt", OpcodeCategory::Float),
+    ("f64.le", OpcodeCategory::Float),
+    ("f64.ge", OpcodeCategory::Float),
+    ("i32.clz", OpcodeCategory::Integer),
+    ("i32.ctz", OpcodeCategory::Integer),
+    ("i32.popcnt", OpcodeCategory::Integer),
+    ("i32.add", OpcodeCategory::Integer),
+    ("i32.sub", OpcodeCategory::Integer),
+    ("i32.mul", OpcodeCategory::Integer),
+    ("i32.div_s", OpcodeCategory::Integer),
+    ("i32.div_u", OpcodeCategory::Integer),
+    ("i32.rem_s", OpcodeCategory::Integer),
+    ("i32.rem_u", OpcodeCategory::Integer),
+    ("i32.and", OpcodeCategory::Integer),
+    ("i32.or", OpcodeCategory::Integer),
+    ("i32.xor", OpcodeCategory::Integer),
+    ("i32.shl", OpcodeCategory::Integer),
+    ("i32.shr_s", OpcodeCategory::Integer),
+    ("i32.shr_u", OpcodeCategory::Integer),
+    ("i32.rotl", OpcodeCategory::Integer),
+    ("i32.rotr", OpcodeCategory::Integer),
+    ("i64.clz", OpcodeCategory::Integer),
+    ("i64.ctz", OpcodeCategory::Integer),
+    ("i64.popcnt", OpcodeCategory::Integer),
+    ("i64.add", OpcodeCategory::Integer),
+    ("i64.sub", OpcodeCategory::Integer),
+    ("i64.mul", OpcodeCategory::Integer),
+    ("i64.div_s", OpcodeCategory::Integer),
+    ("i64.div_u", OpcodeCategory::Integer),
+    ("i64.rem_s", OpcodeCategory::Integer),
+    ("i64.rem_u", OpcodeCategory::Integer),
+    ("i64.and", OpcodeCategory::Integer),
+    ("i64.or", OpcodeCategory::Integer),
+    ("i64.xor", OpcodeCategory::Integer),
+    ("i64.shl", OpcodeCategory::Integer),
+    ("i64.shr_s", OpcodeCategory::Integer),
+    ("i64.shr_u", OpcodeCategory::Integer),
+    ("i64.rotl", OpcodeCategory::Integer),
+    ("i64.rotr", OpcodeCategory::Integer),
+    ("f32.abs", OpcodeCategory::Float),
+    ("f32.neg", OpcodeCategory::Float),
+    ("f32.ceil", OpcodeCategory::Float),
+    ("f32.floor", OpcodeCategory::Float),
+    ("f32.trunc", OpcodeCategory::Float),
+    ("f32.nearest", OpcodeCategory::Float),
+    ("f32.sqrt", OpcodeCategory::Float),
+    ("f32.add", OpcodeCategory::Float),
+    ("f32.sub", OpcodeCategory::Float),
+    ("f32.mul", OpcodeCategory::Float),
+    ("f32.div", OpcodeCategory::Float),
+    ("f32.min", OpcodeCategory::Float),
+    ("f32.max", OpcodeCategory::Float),
+    ("f32.copysign", OpcodeCategory::Float),
+    ("f64.abs", OpcodeCategory::Float),
+    ("f64.neg", OpcodeCategory::Float),
+    ("f64.ceil", OpcodeCategory::Float),
+    ("f64.floor", OpcodeCategory::Float),
+    ("f64.trunc", OpcodeCategory::Float),
+    ("f64.nearest", OpcodeCategory::Float),
+    ("f64.sqrt", OpcodeCategory::Float),
+    ("f64.add", OpcodeCategory::Float),
+    ("f64.sub", OpcodeCategory::Float),
+    ("f64.mul", OpcodeCategory::Float),
+    ("f64.div", OpcodeCategory::Float),
+    ("f64.min", OpcodeCategory::Float),
+    ("f64.max", OpcodeCategory::Float),
+    ("f64.copysign", OpcodeCategory::Float),
+    ("i32.wrap_i64", OpcodeCategory::Integer),
+    ("i32.trunc_f32_s", OpcodeCategory::Float),
+    ("i32.trunc_f32_u", OpcodeCategory::Float),
+    ("i32.trunc_f64_s", OpcodeCategory::Float),
+    ("i32.trunc_f64_u", OpcodeCategory::Float),
+    ("i64.extend_i32_s", OpcodeCategory::Integer),
+    ("i64.extend_i32_u", OpcodeCategory::Integer),
+    ("i64.trunc_f32_s", OpcodeCategory::Float),
+    ("i64.trunc_f32_u", OpcodeCategory::Float),
+    ("i64.trunc_f64_s", OpcodeCategory::Float),
+    ("i64.trunc_f64_u", OpcodeCategory::Float),
+    ("f32.convert_i32_s", OpcodeCategory::Float),
+    ("f32.convert_i32_u", OpcodeCategory::Float),
+    ("f32.convert_i64_s", OpcodeCategory::Float),
+    ("f32.convert_i64_u", OpcodeCategory::Float),
+    ("f32.demote_f64", OpcodeCategory::Float),
+    ("f64.convert_i32_s", OpcodeCategory::Float),
+    ("f64.convert_i32_u", OpcodeCategory::Float),
+    ("f64.convert_i64_s", OpcodeCategory::Float),
+    ("f64.convert_i64_u", OpcodeCategory::Float),
+    ("f64.promote_f32", OpcodeCategory::Float),
+    ("i32.reinterpret_f32", OpcodeCategory::Float),
+    ("i64.reinterpret_f64", OpcodeCategory::Float),
+    ("f32.reinterpret_i32", OpcodeCategory::Float),
+    ("f64.reinterpret_i64", OpcodeCategory::Float),
+    ("i32.extend8_s", OpcodeCategory::Integer),
+    ("i32.extend16_s", OpcodeCategory::Integer),
+    ("i64.extend8_s", OpcodeCategory::Integer),
+    ("i64.extend16_s", OpcodeCategory::Integer),
+    ("i64.extend32_s", OpcodeCategory::Integer),
+    ("ref.null", OpcodeCategory::Reference),
+    ("ref.is_null", OpcodeCategory::Reference),
+    ("ref.func", OpcodeCategory::Reference),
+    ("ref.eq", OpcodeCategory::Reference),
+    ("ref.as_non_null", OpcodeCategory::Reference),
+    ("br_on_null", OpcodeCategory::Control),
+    ("br_on_non_null", OpcodeCategory::Control),
+    ("struct.new", OpcodeCategory::Gc),
+    ("struct.new_default", OpcodeCategory::Gc),
+    ("struct.get", OpcodeCategory::Gc),
+    ("struct.get_s", OpcodeCategory::Gc),
+    ("struct.get_u", OpcodeCategory::Gc),
+    ("struct.set", OpcodeCategory::Gc),
+    ("array.new", OpcodeCategory::Gc),
+    ("array.new_default", OpcodeCategory::Gc),
+    ("array.new_fixed", OpcodeCategory::Gc),
+    ("array.new_data", OpcodeCategory::Gc),
+    ("array.new_elem", OpcodeCategory::Gc),
+    ("array.get", OpcodeCategory::Gc),
+    ("array.get_s", OpcodeCategory::Gc),
+    ("array.get_u", OpcodeCategory::Gc),
+    ("array.set", OpcodeCategory::Gc),
+    ("array.len", OpcodeCategory::Gc),
+    ("array.fill", OpcodeCategory::Gc),
+    ("array.copy", OpcodeCategory::Gc),
+    ("array.init_data", OpcodeCategory::Gc),
+    ("array.init_elem", OpcodeCategory::Gc),
+    ("ref.test", OpcodeCategory::Gc),
+    ("ref.test", OpcodeCategory::Gc),
+    ("ref.cast", OpcodeCategory::Gc),
+    ("ref.cast", OpcodeCategory::Gc),
+    ("br_on_cast", OpcodeCategory::Gc),
+    ("br_on_cast_fail", OpcodeCategory::Gc),
+    ("any.convert_extern", OpcodeCategory::Gc),
+    ("extern.convert_any", OpcodeCategory::Gc),
+    ("ref.i31", OpcodeCategory::Gc),
+    ("i31.get_s", OpcodeCategory::Gc),
+    ("i31.get_u", OpcodeCategory::Gc),
+    ("i32.trunc_sat_f32_s", OpcodeCategory::Float),
+    ("i32.trunc_sat_f32_u", OpcodeCategory::Float),
+    ("i32.trunc_sat_f64_s", OpcodeCategory::Float),
+    ("i32.trunc_sat_f64_u", OpcodeCategory::Float),
+    ("i64.trunc_sat_f32_s", OpcodeCategory::Float),
+    ("i64.trunc_sat_f32_u", OpcodeCategory::Float),
+    ("i64.trunc_sat_f64_s", OpcodeCategory::Float),
+    ("i64.trunc_sat_f64_u", OpcodeCategory::Float),
+    ("memory.init", OpcodeCategory::Memory),
+    ("data.drop", OpcodeCategory::Memory),
+    ("memory.copy", OpcodeCategory::Memory),
+    ("memory.fill", OpcodeCategory::Memory),
+    ("table.init", OpcodeCategory::Table),
+    ("elem.drop", OpcodeCategory::Table),
+    ("table.copy", OpcodeCategory::Table),
+    ("table.grow", OpcodeCategory::Table),
+    ("table.size", OpcodeCategory::Table),
+    ("table.fill", OpcodeCategory::Table),
+    ("v128.load", OpcodeCategory::Simd),
+    ("v128.load8x8_s", OpcodeCategory::Simd),
+    ("v128.load8x8_u", OpcodeCategory::Simd),
+    ("v128.load16x4_s", OpcodeCategory::Simd),
+    ("v128.load16x4_u", OpcodeCategory::Simd),
+    ("v128.load32x2_s", OpcodeCategory::Simd),
+    ("v128.load32x2_u", OpcodeCategory::Simd),
+    ("v128.load8_splat", OpcodeCategory::Simd),
+    ("v128.load16_splat", OpcodeCategory::Simd),
+    ("v128.load32_splat", OpcodeCategory::Simd),
+    ("v128.load64_splat", OpcodeCategory::Simd),
+    ("v128.store", OpcodeCategory::Simd),
+    ("v128.const", OpcodeCategory::Simd),
+    ("i8x16.shuffle", OpcodeCategory::Simd),
+    ("i8x16.swizzle", OpcodeCategory::Simd),
+    ("i8x16.splat", OpcodeCategory::Simd),
+    ("i16x8.splat", OpcodeCategory::Simd),
+    ("i32x4.splat", OpcodeCategory::Simd),
+    ("i64x2.splat", OpcodeCategory::Simd),
+    ("f32x4.splat", OpcodeCategory::Simd),
+    ("f64x2.splat", OpcodeCategory::Simd),
+    ("i8x16.extract_lane_s", OpcodeCategory::Simd),
+    ("i8x16.extract_lane_u", OpcodeCategory::Simd),
+    ("i8x16.replace_lane", OpcodeCategory::Simd),
+    ("i16x8.extract_lane_s", OpcodeCategory::Simd),
+    ("i16x8.extract_lane_u", OpcodeCategory::Simd),
+    ("i16x8.replace_lane", OpcodeCategory::Simd),
+    ("i32x4.extract_lane", OpcodeCategory::Simd),
+    ("i32x4.replace_lane", OpcodeCategory::Simd),
+    ("i64x2.extract_lane", OpcodeCategory::Simd),
+    ("i64x2.replace_lane", OpcodeCategory::Simd),
+    ("f32x4.extract_lane", OpcodeCategory::Simd),
+    ("f32x4.replace_lane", OpcodeCategory::Simd),
+    ("f64x2.extract_lane", OpcodeCategory::Simd),
+    ("f64x2.replace_lane", OpcodeCategory::Simd),
+    ("i8x16.eq", OpcodeCategory::Simd),
+    ("i8x16.ne", OpcodeCategory::Simd),
+    ("i8x16.lt_s", OpcodeCategory::Simd),
+    ("i8x16.lt_u", OpcodeCategory::Simd),
+    ("i8x16.gt_s", OpcodeCategory::Simd),
+    ("i8x16.gt_u", OpcodeCategory::Simd),
+    ("i8x16.le_s", OpcodeCategory::Simd),
+    ("i8x16.le_u", OpcodeCategory::Simd),
+    ("i8x16.ge_s", OpcodeCategory::Simd),
+    ("i8x16.ge_u", OpcodeCategory::Simd),
+    ("i16x8.eq", OpcodeCategory::Simd),
+    ("i16x8.ne", OpcodeCategory::Simd),
+    ("i16x8.lt_s", OpcodeCategory::Simd),
+    ("i16x8.lt_u", OpcodeCategory::Simd),
+    ("i16x8.gt_s", OpcodeCategory::Simd),
+    ("i16x8.gt_u", OpcodeCategory::Simd),
+    ("i16x8.le_s", OpcodeCategory::Simd),
+    ("i16x8.le_u", OpcodeCategory::Simd),
+    ("i16x8.ge_s", OpcodeCategory::Simd),
+    ("i16x8.ge_u", OpcodeCategory::Simd),
+    ("i32x4.eq", OpcodeCategory::Simd),
+    ("i32x4.ne", OpcodeCategory::Simd),
+    ("i32x4.lt_s", OpcodeCategory::Simd),
+    ("i32x4.lt_u", OpcodeCategory::Simd),
+    ("i32x4.gt_s", OpcodeCategory::Simd),
+    ("i32x4.gt_u", OpcodeCategory::Simd),
+    ("i32x4.le_s", OpcodeCategory::Simd),
+    ("i32x4.le_u", OpcodeCategory::Simd),
+    ("i32x4.ge_s", OpcodeCategory::Simd),
+    ("i32x4.ge_u", OpcodeCategory::Simd),
+    ("f32x4.eq", OpcodeCategory::Simd),
+    ("f32x4.ne", OpcodeCategory::Simd),
+    ("f32x4.lt", OpcodeCategory::Simd),
+    ("f32x4.gt", OpcodeCategory::Simd),
+    ("f32x4.le", OpcodeCategory::Simd),
+    ("f32x4.ge", OpcodeCategory::Simd),
+    ("f64x2.eq", OpcodeCategory::Simd),
+    ("f64x2.ne", OpcodeCategory::Simd),
+    ("f64x2.lt", OpcodeCategory::Simd),
+    ("f64x2.gt", OpcodeCategory::Simd),
+    ("f64x2.le", OpcodeCategory::Simd),
+    ("f64x2.ge", OpcodeCategory::Simd),
+    ("v128.not", OpcodeCategory::Simd),
+    ("v128.and", OpcodeCategory::Simd),
+    ("v128.andnot", OpcodeCategory::Simd),
+    ("v128.or", OpcodeCategory::Simd),
+    ("v128.xor", OpcodeCategory::Simd),
+    ("v128.bitselect", OpcodeCategory::Simd),
+    ("v128.any_true", OpcodeCategory::Simd),
+    ("v128.load8_lane", OpcodeCategory::Simd),
+    ("v128.load16_lane", OpcodeCategory::Simd),
+    ("v128.load32_lane", OpcodeCategory::Simd),
+    ("v128.load64_lane", OpcodeCategory::Simd),
+    ("v128.store8_lane", OpcodeCategory::Simd),
+    ("v128.store16_lane", OpcodeCategory::Simd),
+    ("v128.store32_lane", OpcodeCategory::Simd),
+    ("v128.store64_lane", OpcodeCategory::Simd),
+    ("v128.load32_zero", OpcodeCategory::Simd),
+    ("v128.load64_zero", OpcodeCategory::Simd),
+    ("f32x4.demote_f64x2_zero", OpcodeCategory::Simd),
+    ("f64x2.promote_low_f32x4", OpcodeCategory::Simd),
+    ("i8x16.abs", OpcodeCategory::Simd),
+    ("i8x16.neg", OpcodeCategory::Simd),
+    ("i8x16.popcnt", OpcodeCategory::Simd),
+    ("i8x16.all_true", OpcodeCategory::Simd),
+    ("i8x16.bitmask", OpcodeCategory::Simd),
+    ("i8x16.narrow_i16x8_s", OpcodeCategory::Simd),
+    ("i8x16.narrow_i16x8_u", OpcodeCategory::Simd),
+    ("f32x4.ceil", OpcodeCategory::Simd),
+    ("f32x4.floor", OpcodeCategory::Simd),
+    ("f32x4.trunc", OpcodeCategory::Simd),
+    ("f32x4.nearest", OpcodeCategory::Simd),
+    ("i8x16.shl", OpcodeCategory::Simd),
+    ("i8x16.shr_s", OpcodeCategory::Simd),
+    ("i8x16.shr_u", OpcodeCategory::Simd),
+    ("i8x16.add", OpcodeCategory::Simd),
+    ("i8x16.add_sat_s", OpcodeCategory::Simd),
+    ("i8x16.add_sat_u", OpcodeCategory::Simd),
+    ("i8x16.sub", OpcodeCategory::Simd),
+    ("i8x16.sub_sat_s", OpcodeCategory::Simd),
+    ("i8x16.sub_sat_u", OpcodeCategory::Simd),
+    ("f64x2.ceil", OpcodeCategory::Simd),
+    ("f64x2.floor", OpcodeCategory::Simd),
+    ("i8x16.min_s", OpcodeCategory::Simd),
+    ("i8x16.min_u", OpcodeCategory::Simd),
+    ("i8x16.max_s", OpcodeCategory::Simd),
+    ("i8x16.max_u", OpcodeCategory::Simd),
+    ("f64x2.trunc", OpcodeCategory::Simd),
+    ("i8x16.avgr_u", OpcodeCategory::Simd),
+    ("i16x8.extadd_pairwise_i8x16_s", OpcodeCategory::Simd),
+    ("i16x8.extadd_pairwise_i8x16_u", OpcodeCategory::Simd),
+    ("i32x4.extadd_pairwise_i16x8_s", OpcodeCategory::Simd),
+    ("i32x4.extadd_pairwise_i16x8_u", OpcodeCategory::Simd),
+    ("i16x8.abs", OpcodeCategory::Simd),
+    ("i16x8.neg", OpcodeCategory::Simd),
+    ("i16x8.q15mulr_sat_s", OpcodeCategory::Simd),
+    ("i16x8.all_true", OpcodeCategory::Simd),
+    ("i16x8.bitmask", OpcodeCategory::Simd),
+    ("i16x8.narrow_i32x4_s", OpcodeCategory::Simd),
+    ("i16x8.narrow_i32x4_u", OpcodeCategory::Simd),
+    ("i16x8.extend_low_i8x16_s", OpcodeCategory::Simd),
+    ("i16x8.extend_high_i8x16_s", OpcodeCategory::Simd),
+    ("i16x8.extend_low_i8x16_u", OpcodeCategory::Simd),
+    ("i16x8.extend_high_i8x16_u", OpcodeCategory::Simd),
+    ("i16x8.shl", OpcodeCategory::Simd),
+    ("i16x8.shr_s", OpcodeCategory::Simd),
+    ("i16x8.shr_u", OpcodeCategory::Simd),
+    ("i16x8.add", OpcodeCategory::Simd),
+    ("i16x8.add_sat_s", OpcodeCategory::Simd),
+    ("i16x8.add_sat_u", OpcodeCategory::Simd),
+    ("i16x8.sub", OpcodeCategory::Simd),
+    ("i16x8.sub_sat_s", OpcodeCategory::Simd),
+    ("i16x8.sub_sat_u", OpcodeCategory::Simd),
+    ("f64x2.nearest", OpcodeCategory::Simd),
+    ("i16x8.mul", OpcodeCategory::Simd),
+    ("i16x8.min_s", OpcodeCategory::Simd),
+    ("i16x8.min_u", OpcodeCategory::Simd),
+    ("i16x8.max_s", OpcodeCategory::Simd),
+    ("i16x8.max_u", OpcodeCategory::Simd),
+    ("i16x8.avgr_u", OpcodeCategory::Simd),
+    ("i16x8.extmul_low_i8x16_s", OpcodeCategory::Simd),
+    ("i16x8.extmul_high_i8x16_s", OpcodeCategory::Simd),
+    ("i16x8.extmul_low_i8x16_u", OpcodeCategory::Simd),
+    ("i16x8.extmul_high_i8x16_u", OpcodeCategory::Simd),
+    ("i32x4.abs", OpcodeCategory::Simd),
+    ("i32x4.neg", OpcodeCategory::Simd),
+    ("i32x4.all_true", OpcodeCategory::Simd),
+    ("i32x4.bitmask", OpcodeCategory::Simd),
+    ("i32x4.extend_low_i16x8_s", OpcodeCategory::Simd),
+    ("i32x4.extend_high_i16x8_s", OpcodeCategory::Simd),
+    ("i32x4.extend_low_i16x8_u", OpcodeCategory::Simd),
+    ("i32x4.extend_high_i16x8_u", OpcodeCategory::Simd),
+    ("i32x4.shl", OpcodeCategory::Simd),
+    ("i32x4.shr_s", OpcodeCategory::Simd),
+    ("i32x4.shr_u", OpcodeCategory::Simd),
+    ("i32x4.add", OpcodeCategory::Simd),
+    ("i32x4.sub", OpcodeCategory::Simd),
+    ("i32x4.mul", OpcodeCategory::Simd),
+    ("i32x4.min_s", OpcodeCategory::Simd),
+    ("i32x4.min_u", OpcodeCategory::Simd),
+    ("i32x4.max_s", OpcodeCategory::Simd),
+    ("i32x4.max_u", OpcodeCategory::Simd),
+    ("i32x4.dot_i16x8_s", OpcodeCategory::Simd),
+    ("i32x4.extmul_low_i16x8_s", OpcodeCategory::Simd),
+    ("i32x4.extmul_high_i16x8_s", OpcodeCategory::Simd),
+    ("i32x4.extmul_low_i16x8_u", OpcodeCategory::Simd),
+    ("i32x4.extmul_high_i16x8_u", OpcodeCategory::Simd),
+    ("i64x2.abs", OpcodeCategory::Simd),
+    ("i64x2.neg", OpcodeCategory::Simd),
+    ("i64x2.all_true", OpcodeCategory::Simd),
+    ("i64x2.bitmask", OpcodeCategory::Simd),
+    ("i64x2.extend_low_i32x4_s", OpcodeCategory::Simd),
+    ("i64x2.extend_high_i32x4_s", OpcodeCategory::Simd),
+    ("i64x2.extend_low_i32x4_u", OpcodeCategory::Simd),
+    ("i64x2.extend_high_i32x4_u", OpcodeCategory::Simd),
+    ("i64x2.shl", OpcodeCategory::Simd),
+    ("i64x2.shr_s", OpcodeCategory::Simd),
+    ("i64x2.shr_u", OpcodeCategory::Simd),
+    ("i64x2.add", OpcodeCategory::Simd),
+    ("i64x2.sub", OpcodeCategory::Simd),
+    ("i64x2.mul", OpcodeCategory::Simd),
+    ("i64x2.eq", OpcodeCategory::Simd),
+    ("i64x2.ne", OpcodeCategory::Simd),
+    ("i64x2.lt_s", OpcodeCategory::Simd),
+    ("i64x2.gt_s", OpcodeCategory::Simd),
+    ("i64x2.le_s", OpcodeCategory::Simd),
+    ("i64x2.ge_s", OpcodeCategory::Simd),
+    ("i64x2.extmul_low_i32x4_s", OpcodeCategory::Simd),
+    ("i64x2.extmul_high_i32x4_s", OpcodeCategory::Simd),
+    ("i64x2.extmul_low_i32x4_u", OpcodeCategory::Simd),
+    ("i64x2.extmul_high_i32x4_u", OpcodeCategory::Simd),
+    ("f32x4.abs", OpcodeCategory::Simd),
+    ("f32x4.neg", OpcodeCategory::Simd),
+    ("f32x4.sqrt", OpcodeCategory::Simd),
+    ("f32x4.add", OpcodeCategory::Simd),
+    ("f32x4.sub", OpcodeCategory::Simd),
+    ("f32x4.mul", OpcodeCategory::Simd),
+    ("f32x4.div", OpcodeCategory::Simd),
+    ("f32x4.min", OpcodeCategory::Simd),
+    ("f32x4.max", OpcodeCategory::Simd),
+    ("f32x4.pmin", OpcodeCategory::Simd),
+    ("f32x4.pmax", OpcodeCategory::Simd),
+    ("f64x2.abs", OpcodeCategory::Simd),
+    ("f64x2.neg", OpcodeCategory::Simd),
+    ("f64x2.sqrt", OpcodeCategory::Simd),
+    ("f64x2.add", OpcodeCategory::Simd),
+    ("f64x2.sub", OpcodeCategory::Simd),
+    ("f64x2.mul", OpcodeCategory::Simd),
+    ("f64x2.div", OpcodeCategory::Simd),
+    ("f64x2.min", OpcodeCategory::Simd),
+    ("f64x2.max", OpcodeCategory::Simd),
+    ("f64x2.pmin", OpcodeCategory::Simd),
+    ("f64x2.pmax", OpcodeCategory::Simd),
+    ("i32x4.trunc_sat_f32x4_s", OpcodeCategory::Simd),
+    ("i32x4.trunc_sat_f32x4_u", OpcodeCategory::Simd),
+    ("f32x4.convert_i32x4_s", OpcodeCategory::Simd),
+    ("f32x4.convert_i32x4_u", OpcodeCategory::Simd),
+    ("i32x4.trunc_sat_f64x2_s_zero", OpcodeCategory::Simd),
+    ("i32x4.trunc_sat_f64x2_u_zero", OpcodeCategory::Simd),
+    ("f64x2.convert_low_i32x4_s", OpcodeCategory::Simd),
+    ("f64x2.convert_low_i32x4_u", OpcodeCategory::Simd),
+    ("i8x16.relaxed_swizzle", OpcodeCategory::Simd),
+    ("i32x4.relaxed_trunc_f32x4_s", OpcodeCategory::Simd),
+    ("i32x4.relaxed_trunc_f32x4_u", OpcodeCategory::Simd),
+    ("i32x4.relaxed_trunc_f64x2_s_zero", OpcodeCategory::Simd),
+    ("i32x4.relaxed_trunc_f64x2_u_zero", OpcodeCategory::Simd),
+    ("f32x4.relaxed_madd", OpcodeCategory::Simd),
+    ("f32x4.relaxed_nmadd", OpcodeCategory::Simd),
+    ("f64x2.relaxed_madd", OpcodeCategory::Simd),
+    ("f64x2.relaxed_nmadd", OpcodeCategory::Simd),
+    ("i8x16.relaxed_laneselect", OpcodeCategory::Simd),
+    ("i16x8.relaxed_laneselect", OpcodeCategory::Simd),
+    ("i32x4.relaxed_laneselect", OpcodeCategory::Simd),
+    ("i64x2.relaxed_laneselect", OpcodeCategory::Simd),
+    ("f32x4.relaxed_min", OpcodeCategory::Simd),
+    ("f32x4.relaxed_max", OpcodeCategory::Simd),
+    ("f64x2.relaxed_min", OpcodeCategory::Simd),
+    ("f64x2.relaxed_max", OpcodeCategory::Simd),
+    ("i16x8.relaxed_q15mulr_s", OpcodeCategory::Simd),
+    ("i16x8.relaxed_dot_i8x16_i7x16_s", OpcodeCategory::Simd),
+    ("i32x4.relaxed_dot_i8x16_i7x16_add_s", OpcodeCategory::Simd),
+    ("memory.atomic.notify", OpcodeCategory::Atomic),
+    ("memory.atomic.wait32", OpcodeCategory::Atomic),
+    ("memory.atomic.wait64", OpcodeCategory::Atomic),
+    ("atomic.fence", OpcodeCategory::Atomic),
+    ("i32.atomic.load", OpcodeCategory::Atomic),
+    ("i64.atomic.load", OpcodeCategory::Atomic),
+    ("i32.atomic.load8_u", OpcodeCategory::Atomic),
+    ("i32.atomic.load16_u", OpcodeCategory::Atomic),
+    ("i64.atomic.load8_u", OpcodeCategory::Atomic),
+    ("i64.atomic.load16_u", OpcodeCategory::Atomic),
+    ("i64.atomic.load32_u", OpcodeCategory::Atomic),
+    ("i32.atomic.store", OpcodeCategory::Atomic),
+    ("i64.atomic.store", OpcodeCategory::Atomic),
+    ("i32.atomic.store8", OpcodeCategory::Atomic),
+    ("i32.atomic.store16", OpcodeCategory::Atomic),
+    ("i64.atomic.store8", OpcodeCategory::Atomic),
+    ("i64.atomic.store16", OpcodeCategory::Atomic),
+    ("i64.atomic.store32", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.add", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.add", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.add_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.add_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.add_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.add_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.add_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.sub", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.sub", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.sub_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.sub_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.sub_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.sub_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.sub_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.and", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.and", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.and_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.and_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.and_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.and_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.and_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.or", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.or", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.or_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.or_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.or_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.or_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.or_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.xor", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.xor", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.xor_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.xor_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.xor_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.xor_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.xor_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.xchg", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.xchg", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.xchg_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.xchg_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.xchg_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.xchg_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.xchg_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw.cmpxchg", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw.cmpxchg", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw8.cmpxchg_u", OpcodeCategory::Atomic),
+    ("i32.atomic.rmw16.cmpxchg_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw8.cmpxchg_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw16.cmpxchg_u", OpcodeCategory::Atomic),
+    ("i64.atomic.rmw32.cmpxchg_u", OpcodeCategory::Atomic),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cost_table() {
+        let table = CostTable::new()
+            .with_default(2)
+            .with_category(OpcodeCategory::Simd, 4)
+            .with_category(OpcodeCategory::Float, 3)
+            .with_opcode("memory.grow", 100)
+            .unwrap()
+            .with_opcode("f32.div", 8)
+            .unwrap();
+        assert_eq!(table.cost_of("memory.grow"), Some(100));
+        assert_eq!(table.cost_of("f32.div"), Some(8));
+        assert_eq!(table.cost_of("f64.add"), Some(3));
+        assert_eq!(table.cost_of("i32.trunc_f32_s"), Some(3));
+        assert_eq!(table.cost_of("i8x16.add"), Some(4));
+        assert_eq!(table.cost_of("i32.add"), Some(2));
+        assert_eq!(table.cost_of("unknown"), None);
+        assert!(CostTable::new().with_opcode("unknown", 1).is_err());
+
+        // the dense array covers every instruction
+        let costs = table.to_vec().unwrap();
+        assert_eq!(costs.len(), OPCODES.len());
+        assert_eq!(costs[0], 2);
+        assert_eq!(costs.iter().filter(|cost| **cost == 100).count(), 1);
+
+        // a later category setting replaces the earlier one
+        let table = table.with_category(OpcodeCategory::Simd, 5);
+        assert_eq!(table.cost_of("v128.load"), Some(5));
+    }
+
+    #[test]
+    fn test_cost_table_from_str() {
+        let table: CostTable = r#"
+            # comments and blank lines are ignored
+            default = 3
+
+            @simd = 7
+            call_indirect = 20
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(table.cost_of("nop"), Some(3));
+        assert_eq!(table.cost_of("i64x2.mul"), Some(7));
+        assert_eq!(table.cost_of("call_indirect"), Some(20));
+
+        assert!("@unknown = 1".parse::<CostTable>().is_err());
+        assert!("memory.grow = many".parse::<CostTable>().is_err());
+        assert!("memory.grow".parse::<CostTable>().is_err());
+    }
+
+    #[test]
+    fn test_cost_table_in_execution() {
+        use crate::{
+            config::{CommonConfigOptions, ConfigBuilder, StatisticsConfigOptions},
+            params,
+            vm::SyncInst,
+            wat2wasm, Module, Statistics, Store, Vm, WasmVal,
+        };
+
+        // the costs are apart by orders of magnitude, so the total cost tells which
+        // instructions the runtime charged and how often
+        let table = CostTable::new()
+            .with_default(0)
+            .with_opcode("i32.add", 100)
+            .unwrap()
+            .with_opcode("i32.sub", 1)
+            .unwrap()
+            .with_opcode("memory.size", 10_000)
+            .unwrap()
+            .with_opcode("i32x4.add", 1_000_000)
+            .unwrap();
+        let mut stat = Statistics::new().unwrap();
+        stat.set_cost_table(table.to_vec().unwrap());
+
+        let config = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_statistics_config(StatisticsConfigOptions::default().measure_cost(true))
+            .build()
+            .unwrap();
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (memory 1)
+            (func (export "run") (param $a i32) (param $b i32) (result i32)
+                (i32x4.extract_lane 0
+                    (i32x4.add
+                        (i32x4.splat
+                            (i32.sub
+                                (i32.add (i32.add (local.get $a) (local.get $b)) (memory.size))
+                                (i32.const 1)))
+                        (i32x4.splat (local.get $b)))))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(Some(&config), wasm_bytes).unwrap();
+        let store = Store::with_statistics(
+            Some(&config),
+            HashMap::<String, &mut dyn SyncInst>::new(),
+            stat.clone(),
+        )
+        .unwrap();
+        let mut vm = Vm::new(store);
+        vm.register_module(None, module).unwrap();
+
+        let (returns, delta) = vm
+            .run_func_with_statistics(None, "run", params!(2, 3))
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+        assert_eq!(delta.cost(), 1_010_201);
+        assert_eq!(stat.cost(), 1_010_201);
+    }
+
+    #[test]
+    fn test_cost_table_opcode_numbering() {
+        use crate::{
+            config::{CommonConfigOptions, ConfigBuilder, StatisticsConfigOptions},
+            params,
+            vm::SyncInst,
+            wat2wasm, Module, Statistics, Store, Vm,
+        };
+
+        // each function runs the instruction it is named after as many times as given below,
+        // covering the single-byte opcodes and the `0xFC` and `0xFD` prefixed ones
+        let cases = [
+            ("nop", 3),
+            ("select", 1),
+            ("br_if", 2),
+            ("call", 1),
+            ("local.get", 2),
+            ("memory.grow", 1),
+            ("i32.mul", 2),
+            ("i64.add", 1),
+            ("f32.add", 1),
+            ("f64.sqrt", 1),
+            ("i32.wrap_i64", 1),
+            ("ref.is_null", 1),
+            ("i32.trunc_sat_f32_s", 1),
+            ("memory.fill", 1),
+            ("table.size", 1),
+            ("i8x16.swizzle", 1),
+            ("i32x4.mul", 1),
+            ("f64x2.add", 1),
+        ];
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (memory 1)
+            (table 1 funcref)
+            (func $nop (export "nop") nop nop nop)
+            (func (export "select")
+                (drop (select (i32.const 1) (i32.const 2) (i32.const 0))))
+            (func (export "br_if")
+                (block (br_if 0 (i32.const 0)) (br_if 0 (i32.const 1))))
+            (func (export "call") (call $nop))
+            (func (export "local.get") (local i32)
+                (drop (local.get 0))
+                (drop (local.get 0)))
+            (func (export "memory.grow") (drop (memory.grow (i32.const 0))))
+            (func (export "i32.mul")
+                (drop (i32.mul (i32.mul (i32.const 2) (i32.const 3)) (i32.const 4))))
+            (func (export "i64.add") (drop (i64.add (i64.const 1) (i64.const 2))))
+            (func (export "f32.add") (drop (f32.add (f32.const 1) (f32.const 2))))
+            (func (export "f64.sqrt") (drop (f64.sqrt (f64.const 4))))
+            (func (export "i32.wrap_i64") (drop (i32.wrap_i64 (i64.const 1))))
+            (func (export "ref.is_null") (drop (ref.is_null (ref.null func))))
+            (func (export "i32.trunc_sat_f32_s") (drop (i32.trunc_sat_f32_s (f32.const 1.5))))
+            (func (export "memory.fill")
+                (memory.fill (i32.const 0) (i32.const 0) (i32.const 4)))
+            (func (export "table.size") (drop (table.size)))
+            (func (export "i8x16.swizzle")
+                (drop (i8x16.swizzle (v128.const i32x4 1 2 3 4) (v128.const i32x4 0 1 2 3))))
+            (func (export "i32x4.mul")
+                (drop (i32x4.mul (v128.const i32x4 1 2 3 4) (v128.const i32x4 1 2 3 4))))
+            (func (export "f64x2.add")
+                (drop (f64x2.add (v128.const f64x2 1 2) (v128.const f64x2 3 4))))
+        )
+"#,
+        )
+        .unwrap();
+
+        let config = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_statistics_config(StatisticsConfigOptions::default().measure_cost(true))
+            .build()
+            .unwrap();
+        let module = Module::from_bytes(Some(&config), wasm_bytes).unwrap();
+        let mut stat = Statistics::new().unwrap();
+        let store = Store::with_statistics(
+            Some(&config),
+            HashMap::<String, &mut dyn SyncInst>::new(),
+            stat.clone(),
+        )
+        .unwrap();
+        let mut vm = Vm::new(store);
+        vm.register_module(None, module).unwrap();
+
+        // only the instruction under test costs anything, so a wrong index in the dense array
+        // charges another instruction instead
+        for (opcode, times) in cases {
+            let table = CostTable::new()
+                .with_default(0)
+                .with_opcode(opcode, 1)
+                .unwrap();
+            stat.set_cost_table(table.to_vec().unwrap());
+            let (_, delta) = vm
+                .run_func_with_statistics(None, opcode, params!())
+                .unwrap();
+            assert_eq!(delta.cost(), times, "the cost of `{opcode}`");
+        }
+    }
+}
@@ -36,6 +36,34 @@ impl<Data> ImportObjectBuilder<Data> {
         name: impl AsRef<str>,
         real_func: sys::SyncFn<Data>,
     ) -> WasmEdgeResult<&mut Self>
+    where
+        Args: WasmValTypeList,
+        Rets: WasmValTypeList,
+    {
+        self.with_func_and_cost::<Args, Rets>(name, real_func, 0)
+    }
+
+    /// Adds a [host function](crate::Func) with a static cost to the [ImportObject] to create.
+    ///
+    /// The cost is added to the [statistics](crate::Statistics) on each call when the cost measuring option is enabled, and is counted against the [cost limit](crate::Statistics::set_cost_limit). A host function can charge additional cost during the call with [Statistics::charge](crate::Statistics::charge) on the statistics returned by [Statistics::from_calling_frame](crate::Statistics::from_calling_frame).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [host function](crate::Func) to add.
+    ///
+    /// * `real_func` - The native function.
+    ///
+    /// * `cost` - The cost of each call to the function.
+    ///
+    /// # error
+    ///
+    /// If fail to create or add the [host function](crate::Func), then an error is returned.
+    pub fn with_func_and_cost<Args, Rets>(
+        &mut self,
+        name: impl AsRef<str>,
+        real_func: sys::SyncFn<Data>,
+        cost: u64,
+    ) -> WasmEdgeResult<&mut Self>
     where
         Args: WasmValTypeList,
         Rets: WasmValTypeList,
@@ -43,12 +71,7 @@ impl<Data> ImportObjectBuilder<Data> {
         let args = Args::wasm_types();
         let returns = Rets::wasm_types();
         let ty = FuncType::new(args.to_vec(), returns.to_vec());
-        let func = unsafe {
-            Function::create_sync_func(&ty, real_func, self.import_object.get_host_data_mut(), 0)
-        }?;
-        self.import_object.add_func(name, func);
-
-        Ok(self)
+        self.with_func_by_type_and_cost(name, ty, real_func, cost)
     }
 
     /// Adds a [host function](crate::Func) to the [ImportObject] to create.
@@ -73,9 +96,34 @@ impl<Data> ImportObjectBuilder<Data> {
         name: impl AsRef<str>,
         ty: FuncType,
         real_func: sys::SyncFn<Data>,
+    ) -> WasmEdgeResult<&mut Self> {
+        self.with_func_by_type_and_cost(name, ty, real_func, 0)
+    }
+
+    /// Adds a [host function](crate::Func) of the given type with a static cost to the [ImportObject] to create. See [ImportObjectBuilder::with_func_and_cost](crate::ImportObjectBuilder::with_func_and_cost) for how the cost is counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [host function](crate::Func) to add.
+    ///
+    /// * `ty` - The function type.
+    ///
+    /// * `real_func` - The native function.
+    ///
+    /// * `cost` - The cost of each call to the function.
+    ///
+    /// # error
+    ///
+    /// If fail to create or add the [host function](crate::Func), then an error is returned.
+    pub fn with_func_by_type_and_cost(
+        &mut self,
+        name: impl AsRef<str>,
+        ty: FuncType,
+        real_func: sys::SyncFn<Data>,
+        cost: u64,
     ) -> WasmEdgeResult<&mut Self> {
         let func = unsafe {
-            Function::create_sync_func(&ty, real_func, self.import_object.get_host_data_mut(), 0)
+            Function::create_sync_func(&ty, real_func, self.import_object.get_host_data_mut(), cost)
         }?;
         self.import_object.add_func(name, func);
         Ok(self)
@@ -93,11 +93,14 @@
 //! This project is licensed under the terms of the [Apache 2.0 license](https://github.com/tensorflow/rust/blob/HEAD/LICENSE).
 //!
 
+#[cfg(feature = "aot")]
+mod cache;
 #[doc(hidden)]
 #[cfg(feature = "aot")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
 mod compiler;
 pub mod config;
+mod cost_table;
 
 // #[cfg(feature = "dock")]
 // pub mod dock;
@@ -106,10 +109,17 @@ mod import;
 mod instance;
 #[doc(hidden)]
 pub mod io;
+mod limits;
+mod linker;
 #[doc(hidden)]
 pub mod log;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
 mod module;
+mod module_info;
 pub mod plugin;
+mod pool;
 mod statistics;
 mod store;
 pub mod types;
@@ -123,23 +133,37 @@ pub mod r#async;
 #[doc(inline)]
 #[cfg(feature = "aot")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
-pub use compiler::Compiler;
+pub use compiler::{CompileInput, CompileProgress, CompileReport, Compiler};
+
+#[doc(inline)]
+#[cfg(feature = "aot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
+pub use cache::CompilationCache;
 
+pub use cost_table::{CostTable, OpcodeCategory};
 #[doc(inline)]
 pub use import::{AsInstance, ImportObject, ImportObjectBuilder};
 pub use instance::Instance;
 #[doc(inline)]
 pub use io::{WasmVal, WasmValType, WasmValTypeList};
 #[doc(inline)]
+pub use limits::{ResourceLimiter, StoreLimits};
+#[doc(inline)]
+pub use linker::{Extern, HostFn, HostFunc, Linker};
+#[doc(inline)]
 pub use log::LogManager;
 #[doc(inline)]
 pub use module::{ExportType, ImportType, Module};
 #[doc(inline)]
-pub use statistics::Statistics;
+pub use module_info::{DataSegment, ElementSegment, ModuleFeatures, ModuleInfo, SegmentKind};
+#[doc(inline)]
+pub use pool::{InstancePool, InstancePre, PoolMetrics, PooledInstance};
+#[doc(inline)]
+pub use statistics::{Statistics, StatisticsSnapshot};
 #[doc(inline)]
-pub use store::Store;
+pub use store::{ImportProblem, ImportStubs, Store};
 #[doc(inline)]
-pub use vm::Vm;
+pub use vm::{InstanceHandle, Vm};
 
 pub use wasmedge_types::{
     error, wat2wasm, CompilerOptimizationLevel, CompilerOutputFormat, ExternalInstanceType,
@@ -0,0 +1,238 @@
+//! Defines the ResourceLimiter trait and the StoreLimits type.
+
+/// The size of a WebAssembly page in bytes.
+pub(crate) const WASM_PAGE_SIZE: usize = 64 * 1024;
+
+/// Converts a number of WebAssembly pages to bytes, saturating at `usize::MAX`.
+pub(crate) fn pages_to_bytes(pages: u32) -> usize {
+    (pages as usize).saturating_mul(WASM_PAGE_SIZE)
+}
+
+/// Decides whether the memories and tables in a [store](crate::Store) are allowed to grow.
+///
+/// A [ResourceLimiter] is attached to a [store](crate::Store) by [Store::set_resource_limiter](crate::Store::set_resource_limiter), and is consulted
+///
+/// * when a [module](crate::Module) is instantiated into the store, for each memory and table defined by the module. The `current` size is `0` and the `desired` size is the minimum size of the memory or table.
+///
+/// * when a memory or table is grown by [Store::grow_memory](crate::Store::grow_memory), [Store::grow_table](crate::Store::grow_table), [Vm::grow_memory](crate::Vm::grow_memory) or [Vm::grow_table](crate::Vm::grow_table).
+///
+/// If the limiter returns `false`, then the instantiation or the growth fails and nothing is allocated.
+///
+/// The limiter is not consulted for the `memory.grow` and `table.grow` instructions executed by the wasm code, which are handled inside the WasmEdge runtime without a callback. The only bound the runtime enforces on them is static:
+///
+/// * each memory of a module instantiated into the store is capped by the runtime at the number of pages returned by [ResourceLimiter::memory_pages_cap] at the instantiation, so the wasm code cannot grow a single memory past the cap. Since the cap of each memory is computed from the total size at the instantiation, the memories grown by the wasm code together may exceed a limit on the total size;
+///
+/// * a table grown by the wasm code is only bounded by its declared maximum.
+///
+/// Growing a memory or a table directly through its handle, instead of through the store or the vm, bypasses the limiter as well.
+///
+/// The exported memories grown by the wasm code are counted in the `store_total` passed to the later calls. The memories not exported by a module instance cannot be looked up, so each of them is counted with its initial size.
+///
+/// A module without [ModuleInfo](crate::ModuleInfo), such as a module loaded from a shared library file, cannot be checked, so it fails to instantiate into a store with a limiter.
+pub trait ResourceLimiter {
+    /// Returns whether a memory is allowed to grow from `current` to `desired` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The current size of the memory in bytes.
+    ///
+    /// * `desired` - The size of the memory in bytes after growing.
+    ///
+    /// * `maximum` - The declared maximum size of the memory in bytes, if any.
+    ///
+    /// * `store_total` - The total size in bytes of all memories in the store before growing, including `current`.
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+        store_total: usize,
+    ) -> bool;
+
+    /// Returns whether a table is allowed to grow from `current` to `desired` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The current number of elements of the table.
+    ///
+    /// * `desired` - The number of elements of the table after growing.
+    ///
+    /// * `maximum` - The declared maximum number of elements of the table, if any.
+    fn table_growing(&mut self, current: u32, desired: u32, maximum: Option<u32>) -> bool;
+
+    /// Returns the number of pages the runtime caps each memory of a module at, or `None` if the memories are only capped by their declared maximum and [RuntimeConfigOptions::max_memory_pages](crate::config::RuntimeConfigOptions::max_memory_pages).
+    ///
+    /// The cap is fixed when the module is instantiated. By default, no cap is set.
+    ///
+    /// # Argument
+    ///
+    /// * `store_total` - The total size in bytes of all memories in the store before the module is instantiated.
+    fn memory_pages_cap(&self, store_total: usize) -> Option<u32> {
+        let _ = store_total;
+        None
+    }
+}
+
+/// A [ResourceLimiter] with fixed limits.
+///
+/// A memory is capped at the size limit of a single memory, and at the part of the total limit left when its module is instantiated, so the wasm code cannot grow a single memory past either limit. The limits on the total size of the memories and on the number of table elements are checked when a module is instantiated and when a memory or table is grown through the store or the vm, but not when the wasm code grows them, see [ResourceLimiter].
+///
+/// By default, no limit is set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StoreLimits {
+    memory_size: Option<usize>,
+    memories_total: Option<usize>,
+    table_elements: Option<u32>,
+}
+impl StoreLimits {
+    /// Creates a new [StoreLimits] without any limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size in bytes of a single memory.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The maximum size in bytes.
+    pub fn with_memory_size(self, bytes: usize) -> Self {
+        Self {
+            memory_size: Some(bytes),
+            ..self
+        }
+    }
+
+    /// Sets the maximum total size in bytes of all memories in the store.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The maximum total size in bytes.
+    pub fn with_memories_total(self, bytes: usize) -> Self {
+        Self {
+            memories_total: Some(bytes),
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of elements of a single table, which is checked for the initial size of a table and the growth through the store or the vm.
+    ///
+    /// # Argument
+    ///
+    /// * `elements` - The maximum number of elements.
+    pub fn with_table_elements(self, elements: u32) -> Self {
+        Self {
+            table_elements: Some(elements),
+            ..self
+        }
+    }
+
+    /// Returns the maximum size in bytes of a single memory.
+    pub fn memory_size(&self) -> Option<usize> {
+        self.memory_size
+    }
+
+    /// Returns the maximum total size in bytes of all memories in the store.
+    pub fn memories_total(&self) -> Option<usize> {
+        self.memories_total
+    }
+
+    /// Returns the maximum number of elements of a single table.
+    pub fn table_elements(&self) -> Option<u32> {
+        self.table_elements
+    }
+}
+impl ResourceLimiter for StoreLimits {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+        store_total: usize,
+    ) -> bool {
+        if let Some(limit) = self.memory_size {
+            if desired > limit {
+                return false;
+            }
+        }
+        if let Some(limit) = self.memories_total {
+            let total = store_total.saturating_sub(current).saturating_add(desired);
+            if total > limit {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn table_growing(&mut self, _current: u32, desired: u32, _maximum: Option<u32>) -> bool {
+        match self.table_elements {
+            Some(limit) => desired <= limit,
+            None => true,
+        }
+    }
+
+    fn memory_pages_cap(&self, store_total: usize) -> Option<u32> {
+        // a new memory can neither exceed its own limit nor the rest of the total limit
+        let rest = self
+            .memories_total
+            .map(|total| total.saturating_sub(store_total));
+        let bytes = match (self.memory_size, rest) {
+            (Some(size), Some(rest)) => size.min(rest),
+            (Some(bytes), None) | (None, Some(bytes)) => bytes,
+            (None, None) => return None,
+        };
+        Some(u32::try_from(bytes / WASM_PAGE_SIZE).unwrap_or(u32::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_limits() {
+        let mut limits = StoreLimits::new();
+        assert!(limits.memory_growing(0, usize::MAX, None, usize::MAX));
+        assert!(limits.table_growing(0, u32::MAX, None));
+
+        let mut limits = StoreLimits::new()
+            .with_memory_size(4 * WASM_PAGE_SIZE)
+            .with_memories_total(6 * WASM_PAGE_SIZE)
+            .with_table_elements(10);
+        assert_eq!(limits.memory_size(), Some(4 * WASM_PAGE_SIZE));
+        assert_eq!(limits.memories_total(), Some(6 * WASM_PAGE_SIZE));
+        assert_eq!(limits.table_elements(), Some(10));
+
+        // a single memory cannot exceed 4 pages
+        assert!(limits.memory_growing(0, 4 * WASM_PAGE_SIZE, None, 0));
+        assert!(!limits.memory_growing(0, 5 * WASM_PAGE_SIZE, None, 0));
+
+        // the memories of the store cannot exceed 6 pages in total
+        assert!(limits.memory_growing(
+            WASM_PAGE_SIZE,
+            3 * WASM_PAGE_SIZE,
+            None,
+            4 * WASM_PAGE_SIZE
+        ));
+        assert!(!limits.memory_growing(
+            WASM_PAGE_SIZE,
+            4 * WASM_PAGE_SIZE,
+            None,
+            4 * WASM_PAGE_SIZE
+        ));
+
+        assert!(limits.table_growing(5, 10, None));
+        assert!(!limits.table_growing(5, 11, None));
+
+        // the runtime caps a new memory at the smaller limit
+        assert_eq!(limits.memory_pages_cap(0), Some(4));
+        assert_eq!(limits.memory_pages_cap(4 * WASM_PAGE_SIZE), Some(2));
+        assert_eq!(limits.memory_pages_cap(8 * WASM_PAGE_SIZE), Some(0));
+        assert_eq!(
+            StoreLimits::new()
+                .with_memories_total(3 * WASM_PAGE_SIZE + 1)
+                .memory_pages_cap(0),
+            Some(3)
+        );
+        assert_eq!(StoreLimits::new().memory_pages_cap(0), None);
+    }
+}
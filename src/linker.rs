@@ -0,0 +1,796 @@
+//! Defines WasmEdge Linker and Extern.
+
+use crate::{
+    config::Config,
+    error::{CoreCommonError, CoreError, LinkerError, WasmEdgeError},
+    CallingFrame, FuncType, ImportType, Instance, Module, Store, WasmEdgeResult, WasmValue,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Arc,
+    },
+};
+use sys::{instance::function::AsFunc, AsInstance};
+use wasmedge_sys as sys;
+
+/// The closure of a host function created by [Extern::func].
+pub type HostFn =
+    dyn Fn(&mut CallingFrame, Vec<WasmValue>) -> Result<Vec<WasmValue>, CoreError> + Send + Sync;
+
+/// Defines a host item that can be defined in a [Linker](crate::Linker) and imported by a wasm module.
+#[derive(Debug)]
+pub enum Extern {
+    /// A [host function](crate::Func).
+    Func(sys::Function),
+    /// A host function created by [Extern::func], which owns its closure.
+    HostFunc(HostFunc),
+    /// A [table](crate::Table).
+    Table(sys::Table),
+    /// A [memory](crate::Memory).
+    Memory(sys::Memory),
+    /// A [global](crate::Global).
+    Global(sys::Global),
+}
+impl Extern {
+    /// Creates a [host function](crate::Func) of the given type calling the given closure, which is typically used to synthesize the implementation of an import on demand.
+    ///
+    /// The closure is owned by the import namespace the function is added to, and dropped together with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `ty` - The types of the arguments and returns of the host function.
+    ///
+    /// * `real_func` - The closure called with the calling frame and the arguments.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [host function](crate::Func), then an error is returned.
+    pub fn func<F>(ty: &FuncType, real_func: F) -> WasmEdgeResult<Self>
+    where
+        F: Fn(&mut CallingFrame, Vec<WasmValue>) -> Result<Vec<WasmValue>, CoreError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        // the closure is boxed twice, so the thin pointer to the inner box stays valid when the outer box is moved
+        let mut closure: Box<Box<HostFn>> = Box::new(Box::new(real_func));
+        let data: *mut Box<HostFn> = &mut *closure;
+        let func = unsafe { sys::Function::create_sync_func(ty, call_host_fn, data, 0) }?;
+        Ok(Extern::HostFunc(HostFunc {
+            func,
+            data: HostFuncData::Closure(closure),
+        }))
+    }
+
+    /// Adds this item to the given import module.
+    pub(crate) fn add_to(
+        self,
+        import: &mut sys::ImportModule<NamespaceData>,
+        name: impl AsRef<str>,
+    ) {
+        match self {
+            Extern::Func(func) => import.add_func(name, func),
+            Extern::HostFunc(HostFunc { func, data }) => {
+                import.get_host_data_mut().push(data);
+                import.add_func(name, func);
+            }
+            Extern::Table(table) => import.add_table(name, table),
+            Extern::Memory(memory) => import.add_memory(name, memory),
+            Extern::Global(global) => import.add_global(name, global),
+        }
+    }
+}
+
+/// A host function created by [Extern::func], together with the closure it calls.
+pub struct HostFunc {
+    pub(crate) func: sys::Function,
+    pub(crate) data: HostFuncData,
+}
+impl std::fmt::Debug for HostFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HostFunc")
+            .field("func", &self.func)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The host data of a host function, which must live as long as the function.
+pub(crate) enum HostFuncData {
+    Closure(Box<Box<HostFn>>),
+    /// The module name and the name of the import replaced by a stub function.
+    Stub(Box<(String, String)>),
+}
+
+/// The host data of the import namespaces owned by a [store](crate::Store), which keeps the host data of their host functions alive.
+pub(crate) type NamespaceData = Vec<HostFuncData>;
+
+fn call_host_fn(
+    closure: &mut Box<HostFn>,
+    _inst: &mut sys::Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    closure(frame, args)
+}
+
+/// Defines the entry of an import namespace collected by a [Linker](crate::Linker).
+enum Namespace<'inst, T: ?Sized> {
+    /// A host import object or plugin instance.
+    Instance(&'inst mut T),
+    /// A named wasm module.
+    Module(Module),
+    /// The items defined individually by [Linker::define].
+    Definitions(Vec<(String, Extern)>),
+    /// Another name of the namespace of the given name.
+    Alias(String),
+}
+
+/// A [Linker] collects the import namespaces used to instantiate wasm [modules](crate::Module).
+///
+/// An import namespace is provided by a host import object, a named wasm module, or the items defined individually by [define](crate::Linker::define). The namespaces are registered into a new [store](crate::Store) in the order they are added to the [Linker], so a named wasm module can import from any namespace added before it.
+///
+/// By default, adding a namespace or an item whose name is already in use is an error. With [allow_shadowing](crate::Linker::allow_shadowing) enabled, the later one replaces the earlier one in place. An individual item can only shadow an item defined by [define](crate::Linker::define), since a namespace provided by a host import object or a wasm module is a single module instance, of which the items cannot be replaced.
+pub struct Linker<'inst, T: ?Sized> {
+    config: Option<Config>,
+    namespaces: Vec<(String, Namespace<'inst, T>)>,
+    allow_shadowing: bool,
+}
+impl<T: ?Sized> std::fmt::Debug for Linker<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Linker")
+            .field("config", &self.config)
+            .field(
+                "namespaces",
+                &self.namespaces.iter().map(|(x, _)| x).collect::<Vec<_>>(),
+            )
+            .field("allow_shadowing", &self.allow_shadowing)
+            .finish()
+    }
+}
+impl<'inst, T: AsInstance + ?Sized> Linker<'inst, T> {
+    /// Creates a new [Linker].
+    ///
+    /// # Argument
+    ///
+    /// * `config` - The configuration used to create the [store](crate::Store) and instantiate the modules.
+    pub fn new(config: Option<&Config>) -> Self {
+        Self {
+            config: config.cloned(),
+            namespaces: Vec::new(),
+            allow_shadowing: false,
+        }
+    }
+
+    /// Sets whether a namespace or an item can be redefined. If enabled, the later definition replaces the earlier one. Disabled by default.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether to allow shadowing.
+    pub fn allow_shadowing(&mut self, enable: bool) -> &mut Self {
+        self.allow_shadowing = enable;
+        self
+    }
+
+    /// Adds a host import object or plugin instance as an import namespace. The name of the namespace is the name of the given instance.
+    ///
+    /// # Argument
+    ///
+    /// * `instance` - The named module instance to add.
+    ///
+    /// # Error
+    ///
+    /// * If the given instance has no name, then [LinkerError::AnonymousInstance](crate::error::LinkerError) is returned.
+    ///
+    /// * If the namespace is already defined and shadowing is disabled, then [LinkerError::DuplicateModule](crate::error::LinkerError) is returned.
+    pub fn instance(&mut self, instance: &'inst mut T) -> WasmEdgeResult<&mut Self> {
+        let name = instance
+            .name()
+            .ok_or_else(|| Box::new(WasmEdgeError::Linker(LinkerError::AnonymousInstance)))?;
+        self.insert_namespace(name, Namespace::Instance(instance))?;
+        Ok(self)
+    }
+
+    /// Adds a wasm module as a named import namespace. The module is instantiated when the [Linker] builds the [store](crate::Store).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the namespace.
+    ///
+    /// * `module` - The validated [module](crate::Module) to add.
+    ///
+    /// # Error
+    ///
+    /// If the namespace is already defined and shadowing is disabled, then [LinkerError::DuplicateModule](crate::error::LinkerError) is returned.
+    pub fn module(&mut self, name: impl AsRef<str>, module: &Module) -> WasmEdgeResult<&mut Self> {
+        self.insert_namespace(name.as_ref().to_string(), Namespace::Module(module.clone()))?;
+        Ok(self)
+    }
+
+    /// Defines an individual host item in the given import namespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The name of the namespace.
+    ///
+    /// * `name` - The name of the item.
+    ///
+    /// * `item` - The host item to define.
+    ///
+    /// # Error
+    ///
+    /// * If the namespace is provided by a host import object or a wasm module and shadowing is disabled, then [LinkerError::DuplicateModule](crate::error::LinkerError) is returned. If shadowing is enabled, then [LinkerError::UnsupportedShadowing](crate::error::LinkerError) is returned, since the other items of the namespace cannot be kept.
+    ///
+    /// * If the item is already defined and shadowing is disabled, then [LinkerError::DuplicateDefinition](crate::error::LinkerError) is returned.
+    pub fn define(
+        &mut self,
+        module: impl AsRef<str>,
+        name: impl AsRef<str>,
+        item: Extern,
+    ) -> WasmEdgeResult<&mut Self> {
+        let mod_name = module.as_ref();
+        let name = name.as_ref();
+
+        match self.namespaces.iter_mut().find(|(x, _)| x == mod_name) {
+            Some((_, Namespace::Definitions(items))) => {
+                match items.iter().position(|(x, _)| x == name) {
+                    Some(_) if !self.allow_shadowing => {
+                        return Err(Box::new(WasmEdgeError::Linker(
+                            LinkerError::DuplicateDefinition {
+                                mod_name: mod_name.to_string(),
+                                name: name.to_string(),
+                            },
+                        )))
+                    }
+                    Some(idx) => items[idx] = (name.to_string(), item),
+                    None => items.push((name.to_string(), item)),
+                }
+            }
+            Some(_) if self.allow_shadowing => {
+                return Err(Box::new(WasmEdgeError::Linker(
+                    LinkerError::UnsupportedShadowing {
+                        mod_name: mod_name.to_string(),
+                        name: name.to_string(),
+                    },
+                )))
+            }
+            _ => self.insert_namespace(
+                mod_name.to_string(),
+                Namespace::Definitions(vec![(name.to_string(), item)]),
+            )?,
+        }
+
+        Ok(self)
+    }
+
+    /// Makes the functions of the import namespace `from` also available as the import namespace `to`.
+    ///
+    /// A WasmEdge module instance is registered under exactly one name, so the alias is a namespace of its own exporting the same functions as `from`, which run in the same module instance and share its state. A host function defined by this crate is bound again with the same host data, and a wasm function is called through a forwarding host function. The memories, tables and globals of `from` cannot be owned by two namespaces, so they are not available under the alias. The alias keeps `from` in use, see [Store::unregister](crate::Store::unregister).
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The name of an existing wasm module namespace.
+    ///
+    /// * `to` - The name of the alias.
+    ///
+    /// # Error
+    ///
+    /// * If `from` is not defined, then [LinkerError::NotFoundModule](crate::error::LinkerError) is returned.
+    ///
+    /// * If `to` is already defined and shadowing is disabled, then [LinkerError::DuplicateModule](crate::error::LinkerError) is returned.
+    pub fn alias_module(
+        &mut self,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+    ) -> WasmEdgeResult<&mut Self> {
+        let from = from.as_ref();
+        if !self.contains(from) {
+            return Err(Box::new(WasmEdgeError::Linker(
+                LinkerError::NotFoundModule(from.to_string()),
+            )));
+        }
+        self.insert_namespace(to.as_ref().to_string(), Namespace::Alias(from.to_string()))?;
+        Ok(self)
+    }
+
+    /// Checks if the [Linker] contains an import namespace with the given name.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the namespace.
+    pub fn contains(&self, name: impl AsRef<str>) -> bool {
+        self.namespaces.iter().any(|(x, _)| x == name.as_ref())
+    }
+
+    /// Returns the names of all import namespaces in the order of registration.
+    pub fn names(&self) -> Vec<String> {
+        self.namespaces.iter().map(|(x, _)| x.clone()).collect()
+    }
+
+    /// Registers all import namespaces into a new [store](crate::Store) and returns it.
+    ///
+    /// # Error
+    ///
+    /// * If an alias is defined for a namespace of which a function is built into the runtime or a plugin, such as the WASI module, then [LinkerError::UnsupportedAlias](crate::error::LinkerError) is returned. Such a function runs with the calling frame of its own module instance when called through a forwarding function, so it cannot reach the memory of the caller.
+    ///
+    /// * If fail to create the [store](crate::Store) or register any namespace, then an error is returned.
+    pub fn build(self) -> WasmEdgeResult<Store<'inst, T>> {
+        let mut store = Store::new(self.config.as_ref(), HashMap::new())?;
+
+        for (name, namespace) in self.namespaces {
+            match namespace {
+                Namespace::Instance(instance) => {
+                    store
+                        .executor
+                        .register_import_module(&mut store.inner, &*instance)?;
+                    store.instances.insert(name, instance);
+                }
+                Namespace::Module(module) => store.register_named_module(name, &module)?,
+                Namespace::Definitions(items) => {
+                    let mut import = sys::ImportModule::create(&name, Box::new(Vec::new()))?;
+                    for (item_name, item) in items {
+                        item.add_to(&mut import, item_name);
+                    }
+                    store
+                        .executor
+                        .register_import_module(&mut store.inner, &import)?;
+                    store.defined_instance_map.insert(name, import);
+                }
+                Namespace::Alias(from) => {
+                    let is_wasm = store.wasm_instance_map.contains_key(&from);
+                    let alias = {
+                        let instance = store.inner.module(&from)?;
+                        forwarding_namespace(&name, &*instance, !is_wasm)?.0
+                    };
+                    store
+                        .executor
+                        .register_import_module(&mut store.inner, &alias)?;
+                    store.defined_instance_map.insert(name.clone(), alias);
+                    store.dependencies.insert(name, vec![from]);
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    /// Builds the [store](crate::Store) and instantiates the given [module](crate::Module) in it as an anonymous active module instance.
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The validated [module](crate::Module) to instantiate.
+    ///
+    /// # Error
+    ///
+    /// * If any import of the module cannot be resolved, then [LinkerError::UnresolvedImports](crate::error::LinkerError) is returned.
+    ///
+    /// * If fail to build the [store](crate::Store) or instantiate the module, then an error is returned.
+    pub fn instantiate(self, module: &Module) -> WasmEdgeResult<(Store<'inst, T>, Instance)> {
+        let mut store = self.build()?;
+        let instance = Self::instantiate_in(&mut store, module)?;
+        Ok((store, instance))
+    }
+
+    /// Builds the [store](crate::Store) and instantiates the given [module](crate::Module) in it as an anonymous active module instance. The imports from the namespaces not defined in the [Linker] are resolved by the given resolver.
+    ///
+    /// See [Store::register_active_module_with_resolver](crate::Store::register_active_module_with_resolver) for the resolution rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The validated [module](crate::Module) to instantiate.
+    ///
+    /// * `resolver` - The callback returning the host item for a given import, or `None` if the import cannot be resolved.
+    ///
+    /// # Error
+    ///
+    /// * If any import of the module cannot be resolved, then [LinkerError::UnresolvedImports](crate::error::LinkerError) is returned.
+    ///
+    /// * If fail to build the [store](crate::Store), create the resolved items or instantiate the module, then an error is returned.
+    pub fn instantiate_with_resolver<F>(
+        self,
+        module: &Module,
+        resolver: F,
+    ) -> WasmEdgeResult<(Store<'inst, T>, Instance)>
+    where
+        F: Fn(&ImportType) -> Option<Extern>,
+    {
+        let mut store = self.build()?;
+        store.resolve_imports(module, Some(&resolver))?;
+        let instance = Self::instantiate_in(&mut store, module)?;
+        Ok((store, instance))
+    }
+
+    fn instantiate_in(store: &mut Store<'inst, T>, module: &Module) -> WasmEdgeResult<Instance> {
+        let problems = store.check_imports(module);
+        if !problems.is_empty() {
+            return Err(Box::new(WasmEdgeError::Linker(
+                LinkerError::UnresolvedImports(problems.iter().map(|x| x.to_string()).collect()),
+            )));
+        }
+
+        store.register_active_module(module)
+    }
+
+    fn insert_namespace(
+        &mut self,
+        name: String,
+        namespace: Namespace<'inst, T>,
+    ) -> WasmEdgeResult<()> {
+        match self.namespaces.iter().position(|(x, _)| *x == name) {
+            Some(_) if !self.allow_shadowing => Err(Box::new(WasmEdgeError::Linker(
+                LinkerError::DuplicateModule(name),
+            ))),
+            Some(idx) => {
+                self.namespaces[idx] = (name, namespace);
+                Ok(())
+            }
+            None => {
+                self.namespaces.push((name, namespace));
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The module instance the forwarding functions of a namespace call into, which can be switched to another instance exporting the same functions while the namespace is linked.
+#[derive(Debug, Clone)]
+pub(crate) struct ForwardTarget(Arc<AtomicPtr<sys::ffi::WasmEdge_ModuleInstanceContext>>);
+impl ForwardTarget {
+    fn new(instance: &impl AsInstance) -> Self {
+        Self(Arc::new(AtomicPtr::new(
+            unsafe { instance.as_ptr() } as *mut _
+        )))
+    }
+
+    /// Switches the target to the given module instance, which must outlive the forwarding functions or the next switch.
+    pub(crate) fn set(&self, instance: &impl AsInstance) {
+        self.0
+            .store(unsafe { instance.as_ptr() } as *mut _, Ordering::Release);
+    }
+}
+
+/// A module instance borrowed by a forwarding function, which is not deleted when dropped.
+struct TargetInstance(*const sys::ffi::WasmEdge_ModuleInstanceContext);
+impl AsInstance for TargetInstance {
+    unsafe fn as_ptr(&self) -> *const sys::ffi::WasmEdge_ModuleInstanceContext {
+        self.0
+    }
+}
+
+/// Creates an import namespace of the given name exporting the functions of the given module instance, and returns it with the target its forwarding functions call into.
+///
+/// If `rebind` is `true`, then the given module instance is a host namespace, of which every function must be a host function defined by this crate. Such a function is bound again with the same host data, so it runs with the calling frame of its caller. Otherwise, the given module instance is a wasm module instance, and every function is called through a forwarding function, which looks up the function of the same name in the current target when called.
+pub(crate) fn forwarding_namespace(
+    name: &str,
+    instance: &impl AsInstance,
+    rebind: bool,
+) -> WasmEdgeResult<(sys::ImportModule<NamespaceData>, ForwardTarget)> {
+    let target = ForwardTarget::new(instance);
+    let mut namespace = sys::ImportModule::create(name, Box::new(Vec::new()))?;
+    let source = TargetInstance(unsafe { instance.as_ptr() });
+    for func_name in source.func_names().unwrap_or_default() {
+        let func = source.get_func(&func_name)?;
+        if rebind {
+            // the rebound function shares the host data of the function of the source namespace,
+            // which outlives the alias as long as the alias keeps it in use
+            let func = unsafe { sys::Function::rebind(&*func) }.ok_or_else(|| {
+                Box::new(WasmEdgeError::Linker(LinkerError::UnsupportedAlias(
+                    source.name().unwrap_or_default(),
+                )))
+            })?;
+            namespace.add_func(&func_name, func);
+            continue;
+        }
+        let ty = func.ty().ok_or_else(|| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "Fail to get the type of the function ({func_name})"
+            )))
+        })?;
+        let forwarded = target.clone();
+        let forwarded_name = func_name.clone();
+        Extern::func(&ty, move |frame, args| {
+            let mut instance = TargetInstance(forwarded.0.load(Ordering::Acquire));
+            let mut func = instance
+                .get_func_mut(&forwarded_name)
+                .map_err(|_| CoreError::Common(CoreCommonError::FuncNotFound))?;
+            let mut executor = frame
+                .executor_mut()
+                .ok_or(CoreError::Common(CoreCommonError::RuntimeError))?;
+            executor
+                .call_func(&mut func, args)
+                .map_err(|error| match *error {
+                    WasmEdgeError::Core(error) => error,
+                    _ => CoreError::Common(CoreCommonError::RuntimeError),
+                })
+        })?
+        .add_to(&mut namespace, func_name);
+    }
+    Ok((namespace, target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        params, wat2wasm, GlobalType, ImportObject, ImportObjectBuilder, MemoryType, Mutability,
+        ValType, WasmVal,
+    };
+
+    fn real_add(
+        _data: &mut (),
+        _inst: &mut sys::Instance,
+        _frame: &mut CallingFrame,
+        inputs: Vec<WasmValue>,
+    ) -> Result<Vec<WasmValue>, CoreError> {
+        let a = inputs[0].to_i32();
+        let b = inputs[1].to_i32();
+        Ok(vec![WasmValue::from_i32(a + b)])
+    }
+
+    fn create_import_object() -> ImportObject<()> {
+        let mut import_builder = ImportObjectBuilder::new("extern", ()).unwrap();
+        import_builder
+            .with_func::<(i32, i32), i32>("add", real_add)
+            .unwrap();
+        import_builder.build()
+    }
+
+    #[test]
+    fn test_linker_instantiate() {
+        let lib_module = Module::from_bytes(
+            None,
+            wat2wasm(
+                br#"
+        (module
+            (import "extern" "add" (func $add (param i32 i32) (result i32)))
+            (func (export "double") (param i32) (result i32)
+                (call $add (local.get 0) (local.get 0)))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(
+                br#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "offset" (global $offset i32))
+            (import "lib2" "double" (func $double (param i32) (result i32)))
+            (func (export "run") (param i32) (result i32)
+                (i32.add (call $double (local.get 0)) (global.get $offset)))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let mut import_object = create_import_object();
+        let mut linker = Linker::new(None);
+        linker
+            .instance(&mut import_object)
+            .unwrap()
+            .module("lib", &lib_module)
+            .unwrap()
+            .alias_module("lib", "lib2")
+            .unwrap()
+            .define(
+                "env",
+                "memory",
+                Extern::Memory(
+                    sys::Memory::create(&MemoryType::new(1, None, false).unwrap()).unwrap(),
+                ),
+            )
+            .unwrap()
+            .define(
+                "env",
+                "offset",
+                Extern::Global(
+                    sys::Global::create(
+                        &GlobalType::new(ValType::I32, Mutability::Const),
+                        WasmValue::from_i32(1),
+                    )
+                    .unwrap(),
+                ),
+            )
+            .unwrap();
+        assert_eq!(linker.names(), ["extern", "lib", "lib2", "env"]);
+
+        let (mut store, mut instance) = linker.instantiate(&module).unwrap();
+        assert_eq!(store.named_instance_count(), 4);
+        assert!(store.contains("lib2"));
+
+        let mut run = instance.get_func_mut("run").unwrap();
+        let returns = store.executor().call_func(&mut run, params!(5i32)).unwrap();
+        assert_eq!(returns[0].to_i32(), 11);
+    }
+
+    #[test]
+    fn test_linker_shadowing() {
+        let memory_type = MemoryType::new(1, None, false).unwrap();
+        let mut import_object = create_import_object();
+        let mut another_import_object = create_import_object();
+
+        let mut linker = Linker::<ImportObject<()>>::new(None);
+        linker
+            .define(
+                "env",
+                "memory",
+                Extern::Memory(sys::Memory::create(&memory_type).unwrap()),
+            )
+            .unwrap();
+
+        // redefine an item
+        let result = linker.define(
+            "env",
+            "memory",
+            Extern::Memory(sys::Memory::create(&memory_type).unwrap()),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Linker(LinkerError::DuplicateDefinition {
+                mod_name: "env".to_string(),
+                name: "memory".to_string(),
+            }))
+        );
+
+        // redefine a namespace
+        let result = linker.define(
+            "extern",
+            "memory",
+            Extern::Memory(sys::Memory::create(&memory_type).unwrap()),
+        );
+        assert!(result.is_ok());
+        let result = linker.instance(&mut another_import_object);
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Linker(LinkerError::DuplicateModule(
+                "extern".to_string()
+            )))
+        );
+
+        // only a defined namespace can be aliased
+        let result = linker.alias_module("not-exist", "env2");
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Linker(LinkerError::NotFoundModule(
+                "not-exist".to_string()
+            )))
+        );
+
+        // the later definitions replace the earlier ones with shadowing enabled
+        linker.allow_shadowing(true);
+        let result = linker.define(
+            "env",
+            "memory",
+            Extern::Memory(sys::Memory::create(&memory_type).unwrap()),
+        );
+        assert!(result.is_ok());
+        let result = linker.instance(&mut import_object);
+        assert!(result.is_ok());
+        assert_eq!(linker.names(), ["env", "extern"]);
+
+        // the items of a module instance cannot be shadowed individually
+        let result = linker.define(
+            "extern",
+            "memory",
+            Extern::Memory(sys::Memory::create(&memory_type).unwrap()),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Linker(LinkerError::UnsupportedShadowing {
+                mod_name: "extern".to_string(),
+                name: "memory".to_string(),
+            }))
+        );
+        assert_eq!(linker.names(), ["env", "extern"]);
+    }
+
+    #[test]
+    fn test_linker_alias_module() {
+        let counter = Module::from_bytes(
+            None,
+            wat2wasm(
+                br#"
+        (module
+            (global $count (mut i32) (i32.const 0))
+            (func (export "next") (result i32)
+                (global.set $count (i32.add (global.get $count) (i32.const 1)))
+                (global.get $count))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(
+                br#"
+        (module
+            (import "extern2" "add" (func $add (param i32 i32) (result i32)))
+            (import "counter" "next" (func $next (result i32)))
+            (import "counter2" "next" (func $next2 (result i32)))
+            (func (export "run") (result i32)
+                (drop (call $next))
+                (call $add (call $next2) (i32.const 10)))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let mut import_object = create_import_object();
+        let mut linker = Linker::new(None);
+        linker
+            .instance(&mut import_object)
+            .unwrap()
+            .alias_module("extern", "extern2")
+            .unwrap()
+            .module("counter", &counter)
+            .unwrap()
+            .alias_module("counter", "counter2")
+            .unwrap();
+
+        // the alias of a wasm module shares the state of its instance
+        let (mut store, mut instance) = linker.instantiate(&module).unwrap();
+        let mut run = instance.get_func_mut("run").unwrap();
+        let returns = store.executor().call_func(&mut run, []).unwrap();
+        assert_eq!(returns[0].to_i32(), 12);
+        let returns = store.executor().call_func(&mut run, []).unwrap();
+        assert_eq!(returns[0].to_i32(), 14);
+
+        // the aliases keep the aliased namespaces in use
+        assert_eq!(store.dependents("counter"), ["counter2"]);
+        let result = store.unregister("counter");
+        assert!(result.is_err());
+
+        // the functions built into the runtime cannot be aliased
+        let mut wasi = sys::WasiModule::create(None, None, None).unwrap();
+        let mut linker = Linker::new(None);
+        linker
+            .instance(&mut wasi)
+            .unwrap()
+            .alias_module("wasi_snapshot_preview1", "wasi_unstable")
+            .unwrap();
+        let result = linker.build();
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Linker(LinkerError::UnsupportedAlias(
+                "wasi_snapshot_preview1".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_linker_unresolved_imports() {
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(
+                br#"
+        (module
+            (import "extern" "sub" (func (param i32 i32) (result i32)))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let mut import_object = create_import_object();
+        let mut linker = Linker::new(None);
+        linker.instance(&mut import_object).unwrap();
+
+        let result = linker.instantiate(&module);
+        assert!(matches!(
+            *result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::UnresolvedImports(ref problems)) if problems.len() == 1
+        ));
+    }
+}
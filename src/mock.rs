@@ -0,0 +1,580 @@
+//! Defines the mock plugin modules used to test the wasm modules depending on plugins.
+//!
+//! A [MockPlugin] builds an [import object](crate::ImportObject) with the same module name and function signatures as a plugin module, so that a wasm module importing from the plugin can be instantiated and run without the plugin installed. The functions of a mock module return scripted responses, and all calls to them are recorded in a [MockCallLog].
+//!
+//! The module is available with the `mock` feature, which is usually enabled for the dev-dependency only. Mock modules are predefined for the wasi_nn, wasi_crypto and wasmedge_process plugins. The functions of the wasi_crypto modules follow the proposal as implemented by the WasmEdge 0.14 plugin; a wasm module importing other signatures can be tested by replacing them with [MockPlugin::with_func].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use wasmedge_sdk::{mock::MockPlugin, vm::SyncInst, Store, Vm, WasmValue};
+//!
+//! let mock = MockPlugin::wasi_nn()
+//!     .respond("load_by_name", vec![WasmValue::from_i32(0)])
+//!     .handle("compute", |_frame, _args| Ok(vec![WasmValue::from_i32(0)]));
+//! let calls = mock.calls();
+//! let mut wasi_nn = mock.build()?;
+//!
+//! let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+//! instances.insert(wasi_nn.name().unwrap(), &mut wasi_nn);
+//! let mut vm = Vm::new(Store::new(None, instances)?);
+//! // ... run the guest
+//! assert_eq!(calls.count("compute"), 1);
+//! ```
+
+use crate::{
+    error::{CoreError, CoreExecutionError, WasmEdgeError},
+    CallingFrame, FuncType, ImportObject, Instance, ValType, WasmEdgeResult, WasmValue,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+use wasmedge_sys as sys;
+
+/// The function handling the calls to a mock function, which receives the calling frame and the arguments, and returns the results.
+pub type MockHandler =
+    Box<dyn FnMut(&mut CallingFrame, Vec<WasmValue>) -> Result<Vec<WasmValue>, CoreError> + Send>;
+
+/// Defines a function of a mock module.
+struct MockFunc {
+    name: String,
+    ty: FuncType,
+    responses: VecDeque<Vec<WasmValue>>,
+    handler: Option<MockHandler>,
+    calls: MockCallLog,
+}
+impl std::fmt::Debug for MockFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockFunc")
+            .field("name", &self.name)
+            .field("ty", &self.ty)
+            .field("responses", &self.responses.len())
+            .field("handler", &self.handler.is_some())
+            .finish()
+    }
+}
+
+/// Holds the functions of a mock module. It is the host data of the [import object](crate::ImportObject) built by [MockPlugin::build](crate::mock::MockPlugin::build).
+#[derive(Debug)]
+pub struct MockHost {
+    // the functions are boxed, so their addresses are stable while the import object is alive
+    funcs: Vec<Box<MockFunc>>,
+}
+
+/// Defines a call to a mock function.
+#[derive(Debug, Clone)]
+pub struct MockCall {
+    func: String,
+    args: Vec<WasmValue>,
+}
+impl MockCall {
+    /// Returns the name of the called function.
+    pub fn func(&self) -> &str {
+        &self.func
+    }
+
+    /// Returns the arguments of the call.
+    pub fn args(&self) -> &[WasmValue] {
+        &self.args
+    }
+}
+
+/// Records the calls to the functions of a mock module. The clones of a [MockCallLog] share the same records.
+#[derive(Debug, Clone, Default)]
+pub struct MockCallLog {
+    calls: Arc<Mutex<Vec<MockCall>>>,
+}
+impl MockCallLog {
+    fn push(&self, call: MockCall) {
+        self.calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(call);
+    }
+
+    /// Returns all recorded calls in the order they are made.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Returns the recorded calls to the given function in the order they are made.
+    ///
+    /// # Argument
+    ///
+    /// * `func` - The name of the function.
+    pub fn calls_to(&self, func: impl AsRef<str>) -> Vec<MockCall> {
+        self.calls()
+            .into_iter()
+            .filter(|call| call.func == func.as_ref())
+            .collect()
+    }
+
+    /// Returns the number of the recorded calls to the given function.
+    ///
+    /// # Argument
+    ///
+    /// * `func` - The name of the function.
+    pub fn count(&self, func: impl AsRef<str>) -> usize {
+        self.calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .filter(|call| call.func == func.as_ref())
+            .count()
+    }
+
+    /// Removes all recorded calls.
+    pub fn clear(&self) {
+        self.calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+}
+
+/// Creates a mock module standing in for a plugin module.
+///
+/// A call to a function of the mock module is recorded first, and then returns
+///
+/// * the next scripted response added by [respond](crate::mock::MockPlugin::respond), if any; or
+///
+/// * the results of the handler set by [handle](crate::mock::MockPlugin::handle), if any; or
+///
+/// * the zero values of the result types of the function.
+#[derive(Debug)]
+pub struct MockPlugin {
+    name: String,
+    funcs: Vec<MockFunc>,
+    calls: MockCallLog,
+    errors: Vec<String>,
+}
+impl MockPlugin {
+    /// Creates a mock module without any function.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the module.
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            funcs: Vec::new(),
+            calls: MockCallLog::default(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Creates a mock `wasi_ephemeral_nn` module of the wasi_nn plugin.
+    pub fn wasi_nn() -> Self {
+        let i32_fn = |n: usize| FuncType::new(vec![ValType::I32; n], vec![ValType::I32]);
+        Self::new("wasi_ephemeral_nn")
+            .with_func("load", i32_fn(5))
+            .with_func("load_by_name", i32_fn(3))
+            .with_func("load_by_name_with_config", i32_fn(5))
+            .with_func("init_execution_context", i32_fn(2))
+            .with_func("set_input", i32_fn(3))
+            .with_func("get_output", i32_fn(5))
+            .with_func("get_output_single", i32_fn(5))
+            .with_func("compute", i32_fn(1))
+            .with_func("compute_single", i32_fn(1))
+            .with_func("fini_single", i32_fn(1))
+            .with_func("unload", i32_fn(1))
+            .with_func("finalize_execution_context", i32_fn(1))
+    }
+
+    /// Creates a mock `wasi_ephemeral_crypto_common` module of the wasi_crypto plugin.
+    pub fn wasi_crypto_common() -> Self {
+        Self::new("wasi_ephemeral_crypto_common")
+            .with_func("options_open", errno_fn(2))
+            .with_func("options_close", errno_fn(1))
+            .with_func("options_set", errno_fn(5))
+            .with_func("options_set_u64", errno_fn_with_u64(3, 0))
+            .with_func("options_set_guest_buffer", errno_fn(5))
+            .with_func("array_output_len", errno_fn(2))
+            .with_func("array_output_pull", errno_fn(4))
+            .with_func("secrets_manager_open", errno_fn(2))
+            .with_func("secrets_manager_close", errno_fn(1))
+            .with_func("secrets_manager_invalidate", errno_fn_with_u64(3, 0))
+    }
+
+    /// Creates a mock `wasi_ephemeral_crypto_asymmetric_common` module of the wasi_crypto plugin.
+    pub fn wasi_crypto_asymmetric_common() -> Self {
+        Self::new("wasi_ephemeral_crypto_asymmetric_common")
+            .with_func("keypair_generate", errno_fn(5))
+            .with_func("keypair_import", errno_fn(7))
+            .with_func("keypair_generate_managed", errno_fn(6))
+            .with_func("keypair_store_managed", errno_fn(4))
+            .with_func("keypair_replace_managed", errno_fn(4))
+            .with_func("keypair_id", errno_fn(5))
+            .with_func("keypair_from_id", errno_fn_with_u64(3, 1))
+            .with_func("keypair_from_pk_and_sk", errno_fn(3))
+            .with_func("keypair_export", errno_fn(3))
+            .with_func("keypair_publickey", errno_fn(2))
+            .with_func("keypair_secretkey", errno_fn(2))
+            .with_func("keypair_close", errno_fn(1))
+            .with_func("publickey_import", errno_fn(7))
+            .with_func("publickey_export", errno_fn(3))
+            .with_func("publickey_verify", errno_fn(1))
+            .with_func("publickey_from_secretkey", errno_fn(2))
+            .with_func("publickey_close", errno_fn(1))
+            .with_func("secretkey_import", errno_fn(7))
+            .with_func("secretkey_export", errno_fn(3))
+            .with_func("secretkey_close", errno_fn(1))
+    }
+
+    /// Creates a mock `wasi_ephemeral_crypto_kx` module of the wasi_crypto plugin.
+    pub fn wasi_crypto_kx() -> Self {
+        Self::new("wasi_ephemeral_crypto_kx")
+            .with_func("kx_dh", errno_fn(3))
+            .with_func("kx_encapsulate", errno_fn(3))
+            .with_func("kx_decapsulate", errno_fn(4))
+    }
+
+    /// Creates a mock `wasi_ephemeral_crypto_signatures` module of the wasi_crypto plugin.
+    pub fn wasi_crypto_signatures() -> Self {
+        Self::new("wasi_ephemeral_crypto_signatures")
+            .with_func("signature_export", errno_fn(3))
+            .with_func("signature_import", errno_fn(6))
+            .with_func("signature_state_open", errno_fn(2))
+            .with_func("signature_state_update", errno_fn(3))
+            .with_func("signature_state_sign", errno_fn(2))
+            .with_func("signature_state_close", errno_fn(1))
+            .with_func("signature_verification_state_open", errno_fn(2))
+            .with_func("signature_verification_state_update", errno_fn(3))
+            .with_func("signature_verification_state_verify", errno_fn(2))
+            .with_func("signature_verification_state_close", errno_fn(1))
+            .with_func("signature_close", errno_fn(1))
+    }
+
+    /// Creates a mock `wasi_ephemeral_crypto_symmetric` module of the wasi_crypto plugin.
+    pub fn wasi_crypto_symmetric() -> Self {
+        Self::new("wasi_ephemeral_crypto_symmetric")
+            .with_func("symmetric_key_generate", errno_fn(4))
+            .with_func("symmetric_key_import", errno_fn(5))
+            .with_func("symmetric_key_export", errno_fn(2))
+            .with_func("symmetric_key_close", errno_fn(1))
+            .with_func("symmetric_key_generate_managed", errno_fn(5))
+            .with_func("symmetric_key_store_managed", errno_fn(4))
+            .with_func("symmetric_key_replace_managed", errno_fn(4))
+            .with_func("symmetric_key_id", errno_fn(5))
+            .with_func("symmetric_key_from_id", errno_fn_with_u64(3, 1))
+            .with_func("symmetric_state_open", errno_fn(5))
+            .with_func("symmetric_state_clone", errno_fn(2))
+            .with_func("symmetric_state_options_get", errno_fn(6))
+            .with_func("symmetric_state_options_get_u64", errno_fn(4))
+            .with_func("symmetric_state_close", errno_fn(1))
+            .with_func("symmetric_state_absorb", errno_fn(3))
+            .with_func("symmetric_state_squeeze", errno_fn(3))
+            .with_func("symmetric_state_squeeze_tag", errno_fn(2))
+            .with_func("symmetric_state_squeeze_key", errno_fn(4))
+            .with_func("symmetric_state_max_tag_len", errno_fn(2))
+            .with_func("symmetric_state_encrypt", errno_fn(6))
+            .with_func("symmetric_state_encrypt_detached", errno_fn(6))
+            .with_func("symmetric_state_decrypt", errno_fn(6))
+            .with_func("symmetric_state_decrypt_detached", errno_fn(8))
+            .with_func("symmetric_state_ratchet", errno_fn(1))
+            .with_func("symmetric_tag_len", errno_fn(2))
+            .with_func("symmetric_tag_pull", errno_fn(4))
+            .with_func("symmetric_tag_verify", errno_fn(3))
+            .with_func("symmetric_tag_close", errno_fn(1))
+    }
+
+    /// Creates a mock `wasmedge_process` module of the wasmedge_process plugin.
+    pub fn wasmedge_process() -> Self {
+        let void_fn = |n: usize| FuncType::new(vec![ValType::I32; n], vec![]);
+        let i32_fn = || FuncType::new(vec![], vec![ValType::I32]);
+        Self::new("wasmedge_process")
+            .with_func("wasmedge_process_set_prog_name", void_fn(2))
+            .with_func("wasmedge_process_add_arg", void_fn(2))
+            .with_func("wasmedge_process_add_env", void_fn(4))
+            .with_func("wasmedge_process_add_stdin", void_fn(2))
+            .with_func("wasmedge_process_set_timeout", void_fn(1))
+            .with_func("wasmedge_process_run", i32_fn())
+            .with_func("wasmedge_process_get_exit_code", i32_fn())
+            .with_func("wasmedge_process_get_stdout_len", i32_fn())
+            .with_func("wasmedge_process_get_stdout", void_fn(1))
+            .with_func("wasmedge_process_get_stderr_len", i32_fn())
+            .with_func("wasmedge_process_get_stderr", void_fn(1))
+    }
+
+    /// Adds a function to the mock module, or replaces the type of the function with the same name.
+    ///
+    /// The modules of the plugins not covered by the constructors of [MockPlugin] can be defined by this method with the signatures the wasm module imports.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function.
+    ///
+    /// * `ty` - The type of the function.
+    pub fn with_func(mut self, name: impl AsRef<str>, ty: FuncType) -> Self {
+        match self
+            .funcs
+            .iter_mut()
+            .find(|func| func.name == name.as_ref())
+        {
+            Some(func) => func.ty = ty,
+            None => self.funcs.push(MockFunc {
+                name: name.as_ref().to_string(),
+                ty,
+                responses: VecDeque::new(),
+                handler: None,
+                calls: self.calls.clone(),
+            }),
+        }
+        self
+    }
+
+    /// Adds a scripted response to the given function. The scripted responses of a function are returned by its calls in the order they are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The name of the function.
+    ///
+    /// * `results` - The results returned by the call.
+    pub fn respond(mut self, func: impl AsRef<str>, results: Vec<WasmValue>) -> Self {
+        match self.funcs.iter_mut().find(|x| x.name == func.as_ref()) {
+            Some(func) => func.responses.push_back(results),
+            None => self.errors.push(func.as_ref().to_string()),
+        }
+        self
+    }
+
+    /// Sets the handler of the given function, which handles the calls without any scripted response left.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The name of the function.
+    ///
+    /// * `handler` - The function handling the calls. It can access the memory of the caller by the calling frame.
+    pub fn handle<F>(mut self, func: impl AsRef<str>, handler: F) -> Self
+    where
+        F: FnMut(&mut CallingFrame, Vec<WasmValue>) -> Result<Vec<WasmValue>, CoreError>
+            + Send
+            + 'static,
+    {
+        match self.funcs.iter_mut().find(|x| x.name == func.as_ref()) {
+            Some(func) => func.handler = Some(Box::new(handler)),
+            None => self.errors.push(func.as_ref().to_string()),
+        }
+        self
+    }
+
+    /// Returns the [MockCallLog] recording the calls to the functions of the mock module.
+    pub fn calls(&self) -> MockCallLog {
+        self.calls.clone()
+    }
+
+    /// Creates the [import object](crate::ImportObject) of the mock module.
+    ///
+    /// # Error
+    ///
+    /// * If a scripted response or a handler is given to a function not defined in the mock module, or a scripted response does not match the result types of the function, then [WasmEdgeError::Operation](crate::error::WasmEdgeError::Operation) is returned.
+    ///
+    /// * If fail to create the [import object](crate::ImportObject) or any function, then an error is returned.
+    pub fn build(self) -> WasmEdgeResult<ImportObject<MockHost>> {
+        if let Some(func) = self.errors.first() {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "The mock module ({}) has no function named {func}",
+                self.name
+            ))));
+        }
+        for func in self.funcs.iter() {
+            for results in func.responses.iter() {
+                let types: Vec<ValType> = results.iter().map(|x| x.ty()).collect();
+                if types != func.ty.returns() {
+                    return Err(Box::new(WasmEdgeError::Operation(format!(
+                        "The scripted response {types:?} does not match the result types {:?} of the mock function ({})",
+                        func.ty.returns(),
+                        func.name
+                    ))));
+                }
+            }
+        }
+
+        let mut import =
+            ImportObject::create(&self.name, Box::new(MockHost { funcs: Vec::new() }))?;
+        for func in self.funcs {
+            let name = func.name.clone();
+            let ty = func.ty.clone();
+            let funcs = &mut import.get_host_data_mut().funcs;
+            funcs.push(Box::new(func));
+            let data: *mut MockFunc = funcs.last_mut().map(|func| &mut **func).unwrap();
+            // the function data lives in the host data of the import object, which outlives the function
+            let function = unsafe { sys::Function::create_sync_func(&ty, mock_call, data, 0) }?;
+            import.add_func(name, function);
+        }
+        Ok(import)
+    }
+}
+
+/// Returns the type of a wasi_crypto function, which takes the given number of `i32` arguments and returns the `i32` error code.
+fn errno_fn(params: usize) -> FuncType {
+    FuncType::new(vec![ValType::I32; params], vec![ValType::I32])
+}
+
+/// Like [errno_fn], but the function takes a `u64` argument between the given numbers of `i32` arguments.
+fn errno_fn_with_u64(before: usize, after: usize) -> FuncType {
+    let mut params = vec![ValType::I32; before];
+    params.push(ValType::I64);
+    params.extend(vec![ValType::I32; after]);
+    FuncType::new(params, vec![ValType::I32])
+}
+
+/// Records a call to a mock function, and returns its results.
+fn mock_call(
+    func: &mut MockFunc,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    func.calls.push(MockCall {
+        func: func.name.clone(),
+        args: args.clone(),
+    });
+    if let Some(results) = func.responses.pop_front() {
+        return Ok(results);
+    }
+    if let Some(handler) = func.handler.as_mut() {
+        return handler(frame, args);
+    }
+    func.ty
+        .returns()
+        .iter()
+        .map(|ty| match ty {
+            ValType::I32 => Ok(WasmValue::from_i32(0)),
+            ValType::I64 => Ok(WasmValue::from_i64(0)),
+            ValType::F32 => Ok(WasmValue::from_f32(0.0)),
+            ValType::F64 => Ok(WasmValue::from_f64(0.0)),
+            ValType::V128 => Ok(WasmValue::from_v128(0)),
+            ValType::ExternRef => Ok(WasmValue::null_extern_ref()),
+            ValType::FuncRef => Ok(WasmValue::null_func_ref()),
+            ValType::UnsupportedRef => {
+                Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{params, vm::SyncInst, wat2wasm, Module, Store, Vm, WasmVal};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_mock_plugin() {
+        let wasm = wat2wasm(
+            br#"
+            (module
+                (import "wasi_ephemeral_nn" "load_by_name" (func $load (param i32 i32 i32) (result i32)))
+                (import "wasi_ephemeral_nn" "compute" (func $compute (param i32) (result i32)))
+                (func (export "infer") (param i32) (result i32)
+                    (drop (call $load (i32.const 0) (i32.const 4) (i32.const 8)))
+                    (call $compute (local.get 0)))
+            )
+            "#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm).unwrap();
+
+        // a response or a handler of an unknown function is an error
+        assert!(MockPlugin::wasi_nn()
+            .respond("not_found", vec![])
+            .build()
+            .is_err());
+        // a response must match the result types
+        assert!(MockPlugin::wasi_nn()
+            .respond("compute", vec![WasmValue::from_i64(0)])
+            .build()
+            .is_err());
+
+        let mock = MockPlugin::wasi_nn()
+            .respond("compute", vec![WasmValue::from_i32(7)])
+            .handle("compute", |_frame, args| {
+                Ok(vec![WasmValue::from_i32(args[0].to_i32() * 2)])
+            });
+        let calls = mock.calls();
+        let mut wasi_nn = mock.build().unwrap();
+
+        let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+        instances.insert(wasi_nn.name().unwrap(), &mut wasi_nn);
+        let mut vm = Vm::new(Store::new(None, instances).unwrap());
+        vm.register_module(None, module).unwrap();
+
+        // the scripted response is returned first, and then the handler is called
+        let returns = vm.run_func(None, "infer", params!(5)).unwrap();
+        assert_eq!(returns[0].to_i32(), 7);
+        let returns = vm.run_func(None, "infer", params!(5)).unwrap();
+        assert_eq!(returns[0].to_i32(), 10);
+
+        assert_eq!(calls.count("load_by_name"), 2);
+        assert_eq!(calls.count("compute"), 2);
+        let load = &calls.calls_to("load_by_name")[0];
+        let args: Vec<i32> = load.args().iter().map(|x| x.to_i32()).collect();
+        assert_eq!(args, [0, 4, 8]);
+        assert_eq!(calls.calls()[1].func(), "compute");
+
+        calls.clear();
+        assert!(calls.calls().is_empty());
+    }
+
+    #[test]
+    fn test_mock_plugin_wasi_crypto() {
+        let wasm = wat2wasm(
+            br#"
+            (module
+                (import "wasi_ephemeral_crypto_symmetric" "symmetric_key_generate" (func $generate (param i32 i32 i32 i32) (result i32)))
+                (import "wasi_ephemeral_crypto_symmetric" "symmetric_key_from_id" (func $from_id (param i32 i32 i32 i64 i32) (result i32)))
+                (import "wasi_ephemeral_crypto_symmetric" "symmetric_key_close" (func $close (param i32) (result i32)))
+                (import "env" "lookup" (func $lookup (result funcref)))
+                (func (export "run") (result i32)
+                    (drop (call $generate (i32.const 0) (i32.const 7) (i32.const 0) (i32.const 16)))
+                    (drop (call $from_id (i32.const 1) (i32.const 0) (i32.const 4) (i64.const 2) (i32.const 16)))
+                    (call $close (i32.const 3)))
+                (func (export "lookup_is_null") (result i32)
+                    (ref.is_null (call $lookup)))
+            )
+            "#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm).unwrap();
+
+        // every wasi_crypto module can be built
+        for mock in [
+            MockPlugin::wasi_crypto_common(),
+            MockPlugin::wasi_crypto_asymmetric_common(),
+            MockPlugin::wasi_crypto_kx(),
+            MockPlugin::wasi_crypto_signatures(),
+        ] {
+            assert!(mock.build().is_ok());
+        }
+
+        let mock = MockPlugin::wasi_crypto_symmetric()
+            .respond("symmetric_key_close", vec![WasmValue::from_i32(5)]);
+        let calls = mock.calls();
+        let mut symmetric = mock.build().unwrap();
+        // a mock function returning a funcref returns the null reference
+        let mut env = MockPlugin::new("env")
+            .with_func("lookup", FuncType::new(vec![], vec![ValType::FuncRef]))
+            .build()
+            .unwrap();
+
+        let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+        instances.insert(symmetric.name().unwrap(), &mut symmetric);
+        instances.insert(env.name().unwrap(), &mut env);
+        let mut vm = Vm::new(Store::new(None, instances).unwrap());
+        vm.register_module(None, module).unwrap();
+
+        let returns = vm.run_func(None, "run", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 5);
+        assert_eq!(calls.count("symmetric_key_generate"), 1);
+        let from_id = &calls.calls_to("symmetric_key_from_id")[0];
+        assert_eq!(from_id.args()[3].to_i64(), 2);
+
+        let returns = vm.run_func(None, "lookup_is_null", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 1);
+    }
+}
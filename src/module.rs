@@ -1,6 +1,6 @@
 //! Defines WasmEdge AST Module, ImportType, and ExportType.
 
-use crate::{config::Config, ExternalInstanceType, WasmEdgeResult};
+use crate::{config::Config, ExternalInstanceType, ModuleInfo, WasmEdgeResult};
 use std::{borrow::Cow, marker::PhantomData, path::Path, sync::Arc};
 use wasmedge_sys as sys;
 
@@ -10,6 +10,7 @@ use wasmedge_sys as sys;
 #[derive(Debug, Clone)]
 pub struct Module {
     pub(crate) inner: Arc<sys::Module>,
+    info: Option<Arc<ModuleInfo>>,
 }
 impl Module {
     /// Returns a validated module from a file.
@@ -26,6 +27,11 @@ impl Module {
     pub fn from_file(config: Option<&Config>, file: impl AsRef<Path>) -> WasmEdgeResult<Self> {
         let inner_config = config.map(|cfg| cfg.inner.as_ref());
 
+        // collect the module info from the file as it is loaded; AOT shared libraries are skipped
+        let info = std::fs::read(file.as_ref())
+            .ok()
+            .and_then(|bytes| parse_info(&bytes));
+
         // load module
         let inner_module = sys::Loader::create(inner_config)?.from_file(file.as_ref())?;
 
@@ -34,6 +40,7 @@ impl Module {
 
         Ok(Self {
             inner: inner_module,
+            info,
         })
     }
 
@@ -59,9 +66,81 @@ impl Module {
 
         Ok(Self {
             inner: inner_module,
+            info: parse_info(bytes.as_ref()),
         })
     }
 
+    /// Loads an AOT-compiled module from the in-memory universal wasm bytes generated by [Compiler::compile_to_bytes](crate::Compiler::compile_to_bytes).
+    ///
+    /// The bytes are checked for the custom section holding the native code, and then passed to the loader of the WasmEdge runtime like any other wasm binary, see [Module::from_bytes]. This method writes no file itself. Whether the embedded native code is used is decided by the runtime, which executes the module by the interpreter instead, for example, if the native code is compiled by another version of the runtime or for another machine, or the configuration forces the interpreter.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The global configuration.
+    ///
+    /// * `bytes` - The in-memory universal wasm bytes.
+    ///
+    /// # Error
+    ///
+    /// If the given bytes do not contain the AOT-compiled code, or fail to load and validate the module, then an error is returned.
+    pub fn from_compiled_bytes(
+        config: Option<&Config>,
+        bytes: impl AsRef<[u8]>,
+    ) -> WasmEdgeResult<Self> {
+        let bytes = bytes.as_ref();
+        if !has_aot_section(bytes) {
+            return Err(Box::new(crate::error::WasmEdgeError::Operation(
+                String::from("The given bytes do not contain the AOT-compiled code"),
+            )));
+        }
+
+        Self::from_bytes(config, bytes)
+    }
+
+    /// Loads a WebAssembly binary module from in-memory bytes through the given [compilation cache](crate::CompilationCache). The wasm bytes are AOT-compiled and cached on the first load, and the cached artifact is loaded afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The global configuration, which is also used to compile the wasm bytes.
+    ///
+    /// * `bytes` - The in-memory wasm bytes.
+    ///
+    /// * `cache` - The compilation cache to consult.
+    ///
+    /// # Error
+    ///
+    /// If fail to compile, load or validate the WebAssembly module, returns an error.
+    #[cfg(feature = "aot")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
+    pub fn from_bytes_with_cache(
+        config: Option<&Config>,
+        bytes: impl AsRef<[u8]>,
+        cache: &crate::CompilationCache,
+    ) -> WasmEdgeResult<Self> {
+        let inner_config = config.map(|cfg| cfg.inner.as_ref());
+        let aot_file = cache.get_or_compile(config, bytes.as_ref())?;
+
+        // load module
+        let inner_module = sys::Loader::create(inner_config)?.from_file(aot_file)?;
+
+        // validate module
+        sys::Validator::create(inner_config)?.validate(&inner_module)?;
+
+        Ok(Self {
+            inner: inner_module,
+            info: parse_info(bytes.as_ref()),
+        })
+    }
+
+    /// Returns the [module info](crate::ModuleInfo) describing the functions, memories, tables, globals, start function, data and element segments defined in the [module](crate::Module), and the WebAssembly proposals it uses.
+    ///
+    /// The module info is collected when the module is loaded, and shared by the clones of the module.
+    ///
+    /// Returns `None` if the module is loaded from an AOT-compiled shared library, of which the WebAssembly binary is not available.
+    pub fn info(&self) -> Option<&ModuleInfo> {
+        self.info.as_deref()
+    }
+
     /// Returns the count of the imported WasmEdge instances in the [module](crate::Module).
     pub fn count_of_imports(&self) -> u32 {
         self.inner.count_of_imports()
@@ -159,12 +238,31 @@ impl ExportType<'_> {
     }
 }
 
+/// Collects the [module info](crate::ModuleInfo) from a wasm binary or a wasm text, or returns `None` for an AOT shared library.
+fn parse_info(bytes: &[u8]) -> Option<Arc<ModuleInfo>> {
+    let info = match bytes.starts_with(b"\0asm") {
+        true => ModuleInfo::parse(bytes),
+        false => ModuleInfo::parse(&crate::wat2wasm(bytes).ok()?),
+    };
+    info.map(Arc::new)
+}
+
+/// Returns `true` if the given wasm binary carries the custom section holding the AOT-compiled code.
+fn has_aot_section(bytes: &[u8]) -> bool {
+    wasmparser::Parser::new(0)
+        .parse_all(bytes)
+        .map_while(Result::ok)
+        .any(|payload| {
+            matches!(payload, wasmparser::Payload::CustomSection(reader) if reader.name() == "wasmedge")
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         error::{CoreError, CoreLoadError, WasmEdgeError},
-        wat2wasm,
+        wat2wasm, Mutability, SegmentKind, ValType,
     };
 
     #[test]
@@ -303,4 +401,77 @@ mod tests {
         let module_clone = module.clone();
         assert_eq!(module.exports().len(), module_clone.exports().len());
     }
+
+    #[test]
+    fn test_module_info() {
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (memory 1 10)
+            (table 2 funcref)
+            (global (mut i32) (i32.const 0))
+            (func $add (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+            (func $init)
+            (start $init)
+            (data (i32.const 0) "hello")
+            (elem (i32.const 0) $add $init)
+        )
+"#,
+        )
+        .unwrap();
+
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        let info = module.info().unwrap();
+
+        // check the defined items
+        assert_eq!(info.functions().len(), 2);
+        assert_eq!(info.functions()[0].args(), &[ValType::I32, ValType::I32]);
+        assert_eq!(info.functions()[0].returns(), &[ValType::I32]);
+        assert_eq!(info.memories().len(), 1);
+        assert_eq!(info.memories()[0].minimum(), 1);
+        assert_eq!(info.memories()[0].maximum(), Some(10));
+        assert_eq!(info.tables().len(), 1);
+        assert_eq!(info.tables()[0].minimum(), 2);
+        assert_eq!(info.globals().len(), 1);
+        assert_eq!(info.globals()[0].mutability(), Mutability::Var);
+        assert_eq!(info.start_function(), Some(1));
+
+        // check the segments
+        assert_eq!(info.data_segments().len(), 1);
+        assert_eq!(info.data_segments()[0].size(), 5);
+        assert_eq!(
+            info.data_segments()[0].kind(),
+            SegmentKind::Active { index: 0 }
+        );
+        assert_eq!(info.element_segments().len(), 1);
+        assert_eq!(info.element_segments()[0].count(), 2);
+
+        // check the used proposals
+        assert!(!info.features().simd);
+        assert!(!info.features().threads);
+
+        // the info is kept by the cloned module
+        let module_clone = module.clone();
+        assert!(module_clone.info().is_some());
+
+        // load a module using simd
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (func (param v128) (result v128) (local.get 0))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        assert!(module.info().unwrap().features().simd);
+
+        // load a module from a wat file
+        let file = std::env::current_dir()
+            .unwrap()
+            .join("examples/wasmedge-sys/data/fibonacci.wat");
+        let module = Module::from_file(None, file).unwrap();
+        assert_eq!(module.info().unwrap().functions().len(), 1);
+    }
 }
@@ -0,0 +1,352 @@
+//! Defines the read-only introspection types of a WebAssembly module, such as ModuleInfo, DataSegment, ElementSegment, and ModuleFeatures.
+
+use crate::{FuncType, GlobalType, MemoryType, Mutability, RefType, TableType, ValType};
+use wasmparser::{
+    DataKind, ElementItems, ElementKind, ExternalKind, HeapType, Parser, Payload, TypeRef,
+    Validator, WasmFeatures,
+};
+
+/// Describes the internal structure of a WebAssembly module.
+///
+/// A [ModuleInfo] is collected while a [module](crate::Module) is loaded from a WebAssembly binary or text. It lists the items defined by the module itself (imported items are described by [imports](crate::Module::imports)), the data and element segments, and the WebAssembly proposals used by the module.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleInfo {
+    functions: Vec<FuncType>,
+    memories: Vec<MemoryType>,
+    tables: Vec<TableType>,
+    globals: Vec<GlobalType>,
+    start: Option<u32>,
+    data_segments: Vec<DataSegment>,
+    element_segments: Vec<ElementSegment>,
+    features: ModuleFeatures,
+    imported_memories: u32,
+    exported_memories: Vec<u32>,
+}
+impl ModuleInfo {
+    /// Returns the signatures of the functions defined in the module.
+    pub fn functions(&self) -> &[FuncType] {
+        &self.functions
+    }
+
+    /// Returns the types of the memories defined in the module.
+    ///
+    /// The limits of a 64-bit memory are saturated to `u32::MAX` pages.
+    pub fn memories(&self) -> &[MemoryType] {
+        &self.memories
+    }
+
+    /// Returns the types of the tables defined in the module.
+    pub fn tables(&self) -> &[TableType] {
+        &self.tables
+    }
+
+    /// Returns the types of the globals defined in the module.
+    pub fn globals(&self) -> &[GlobalType] {
+        &self.globals
+    }
+
+    /// Returns the index of the start function, if the module declares one.
+    pub fn start_function(&self) -> Option<u32> {
+        self.start
+    }
+
+    /// Returns the data segments of the module.
+    pub fn data_segments(&self) -> &[DataSegment] {
+        &self.data_segments
+    }
+
+    /// Returns the element segments of the module.
+    pub fn element_segments(&self) -> &[ElementSegment] {
+        &self.element_segments
+    }
+
+    /// Returns the WebAssembly proposals used by the module.
+    pub fn features(&self) -> ModuleFeatures {
+        self.features
+    }
+
+    /// Returns whether the memory of the given index among the memories defined in the module is exported, so that it can be looked up in a module instance.
+    pub(crate) fn memory_exported(&self, index: usize) -> bool {
+        let index = self.imported_memories.saturating_add(index as u32);
+        self.exported_memories.contains(&index)
+    }
+
+    /// Parses the given WebAssembly binary.
+    ///
+    /// Returns `None` if the given bytes are not a valid WebAssembly binary.
+    pub(crate) fn parse(bytes: &[u8]) -> Option<Self> {
+        let mut info = ModuleInfo::default();
+        let mut types = Vec::new();
+
+        for payload in Parser::new(0).parse_all(bytes) {
+            match payload.ok()? {
+                Payload::TypeSection(reader) => {
+                    for rec_group in reader {
+                        for sub_type in rec_group.ok()?.into_types() {
+                            let ty = match &sub_type.composite_type {
+                                wasmparser::CompositeType::Func(ty) => Some(FuncType::new(
+                                    ty.params().iter().map(|x| val_type(*x)).collect(),
+                                    ty.results().iter().map(|x| val_type(*x)).collect(),
+                                )),
+                                _ => None,
+                            };
+                            types.push(ty);
+                        }
+                    }
+                }
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        if let TypeRef::Memory(_) = import.ok()?.ty {
+                            info.imported_memories += 1;
+                        }
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export.ok()?;
+                        if export.kind == ExternalKind::Memory {
+                            info.exported_memories.push(export.index);
+                        }
+                    }
+                }
+                Payload::FunctionSection(reader) => {
+                    for type_idx in reader {
+                        let ty = types.get(type_idx.ok()? as usize)?.clone()?;
+                        info.functions.push(ty);
+                    }
+                }
+                Payload::MemorySection(reader) => {
+                    for ty in reader {
+                        let ty = ty.ok()?;
+                        let ty = MemoryType::new(
+                            saturate(ty.initial),
+                            ty.maximum.map(saturate),
+                            ty.shared,
+                        )
+                        .ok()?;
+                        info.memories.push(ty);
+                    }
+                }
+                Payload::TableSection(reader) => {
+                    for table in reader {
+                        let ty = table.ok()?.ty;
+                        info.tables.push(TableType::new(
+                            ref_type(ty.element_type),
+                            ty.initial,
+                            ty.maximum,
+                        ));
+                    }
+                }
+                Payload::GlobalSection(reader) => {
+                    for global in reader {
+                        let ty = global.ok()?.ty;
+                        let mutability = match ty.mutable {
+                            true => Mutability::Var,
+                            false => Mutability::Const,
+                        };
+                        info.globals
+                            .push(GlobalType::new(val_type(ty.content_type), mutability));
+                    }
+                }
+                Payload::StartSection { func, .. } => info.start = Some(func),
+                Payload::DataSection(reader) => {
+                    for data in reader {
+                        let data = data.ok()?;
+                        let kind = match data.kind {
+                            DataKind::Passive => SegmentKind::Passive,
+                            DataKind::Active { memory_index, .. } => SegmentKind::Active {
+                                index: memory_index,
+                            },
+                        };
+                        info.data_segments.push(DataSegment {
+                            kind,
+                            size: data.data.len(),
+                        });
+                    }
+                }
+                Payload::ElementSection(reader) => {
+                    for element in reader {
+                        let element = element.ok()?;
+                        let kind = match element.kind {
+                            ElementKind::Passive => SegmentKind::Passive,
+                            ElementKind::Declared => SegmentKind::Declared,
+                            ElementKind::Active { table_index, .. } => SegmentKind::Active {
+                                index: table_index.unwrap_or(0),
+                            },
+                        };
+                        let (ty, count) = match element.items {
+                            ElementItems::Functions(items) => (RefType::FuncRef, items.count()),
+                            ElementItems::Expressions(ty, items) => (ref_type(ty), items.count()),
+                        };
+                        info.element_segments
+                            .push(ElementSegment { kind, ty, count });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        info.features = ModuleFeatures::detect(bytes);
+
+        Some(info)
+    }
+}
+
+/// Defines how a data or element segment is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentKind {
+    /// The segment is copied into the memory or table with the given index during instantiation.
+    Active {
+        /// The index of the target memory or table.
+        index: u32,
+    },
+    /// The segment is applied explicitly by `memory.init` or `table.init`.
+    Passive,
+    /// The segment only forward-declares the referenced functions.
+    Declared,
+}
+
+/// Describes a data segment of a WebAssembly module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataSegment {
+    kind: SegmentKind,
+    size: usize,
+}
+impl DataSegment {
+    /// Returns the kind of the data segment.
+    pub fn kind(&self) -> SegmentKind {
+        self.kind
+    }
+
+    /// Returns the size in bytes of the data segment.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Describes an element segment of a WebAssembly module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementSegment {
+    kind: SegmentKind,
+    ty: RefType,
+    count: u32,
+}
+impl ElementSegment {
+    /// Returns the kind of the element segment.
+    pub fn kind(&self) -> SegmentKind {
+        self.kind
+    }
+
+    /// Returns the reference type of the elements.
+    pub fn ty(&self) -> RefType {
+        self.ty
+    }
+
+    /// Returns the number of the elements in the segment.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+/// Defines the WebAssembly proposals used by a WebAssembly module.
+///
+/// A proposal is reported as used if the module does not validate without it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModuleFeatures {
+    /// Whether the module uses the [SIMD](https://github.com/WebAssembly/simd) proposal.
+    pub simd: bool,
+    /// Whether the module uses the [Relaxed SIMD](https://github.com/WebAssembly/relaxed-simd) proposal.
+    pub relaxed_simd: bool,
+    /// Whether the module uses the [Threads](https://github.com/webassembly/threads) proposal.
+    pub threads: bool,
+    /// Whether the module uses the [Reference Types](https://github.com/WebAssembly/reference-types) proposal.
+    pub reference_types: bool,
+    /// Whether the module uses the [Bulk memory operations](https://github.com/WebAssembly/bulk-memory-operations) proposal.
+    pub bulk_memory_operations: bool,
+    /// Whether the module uses the [Multi-value](https://github.com/WebAssembly/multi-value) proposal.
+    pub multi_value: bool,
+    /// Whether the module uses the [Multiple memories](https://github.com/WebAssembly/multi-memory) proposal.
+    pub multi_memories: bool,
+    /// Whether the module uses the [Tail call](https://github.com/WebAssembly/tail-call) proposal.
+    pub tail_call: bool,
+    /// Whether the module uses the [Memory64](https://github.com/WebAssembly/memory64) proposal.
+    pub memory64: bool,
+    /// Whether the module uses the [Exception handling](https://github.com/WebAssembly/exception-handling) proposal.
+    pub exception_handling: bool,
+    /// Whether the module uses the [Typed function references](https://github.com/WebAssembly/function-references) proposal.
+    pub function_references: bool,
+    /// Whether the module uses the [GC](https://github.com/WebAssembly/gc) proposal.
+    pub gc: bool,
+}
+impl ModuleFeatures {
+    fn detect(bytes: &[u8]) -> Self {
+        let validate = |features: WasmFeatures| {
+            Validator::new_with_features(features)
+                .validate_all(bytes)
+                .is_ok()
+        };
+
+        // the module must be valid with all features enabled
+        if !validate(WasmFeatures::all()) {
+            return Self::default();
+        }
+
+        // a feature is used if the module is invalid without it
+        let used = |disable: fn(&mut WasmFeatures)| {
+            let mut features = WasmFeatures::all();
+            disable(&mut features);
+            !validate(features)
+        };
+
+        Self {
+            simd: used(|f| {
+                f.simd = false;
+                f.relaxed_simd = false;
+            }),
+            relaxed_simd: used(|f| f.relaxed_simd = false),
+            threads: used(|f| f.threads = false),
+            reference_types: used(|f| {
+                f.reference_types = false;
+                f.function_references = false;
+                f.gc = false;
+            }),
+            bulk_memory_operations: used(|f| f.bulk_memory = false),
+            multi_value: used(|f| f.multi_value = false),
+            multi_memories: used(|f| f.multi_memory = false),
+            tail_call: used(|f| f.tail_call = false),
+            memory64: used(|f| f.memory64 = false),
+            exception_handling: used(|f| f.exceptions = false),
+            function_references: used(|f| {
+                f.function_references = false;
+                f.gc = false;
+            }),
+            gc: used(|f| f.gc = false),
+        }
+    }
+}
+
+fn val_type(ty: wasmparser::ValType) -> ValType {
+    match ty {
+        wasmparser::ValType::I32 => ValType::I32,
+        wasmparser::ValType::I64 => ValType::I64,
+        wasmparser::ValType::F32 => ValType::F32,
+        wasmparser::ValType::F64 => ValType::F64,
+        wasmparser::ValType::V128 => ValType::V128,
+        wasmparser::ValType::Ref(ty) => match ty.heap_type() {
+            HeapType::Func => ValType::FuncRef,
+            HeapType::Extern => ValType::ExternRef,
+            _ => ValType::UnsupportedRef,
+        },
+    }
+}
+
+fn ref_type(ty: wasmparser::RefType) -> RefType {
+    match ty.heap_type() {
+        HeapType::Extern => RefType::ExternRef,
+        _ => RefType::FuncRef,
+    }
+}
+
+fn saturate(value: u64) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
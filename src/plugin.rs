@@ -1,10 +1,8 @@
 //! Defines plugin related structs.
 
-use crate::{instance::Instance, WasmEdgeResult};
-use wasmedge_sys::{self as sys};
-
-#[cfg(feature = "wasi_nn")]
-use wasmedge_types::error::WasmEdgeError;
+use crate::{instance::Instance, Store, WasmEdgeResult};
+use wasmedge_sys::{self as sys, AsInstance};
+use wasmedge_types::error::{PluginError, WasmEdgeError};
 
 /// Defines low-level types used in Plugin development.
 pub mod ffi {
@@ -13,10 +11,24 @@ pub mod ffi {
     };
 }
 
+#[doc(hidden)]
+#[cfg(feature = "ffi")]
+pub mod descriptor;
+
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub use descriptor::program_option;
+#[cfg(feature = "process_policy")]
+mod process;
+#[cfg(feature = "process_policy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "process_policy")))]
+pub use process::{ProcessDenial, ProcessDenialLog, ProcessHost, ProcessPolicy};
+pub use sys::plugin::{PluginVersion, ProgramOption, ProgramOptionType, ProgramOptionValue};
+
 /// Preload config for initializing the wasi_nn plug-in.
 #[cfg(feature = "wasi_nn")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NNPreload {
     /// The alias of the model in the WASI-NN environment.
     alias: String,
@@ -55,6 +67,26 @@ impl NNPreload {
             path: path.as_ref().to_owned(),
         }
     }
+
+    /// Returns the alias of the model in the WASI-NN environment.
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// Returns the inference backend.
+    pub fn backend(&self) -> &GraphEncoding {
+        &self.backend
+    }
+
+    /// Returns the execution target, on which the inference runs.
+    pub fn target(&self) -> &ExecutionTarget {
+        &self.target
+    }
+
+    /// Returns the path to the model file.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
 }
 #[cfg(feature = "wasi_nn")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
@@ -156,10 +188,13 @@ fn test_generate_nnpreload_from_str() {
 }
 
 /// Describes the encoding of the graph.
+///
+/// New encodings are added as the wasi_nn plug-in supports more backends, so a `match` on this enum needs a wildcard arm.
 #[cfg(feature = "wasi_nn")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[allow(non_camel_case_types)]
+#[non_exhaustive]
 pub enum GraphEncoding {
     OpenVINO,
     ONNX,
@@ -168,6 +203,12 @@ pub enum GraphEncoding {
     TensorFlowLite,
     Autodetect,
     GGML,
+    NeuralSpeed,
+    Whisper,
+    Piper,
+    ChatTTS,
+    MLX,
+    Burn,
 }
 #[cfg(feature = "wasi_nn")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
@@ -181,6 +222,12 @@ impl std::fmt::Display for GraphEncoding {
             GraphEncoding::GGML => write!(f, "GGML"),
             GraphEncoding::ONNX => write!(f, "ONNX"),
             GraphEncoding::Autodetect => write!(f, "Autodetect"),
+            GraphEncoding::NeuralSpeed => write!(f, "NeuralSpeed"),
+            GraphEncoding::Whisper => write!(f, "Whisper"),
+            GraphEncoding::Piper => write!(f, "Piper"),
+            GraphEncoding::ChatTTS => write!(f, "ChatTTS"),
+            GraphEncoding::MLX => write!(f, "MLX"),
+            GraphEncoding::Burn => write!(f, "Burn"),
         }
     }
 }
@@ -198,6 +245,12 @@ impl std::str::FromStr for GraphEncoding {
             "tensorflowlite" => Ok(GraphEncoding::TensorFlowLite),
             "autodetect" => Ok(GraphEncoding::Autodetect),
             "ggml" => Ok(GraphEncoding::GGML),
+            "neuralspeed" => Ok(GraphEncoding::NeuralSpeed),
+            "whisper" => Ok(GraphEncoding::Whisper),
+            "piper" => Ok(GraphEncoding::Piper),
+            "chattts" => Ok(GraphEncoding::ChatTTS),
+            "mlx" => Ok(GraphEncoding::MLX),
+            "burn" => Ok(GraphEncoding::Burn),
             _ => Err(WasmEdgeError::Operation(format!(
                 "Failed to convert to NNBackend value. Unknown NNBackend type: {}",
                 s
@@ -248,6 +301,368 @@ impl std::str::FromStr for ExecutionTarget {
     }
 }
 
+/// A set of [preloads](crate::plugin::NNPreload) for the wasi_nn plug-in, which are validated before they are passed to the plug-in.
+///
+/// The preloads can be added and removed at any time, and are passed to the wasi_nn plug-in by [apply](crate::plugin::NNPreloadSet::apply). The applied preloads replace the ones applied before, and take effect on the wasi_nn module instances created afterwards, so a [NNPreloadSet] can be changed and applied again before creating the next [Vm](crate::Vm).
+///
+/// # Example
+///
+/// ```ignore
+/// PluginManager::load(None)?;
+///
+/// let mut preloads = NNPreloadSet::new();
+/// preloads.add(NNPreload::from_str("default:GGML:AUTO:llama-2-7b-chat.Q5_K_M.gguf")?)?;
+/// preloads.add(NNPreload::new("whisper", GraphEncoding::Whisper, ExecutionTarget::CPU, "ggml-base.bin"))?;
+/// preloads.apply()?;
+///
+/// let wasi_nn = PluginManager::load_plugin_wasi_nn()?;
+/// ```
+#[cfg(feature = "wasi_nn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
+#[derive(Debug, Clone, Default)]
+pub struct NNPreloadSet {
+    preloads: Vec<NNPreload>,
+}
+#[cfg(feature = "wasi_nn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
+impl NNPreloadSet {
+    /// Creates an empty [NNPreloadSet].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a preload.
+    ///
+    /// # Argument
+    ///
+    /// * `preload` - The preload to add.
+    ///
+    /// # Error
+    ///
+    /// If the alias of the preload is invalid or already used by another preload, then [NNPreloadProblem::InvalidAlias](crate::plugin::NNPreloadProblem::InvalidAlias) or [NNPreloadProblem::DuplicateAlias](crate::plugin::NNPreloadProblem::DuplicateAlias) is returned, and the preload is not added.
+    pub fn add(&mut self, preload: NNPreload) -> Result<(), NNPreloadProblem> {
+        if let Some(problem) = check_nn_alias(&preload.alias) {
+            return Err(problem);
+        }
+        if self.get(&preload.alias).is_some() {
+            return Err(NNPreloadProblem::DuplicateAlias(preload.alias));
+        }
+        self.preloads.push(preload);
+        Ok(())
+    }
+
+    /// Removes the preload with the given alias, and returns it.
+    ///
+    /// # Argument
+    ///
+    /// * `alias` - The alias of the preload.
+    pub fn remove(&mut self, alias: impl AsRef<str>) -> Option<NNPreload> {
+        let index = self
+            .preloads
+            .iter()
+            .position(|preload| preload.alias == alias.as_ref())?;
+        Some(self.preloads.remove(index))
+    }
+
+    /// Returns the preload with the given alias.
+    ///
+    /// # Argument
+    ///
+    /// * `alias` - The alias of the preload.
+    pub fn get(&self, alias: impl AsRef<str>) -> Option<&NNPreload> {
+        self.preloads
+            .iter()
+            .find(|preload| preload.alias == alias.as_ref())
+    }
+
+    /// Returns the aliases of the preloads in the order they are added.
+    pub fn aliases(&self) -> Vec<&str> {
+        self.preloads
+            .iter()
+            .map(|preload| preload.alias.as_str())
+            .collect()
+    }
+
+    /// Returns the number of the preloads.
+    pub fn len(&self) -> usize {
+        self.preloads.len()
+    }
+
+    /// Checks if there is no preload.
+    pub fn is_empty(&self) -> bool {
+        self.preloads.is_empty()
+    }
+
+    /// Checks the preloads, and returns all problems found.
+    ///
+    /// A preload is reported if its alias is invalid or duplicated, or its model file does not exist on the host. If there is any preload, then the wasi_nn plug-in is also required to be loaded. Notice that the backends supported by the loaded plug-in cannot be queried from the WasmEdge runtime, so they are not checked.
+    pub fn validate(&self) -> Vec<NNPreloadProblem> {
+        let mut problems = Vec::new();
+        let mut aliases = std::collections::HashSet::new();
+        for preload in self.preloads.iter() {
+            if let Some(problem) = check_nn_alias(&preload.alias) {
+                problems.push(problem);
+            } else if !aliases.insert(preload.alias.as_str()) {
+                problems.push(NNPreloadProblem::DuplicateAlias(preload.alias.clone()));
+            }
+            let valid_path = preload
+                .path
+                .to_str()
+                .is_some_and(|path| !path.contains('\0'));
+            if !valid_path {
+                problems.push(NNPreloadProblem::InvalidPath {
+                    alias: preload.alias.clone(),
+                    path: preload.path.clone(),
+                });
+            } else if !preload.path.is_file() {
+                problems.push(NNPreloadProblem::NotFoundFile {
+                    alias: preload.alias.clone(),
+                    path: preload.path.clone(),
+                });
+            }
+        }
+        if !self.preloads.is_empty() && !PluginManager::names().iter().any(|name| name == "wasi_nn")
+        {
+            problems.push(NNPreloadProblem::NotFoundPlugin);
+        }
+        problems
+    }
+
+    /// Validates the preloads, and passes them to the wasi_nn plug-in.
+    ///
+    /// # Error
+    ///
+    /// If [validate](crate::plugin::NNPreloadSet::validate) reports any problem, then an error listing all problems is returned, and the preloads are not passed to the plug-in.
+    pub fn apply(&self) -> WasmEdgeResult<()> {
+        let problems = self.validate();
+        if !problems.is_empty() {
+            let problems: Vec<String> = problems.iter().map(|x| x.to_string()).collect();
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "Invalid wasi_nn preloads: {}",
+                problems.join("; ")
+            ))));
+        }
+        PluginManager::nn_preload(self.preloads.clone());
+        Ok(())
+    }
+}
+
+/// Returns the problem of the given preload alias, if any.
+#[cfg(feature = "wasi_nn")]
+fn check_nn_alias(alias: &str) -> Option<NNPreloadProblem> {
+    if alias.is_empty() || alias.contains([':', '\0']) {
+        return Some(NNPreloadProblem::InvalidAlias(alias.to_string()));
+    }
+    None
+}
+
+/// Describes a problem of a [preload](crate::plugin::NNPreload) found by [NNPreloadSet::validate](crate::plugin::NNPreloadSet::validate).
+#[cfg(feature = "wasi_nn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NNPreloadProblem {
+    /// The alias is empty, or contains `:` or a nul character.
+    InvalidAlias(String),
+    /// The alias is used by more than one preload.
+    DuplicateAlias(String),
+    /// The path of the model file is not valid UTF-8 or contains a nul character.
+    InvalidPath {
+        alias: String,
+        path: std::path::PathBuf,
+    },
+    /// The model file does not exist.
+    NotFoundFile {
+        alias: String,
+        path: std::path::PathBuf,
+    },
+    /// The wasi_nn plug-in is not loaded.
+    NotFoundPlugin,
+}
+#[cfg(feature = "wasi_nn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
+impl NNPreloadProblem {
+    /// Returns the alias of the preload with the problem, or `None` if the problem is not specific to a preload.
+    pub fn alias(&self) -> Option<&str> {
+        match self {
+            NNPreloadProblem::InvalidAlias(alias)
+            | NNPreloadProblem::DuplicateAlias(alias)
+            | NNPreloadProblem::InvalidPath { alias, .. }
+            | NNPreloadProblem::NotFoundFile { alias, .. } => Some(alias),
+            NNPreloadProblem::NotFoundPlugin => None,
+        }
+    }
+}
+#[cfg(feature = "wasi_nn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
+impl std::fmt::Display for NNPreloadProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NNPreloadProblem::InvalidAlias(alias) => write!(f, "invalid alias '{alias}'"),
+            NNPreloadProblem::DuplicateAlias(alias) => write!(f, "duplicate alias '{alias}'"),
+            NNPreloadProblem::InvalidPath { alias, path } => write!(
+                f,
+                "invalid model path '{}' of the alias '{alias}'",
+                path.display()
+            ),
+            NNPreloadProblem::NotFoundFile { alias, path } => write!(
+                f,
+                "model file '{}' of the alias '{alias}' is not found",
+                path.display()
+            ),
+            NNPreloadProblem::NotFoundPlugin => write!(f, "the wasi_nn plug-in is not loaded"),
+        }
+    }
+}
+#[cfg(feature = "wasi_nn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
+impl std::error::Error for NNPreloadProblem {}
+
+/// The paths given to [PluginManager::load], which are searched for the metadata of the loaded plugins.
+static LOADED_PATHS: std::sync::Mutex<Vec<std::path::PathBuf>> = std::sync::Mutex::new(Vec::new());
+
+/// The latest values of the program options set by [PluginManager::set_option], keyed by the plugin name and the option name.
+///
+/// The lock is also held while this crate creates a module instance from a plugin, so that the storage of an option is not written while the plugin reads it.
+static OPTION_VALUES: std::sync::Mutex<std::collections::BTreeMap<(String, String), OptionValue>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+/// The value of a program option set by [PluginManager::set_option].
+#[derive(Debug)]
+struct OptionValue {
+    value: String,
+    // the string the storage of a string option refers to
+    storage: Option<String>,
+    // false until the plugin is loaded and the value is written into the storage
+    written: bool,
+}
+
+/// Writes the values set for the plugins which were not loaded yet into the storages of their options. The values of the plugins which are still not loaded are kept.
+///
+/// If a value cannot be written, then it is dropped, and the first error is returned after the other values are written.
+fn write_pending_options() -> WasmEdgeResult<()> {
+    let mut values = OPTION_VALUES.lock().unwrap_or_else(|err| err.into_inner());
+    let mut first_error = None;
+    values.retain(|(plugin, name), option| {
+        if option.written {
+            return true;
+        }
+        match write_plugin_option(plugin, name, &option.value) {
+            Ok(storage) => {
+                option.storage = storage;
+                option.written = true;
+                true
+            }
+            Err(err) if matches!(*err, WasmEdgeError::Plugin(PluginError::NotFound(_))) => true,
+            Err(err) => {
+                first_error.get_or_insert(err);
+                false
+            }
+        }
+    });
+    match first_error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Calls the given function, which creates module instances from plugins, while no program option is being written.
+fn with_options_unchanged<R>(f: impl FnOnce() -> R) -> R {
+    let _values = OPTION_VALUES.lock().unwrap_or_else(|err| err.into_inner());
+    f()
+}
+
+/// Writes the value of a typed program option into the storage of the given plugin, and returns the string the storage refers to, if any.
+fn write_plugin_option(plugin: &str, name: &str, value: &str) -> WasmEdgeResult<Option<String>> {
+    #[cfg(feature = "ffi")]
+    if let Some(descriptor) = descriptor::defined_descriptor(plugin) {
+        return unsafe { sys::plugin::set_raw_program_option(descriptor, name, value) };
+    }
+    #[cfg(unix)]
+    {
+        let paths = LOADED_PATHS
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone();
+        for path in paths {
+            if let Some(result) =
+                sys::plugin::PluginManager::set_loaded_plugin_option(path, plugin, name, value)
+            {
+                return result;
+            }
+        }
+    }
+    Err(Box::new(WasmEdgeError::Plugin(PluginError::NotFound(
+        plugin.to_string(),
+    ))))
+}
+
+/// Returns the metadata of the plugins defined with the C API, which are loaded from the paths given to [PluginManager::load] and [PluginSet::load].
+fn loaded_metadata() -> Vec<sys::plugin::PluginMetadata> {
+    #[allow(unused_mut)]
+    let mut metadata: Vec<sys::plugin::PluginMetadata> = Vec::new();
+    #[cfg(unix)]
+    {
+        let paths = LOADED_PATHS
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone();
+        for path in paths {
+            for plugin in sys::plugin::PluginManager::loaded_plugin_metadata(path) {
+                if !metadata.iter().any(|loaded| loaded.name == plugin.name) {
+                    metadata.push(plugin);
+                }
+            }
+        }
+    }
+    metadata
+}
+
+/// Returns the default plugin paths searched by the WasmEdge runtime.
+pub(crate) fn default_plugin_paths() -> Vec<std::path::PathBuf> {
+    let mut paths = Vec::new();
+    if let Some(env_paths) = std::env::var_os("WASMEDGE_PLUGIN_PATH") {
+        paths.extend(std::env::split_paths(&env_paths));
+    }
+    // the runtime searches the directories next to the library
+    #[cfg(unix)]
+    if let Some(lib_dir) = sys::plugin::PluginManager::library_path()
+        .as_deref()
+        .and_then(std::path::Path::parent)
+    {
+        if let Some(prefix) = lib_dir.parent() {
+            paths.push(prefix.join("plugin"));
+        }
+        if lib_dir.starts_with("/usr") {
+            paths.push(lib_dir.join("wasmedge"));
+        }
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        paths.push(std::path::Path::new(&home).join(".wasmedge/plugin"));
+    }
+    for dir in [
+        "/usr/local/lib/wasmedge",
+        "/usr/local/lib64/wasmedge",
+        "/usr/lib/wasmedge",
+        "/usr/lib64/wasmedge",
+    ] {
+        let dir = std::path::PathBuf::from(dir);
+        if !paths.contains(&dir) {
+            paths.push(dir);
+        }
+    }
+    paths
+}
+
+/// Records the given paths as the paths from which the plugins are loaded.
+fn record_loaded_paths(paths: impl IntoIterator<Item = std::path::PathBuf>) {
+    let mut loaded = LOADED_PATHS.lock().unwrap_or_else(|err| err.into_inner());
+    for path in paths {
+        if !loaded.contains(&path) {
+            loaded.push(path);
+        }
+    }
+}
+
 /// Defines the API to manage plugins.
 #[derive(Debug)]
 pub struct PluginManager {}
@@ -271,14 +686,24 @@ impl PluginManager {
     /// # Argument
     ///
     /// * `path` - A path to a plugin file or a directory holding plugin files. If `None`, then the default plugin path will be used.
+    ///
+    /// # Error
+    ///
+    /// * If fail to load the plugins from the given path, then an error is returned.
+    ///
+    /// * If a value set by [set_option](crate::plugin::PluginManager::set_option) before a plugin is loaded cannot be written into the option, then the error is returned after the plugins are loaded. See [set_option](crate::plugin::PluginManager::set_option).
     pub fn load(path: Option<&std::path::Path>) -> WasmEdgeResult<()> {
         match path {
-            Some(p) => sys::plugin::PluginManager::load_plugins(p),
+            Some(p) => {
+                sys::plugin::PluginManager::load_plugins(p)?;
+                record_loaded_paths([p.to_path_buf()]);
+            }
             None => {
                 sys::plugin::PluginManager::load_plugins_from_default_paths();
-                Ok(())
+                record_loaded_paths(default_plugin_paths());
             }
         }
+        write_pending_options()
     }
 
     /// Initialize the wasi_nn plug-in with the preloads.
@@ -314,6 +739,8 @@ impl PluginManager {
     /// PluginManager::nn_preload(vec![NNPreload::from_str("default:GGML:CPU:llama-2-7b-chat.Q5_K_M.gguf")?]);
     ///
     /// ```
+    ///
+    /// The preloads are passed to the plug-in without any check. To validate them first, use [NNPreloadSet](crate::plugin::NNPreloadSet).
     #[cfg(feature = "wasi_nn")]
     #[cfg_attr(docsrs, doc(cfg(feature = "wasi_nn")))]
     pub fn nn_preload(preloads: Vec<NNPreload>) {
@@ -350,11 +777,140 @@ impl PluginManager {
         sys::plugin::PluginManager::find(name.as_ref()).map(|p| Plugin { inner: p })
     }
 
+    /// Sets the value of a typed program option of a plugin defined with the C API, for example by [wasmedge_plugin](crate::wasmedge_plugin).
+    ///
+    /// The value is written into the storage of the option held by the plugin, so it is read by the module instances created from the plugin afterwards. The plugin must be defined in this process, or loaded by [PluginManager::load](crate::plugin::PluginManager::load) or [PluginSet::load](crate::plugin::PluginSet::load) on a Unix-like system. The options of the other plugins, such as the official plugins written in C++, cannot be set by this function.
+    ///
+    /// If the plugin is not loaded yet, then the value is kept, and written when the plugin is loaded by [PluginManager::load](crate::plugin::PluginManager::load) or [PluginSet::load](crate::plugin::PluginSet::load), which report the errors of the option and the value instead. Only the latest value of each option is kept.
+    ///
+    /// The value is not written while this crate creates a module instance from a plugin. A plugin reading its options at other times, for example in its host functions, must not rely on them being unchanged, and must copy a string option it keeps, since the storage refers to the latest value only.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - The name of the plugin.
+    ///
+    /// * `name` - The name of the program option.
+    ///
+    /// * `value` - The string representation of the value, which is parsed according to the type of the option.
+    ///
+    /// # Error
+    ///
+    /// * If the plugin is loaded, but its options cannot be set by this function, then [PluginError::NotFound](wasmedge_types::error::PluginError::NotFound) is returned.
+    ///
+    /// * If the plugin has no typed program option of the given name, then [PluginError::NotFoundOption](wasmedge_types::error::PluginError::NotFoundOption) is returned.
+    ///
+    /// * If the value cannot be parsed according to the type of the option, then [PluginError::InvalidOptionValue](wasmedge_types::error::PluginError::InvalidOptionValue) is returned.
+    pub fn set_option(
+        plugin: impl AsRef<str>,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> WasmEdgeResult<()> {
+        let (plugin, name, value) = (plugin.as_ref(), name.as_ref(), value.as_ref());
+        let mut values = OPTION_VALUES.lock().unwrap_or_else(|err| err.into_inner());
+        let option = match write_plugin_option(plugin, name, value) {
+            Ok(storage) => OptionValue {
+                value: value.to_string(),
+                storage,
+                written: true,
+            },
+            Err(err)
+                if matches!(*err, WasmEdgeError::Plugin(PluginError::NotFound(_)))
+                    && !PluginManager::names().iter().any(|x| x == plugin) =>
+            {
+                OptionValue {
+                    value: value.to_string(),
+                    storage: None,
+                    written: false,
+                }
+            }
+            Err(err) => return Err(err),
+        };
+        // the replaced string is dropped after the storage refers to the new one
+        values.insert((plugin.to_string(), name.to_string()), option);
+        Ok(())
+    }
+
+    /// Returns a report of all loaded plugins.
+    ///
+    /// For each loaded plugin, the report holds its modules. The other metadata, such as the version, the description, the program options and the path of the plugin, is read from the descriptor of the plugin. It is available only for the plugins which are loaded by [PluginManager::load] and are defined with the C API, for example by [wasmedge_plugin](crate::wasmedge_plugin), on Unix-like systems. For the other plugins, such as the official plugins written in C++, these fields are `None` or empty.
+    ///
+    /// No module instance is created, so the [errors](PluginModuleInfo::error) of the modules are not reported. Use [PluginManager::inventory_checked] to report them.
+    pub fn inventory() -> Vec<PluginInfo> {
+        Self::collect_inventory(false)
+    }
+
+    /// Returns a report of all loaded plugins like [PluginManager::inventory], which also holds the error which occurs when creating a module instance from each module of the plugins.
+    ///
+    /// Notice that a module instance is created and then dropped for each module of the plugins, so the side effects of creating the module instances, if any, also occur.
+    pub fn inventory_checked() -> Vec<PluginInfo> {
+        Self::collect_inventory(true)
+    }
+
+    fn collect_inventory(check: bool) -> Vec<PluginInfo> {
+        let metadata = loaded_metadata();
+
+        PluginManager::names()
+            .into_iter()
+            .map(|name| {
+                let meta = metadata.iter().find(|meta| meta.name == name);
+                let modules = match PluginManager::find(&name) {
+                    Ok(plugin) => plugin
+                        .mod_names()
+                        .into_iter()
+                        .map(|mod_name| {
+                            let description = meta.and_then(|meta| {
+                                meta.modules
+                                    .iter()
+                                    .find(|(name, _)| name == &mod_name)
+                                    .map(|(_, desc)| desc.clone())
+                            });
+                            let error = match check {
+                                true => plugin
+                                    .mod_instance(&mod_name)
+                                    .err()
+                                    .map(|err| err.to_string()),
+                                false => None,
+                            };
+                            PluginModuleInfo {
+                                name: mod_name,
+                                description,
+                                error,
+                            }
+                        })
+                        .collect(),
+                    Err(_) => Vec::new(),
+                };
+                PluginInfo {
+                    name,
+                    path: meta.map(|meta| meta.path.clone()),
+                    description: meta.map(|meta| meta.description.clone()),
+                    version: meta.map(|meta| meta.version),
+                    api_version: meta.map(|meta| meta.api_version),
+                    modules,
+                    options: meta
+                        .map(|meta| {
+                            meta.options
+                                .iter()
+                                .map(|(name, desc, ty)| PluginOptionInfo {
+                                    name: name.clone(),
+                                    description: desc.clone(),
+                                    ty: *ty,
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+
     pub fn create_plugin_instance(
         pname: impl AsRef<str>,
         mname: impl AsRef<str>,
     ) -> WasmEdgeResult<PluginInstance> {
-        let plugin = sys::plugin::PluginManager::create_plugin_instance(pname, mname)?;
+        let plugin = with_options_unchanged(|| {
+            sys::plugin::PluginManager::create_plugin_instance(pname, mname)
+        })?;
         Ok(plugin)
     }
 
@@ -365,6 +921,8 @@ impl PluginManager {
     /// * `allowed_cmds` - A white list of commands.
     ///
     /// * `allowed` - Determines if wasmedge_process is allowed to execute all commands on the white list.
+    ///
+    /// The configuration is shared by all module instances of the plugin in this process. To configure the commands per module instance, use `ProcessPolicy`, which requires the `process_policy` feature.
     #[cfg(all(
         target_os = "linux",
         feature = "wasmedge_process",
@@ -419,6 +977,292 @@ impl PluginManager {
     }
 }
 
+/// Returns the plugin name derived from the file name of a plugin library, in lower case and without the non-alphanumeric characters, for example, `wasinn` for `libwasmedgePluginWasiNN.so`.
+fn plugin_file_key(path: &std::path::Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let stem = stem.strip_prefix("lib").unwrap_or(stem);
+    let stem = match stem.get(..14) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("wasmedgePlugin") => &stem[14..],
+        _ => stem,
+    };
+    Some(plugin_name_key(stem))
+}
+
+/// Returns the given plugin name in lower case and without the non-alphanumeric characters.
+fn plugin_name_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Defines an allowed plugin of a [PluginSet].
+#[derive(Debug, Clone)]
+struct AllowedPlugin {
+    name: String,
+    version: Option<PluginVersion>,
+}
+
+/// A [PluginSet] loads an explicit set of plugins, and registers the module instances of the allowed plugins only into a [store](crate::Store).
+///
+/// The plugins are loaded from the files given by [with_file](crate::plugin::PluginSet::with_file). If no file is given, then the plugins are loaded from the default plugin paths (see [PluginManager::load]), but only the files whose names match the allowed plugin names are loaded, for example, `libwasmedgePluginWasiNN.so` for the plugin `wasi_nn`. The other files in the default plugin paths are not loaded.
+///
+/// The content of a library file cannot be inspected before loading without running its code, so the plugins are verified in two steps:
+///
+/// * Before any file is loaded into the WasmEdge runtime, the name of each file must match an allowed plugin name.
+///
+/// * After the files are loaded, a plugin defined with the C API, for example by [wasmedge_plugin](crate::wasmedge_plugin), is identified by its descriptor on Unix-like systems. Its name must be allowed, and its version must be the expected one, if any. The versions of the other plugins, such as the official plugins written in C++, cannot be read, so such a plugin cannot be allowed by [allow_version](crate::plugin::PluginSet::allow_version).
+///
+/// A plugin rejected by the second step stays loaded, because the runtime cannot unload it, but its module instances are not registered by [register](crate::plugin::PluginSet::register).
+///
+/// Notice that the loaded plugins are kept in the global state of the process by the WasmEdge runtime, and cannot be unloaded. A [PluginSet] only restricts which plugins are loaded by itself and which module instances are registered into a [store](crate::Store). The plugins loaded by [PluginManager::load] are still visible to [PluginManager].
+///
+/// # Example
+///
+/// ```ignore
+/// let mut plugins = PluginSet::new()
+///     .allow("wasi_logging")
+///     .allow_version("wasi_nn", PluginVersion::create(0, 10, 1, 0));
+/// plugins.load()?;
+///
+/// let mut store = Store::new(None, HashMap::<String, &mut dyn SyncInst>::new())?;
+/// plugins.register(&mut store)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PluginSet {
+    files: Vec<std::path::PathBuf>,
+    allowed: Vec<AllowedPlugin>,
+}
+impl PluginSet {
+    /// Creates a new [PluginSet] without any allowed plugin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a plugin file to load. If any file is given, then the default plugin paths are not searched.
+    ///
+    /// # Argument
+    ///
+    /// * `path` - The path to the plugin file.
+    pub fn with_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Allows the plugin of the given name in any version.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the plugin.
+    pub fn allow(mut self, name: impl AsRef<str>) -> Self {
+        self.insert(name.as_ref(), None);
+        self
+    }
+
+    /// Allows the plugin of the given name in the given version only.
+    ///
+    /// The version is read from the descriptor of the plugin, so it can be verified only for the plugins defined with the C API, for example by [wasmedge_plugin](crate::wasmedge_plugin), on Unix-like systems. Loading an official plugin written in C++ with an expected version fails with [PluginError::UnverifiedVersion](wasmedge_types::error::PluginError::UnverifiedVersion).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the plugin.
+    ///
+    /// * `version` - The expected version of the plugin.
+    pub fn allow_version(mut self, name: impl AsRef<str>, version: PluginVersion) -> Self {
+        self.insert(name.as_ref(), Some(version));
+        self
+    }
+
+    fn insert(&mut self, name: &str, version: Option<PluginVersion>) {
+        match self.allowed.iter_mut().find(|plugin| plugin.name == name) {
+            Some(plugin) => plugin.version = version,
+            None => self.allowed.push(AllowedPlugin {
+                name: name.to_string(),
+                version,
+            }),
+        }
+    }
+
+    /// Returns the names of the allowed plugins.
+    pub fn names(&self) -> Vec<&str> {
+        self.allowed
+            .iter()
+            .map(|plugin| plugin.name.as_str())
+            .collect()
+    }
+
+    /// Verifies the names of the plugin files, loads them, and then verifies the loaded plugins. See [PluginSet] for how the plugins are verified.
+    ///
+    /// # Error
+    ///
+    /// * If the name of a file does not match an allowed plugin name, then [PluginError::NotAllowed](wasmedge_types::error::PluginError::NotAllowed) is returned, and no file is loaded.
+    ///
+    /// * If a loaded file holds a plugin which is not allowed, then [PluginError::NotAllowed](wasmedge_types::error::PluginError::NotAllowed) is returned.
+    ///
+    /// * If the version of an allowed plugin is not the expected one, then [PluginError::VersionMismatch](wasmedge_types::error::PluginError::VersionMismatch) is returned. If the version cannot be read, then [PluginError::UnverifiedVersion](wasmedge_types::error::PluginError::UnverifiedVersion) is returned.
+    ///
+    /// * If a value set by [PluginManager::set_option](crate::plugin::PluginManager::set_option) before a plugin is loaded cannot be written into the option, then the error is returned.
+    ///
+    /// * If fail to load a plugin file, then an error is returned.
+    ///
+    /// * If an allowed plugin is not provided by the files, or is not loaded, then [PluginError::NotFound](wasmedge_types::error::PluginError::NotFound) is returned.
+    pub fn load(&self) -> WasmEdgeResult<()> {
+        let files = match self.files.is_empty() {
+            false => self.files.clone(),
+            true => self.allowed_default_files(),
+        };
+
+        let mut provided: Vec<&str> = Vec::new();
+        for file in files.iter() {
+            let plugin = self.verify_file_name(file)?;
+            if !provided.contains(&plugin.name.as_str()) {
+                provided.push(plugin.name.as_str());
+            }
+        }
+
+        for file in files.iter() {
+            sys::plugin::PluginManager::load_plugins(file)?;
+        }
+        record_loaded_paths(files.iter().cloned());
+        for file in files.iter() {
+            self.verify_loaded_file(file)?;
+        }
+        write_pending_options()?;
+
+        let names = PluginManager::names();
+        for plugin in self.allowed.iter() {
+            if !provided.contains(&plugin.name.as_str()) || !names.contains(&plugin.name) {
+                return Err(Box::new(WasmEdgeError::Plugin(PluginError::NotFound(
+                    plugin.name.clone(),
+                ))));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the allowed plugin whose name matches the name of the given file.
+    fn verify_file_name(&self, file: &std::path::Path) -> WasmEdgeResult<&AllowedPlugin> {
+        let key = plugin_file_key(file);
+        self.allowed
+            .iter()
+            .find(|plugin| key.as_deref() == Some(plugin_name_key(&plugin.name).as_str()))
+            .ok_or_else(|| {
+                Box::new(WasmEdgeError::Plugin(PluginError::NotAllowed(
+                    file.display().to_string(),
+                )))
+            })
+    }
+
+    /// Verifies the names and the versions of the plugins loaded from the given file.
+    fn verify_loaded_file(&self, file: &std::path::Path) -> WasmEdgeResult<()> {
+        #[cfg(unix)]
+        let found = sys::plugin::PluginManager::loaded_plugin_metadata(file);
+        #[cfg(not(unix))]
+        let found: Vec<sys::plugin::PluginMetadata> = Vec::new();
+
+        // the file holds no plugin defined with the C API, so only its name is verified
+        if found.is_empty() {
+            let plugin = self.verify_file_name(file)?;
+            return self.verify_version(plugin, None);
+        }
+        for meta in found {
+            let plugin = self
+                .allowed
+                .iter()
+                .find(|plugin| plugin.name == meta.name)
+                .ok_or_else(|| {
+                    Box::new(WasmEdgeError::Plugin(PluginError::NotAllowed(
+                        meta.name.clone(),
+                    )))
+                })?;
+            self.verify_version(plugin, Some(meta.version))?;
+        }
+        Ok(())
+    }
+
+    /// Checks the given version, if known, against the expected version of the given plugin, if any.
+    fn verify_version(
+        &self,
+        plugin: &AllowedPlugin,
+        found: Option<PluginVersion>,
+    ) -> WasmEdgeResult<()> {
+        match (plugin.version, found) {
+            (None, _) => Ok(()),
+            (Some(expected), Some(found)) if expected == found => Ok(()),
+            (Some(expected), Some(found)) => Err(Box::new(WasmEdgeError::Plugin(
+                PluginError::VersionMismatch {
+                    name: plugin.name.clone(),
+                    expected: expected.to_string(),
+                    found: found.to_string(),
+                },
+            ))),
+            (Some(_), None) => Err(Box::new(WasmEdgeError::Plugin(
+                PluginError::UnverifiedVersion(plugin.name.clone()),
+            ))),
+        }
+    }
+
+    /// Returns the files in the default plugin paths whose names match the allowed plugin names.
+    fn allowed_default_files(&self) -> Vec<std::path::PathBuf> {
+        let keys: Vec<String> = self
+            .allowed
+            .iter()
+            .map(|plugin| plugin_name_key(&plugin.name))
+            .collect();
+        let mut files = Vec::new();
+        for dir in default_plugin_paths() {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+                let is_library = path.extension().and_then(|ext| ext.to_str())
+                    == Some(std::env::consts::DLL_EXTENSION);
+                if is_library
+                    && plugin_file_key(&path).is_some_and(|key| keys.contains(&key))
+                    && !files.contains(&path)
+                {
+                    files.push(path);
+                }
+            }
+        }
+        files
+    }
+
+    /// Creates the module instances of the allowed plugins, and registers them into the given [store](crate::Store). Returns the names of the registered module instances.
+    ///
+    /// # Argument
+    ///
+    /// * `store` - The target [store](crate::Store).
+    ///
+    /// # Error
+    ///
+    /// * If the version of a loaded allowed plugin is not the expected one, or cannot be read, then an error is returned, and no module instance is registered. See [load](crate::plugin::PluginSet::load).
+    ///
+    /// * If an allowed plugin is not loaded, or fail to create or register any module instance, then an error is returned.
+    pub fn register<T: AsInstance + ?Sized>(
+        &self,
+        store: &mut Store<'_, T>,
+    ) -> WasmEdgeResult<Vec<String>> {
+        // a plugin rejected by the verification after loading is still loaded
+        let metadata = loaded_metadata();
+        for plugin in self.allowed.iter() {
+            let meta = metadata.iter().find(|meta| meta.name == plugin.name);
+            self.verify_version(plugin, meta.map(|meta| meta.version))?;
+        }
+
+        let mut registered = Vec::new();
+        for plugin in self.allowed.iter() {
+            let plugin = PluginManager::find(&plugin.name)?;
+            for mod_name in plugin.mod_names() {
+                let instance = plugin.mod_instance(&mod_name)?;
+                registered.push(store.register_owned_instance(instance)?);
+            }
+        }
+        Ok(registered)
+    }
+}
+
 /// Represents a loaded plugin. It provides the APIs for accessing the plugin.
 #[derive(Debug)]
 pub struct Plugin {
@@ -450,8 +1294,486 @@ impl Plugin {
     ///
     /// If failed to return the plugin module instance, then return [PluginError::Create](wasmedge_types::error::PluginError::Create) error.
     pub fn mod_instance(&self, name: impl AsRef<str>) -> WasmEdgeResult<PluginInstance> {
-        self.inner.mod_instance(name.as_ref())
+        with_options_unchanged(|| self.inner.mod_instance(name.as_ref()))
     }
 }
 
 pub type PluginInstance = Instance;
+
+/// Describes a loaded plugin. It is returned by [PluginManager::inventory].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginInfo {
+    name: String,
+    path: Option<std::path::PathBuf>,
+    description: Option<String>,
+    version: Option<PluginVersion>,
+    api_version: Option<u32>,
+    modules: Vec<PluginModuleInfo>,
+    options: Vec<PluginOptionInfo>,
+}
+impl PluginInfo {
+    /// Returns the name of the plugin.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path of the library file from which the plugin is loaded, if known.
+    pub fn path(&self) -> Option<&std::path::Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the description of the plugin, if known.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the version of the plugin, if known.
+    pub fn version(&self) -> Option<PluginVersion> {
+        self.version
+    }
+
+    /// Returns the plugin API version the plugin is built with, if known.
+    pub fn api_version(&self) -> Option<u32> {
+        self.api_version
+    }
+
+    /// Returns the modules of the plugin.
+    pub fn modules(&self) -> &[PluginModuleInfo] {
+        &self.modules
+    }
+
+    /// Returns the program options of the plugin.
+    pub fn options(&self) -> &[PluginOptionInfo] {
+        &self.options
+    }
+}
+impl std::fmt::Display for PluginInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(version) = &self.version {
+            write!(f, " {version}")?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, " ({})", path.display())?;
+        }
+        for module in &self.modules {
+            write!(f, "\n  {module}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes a module of a loaded plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginModuleInfo {
+    name: String,
+    description: Option<String>,
+    error: Option<String>,
+}
+impl PluginModuleInfo {
+    /// Returns the name of the module.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of the module, if known.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the error which occurs when creating a module instance from the module, or `None` if the module instance is created successfully or the modules are not checked by [PluginManager::inventory_checked].
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+impl std::fmt::Display for PluginModuleInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(description) = &self.description {
+            write!(f, ": {description}")?;
+        }
+        if let Some(error) = &self.error {
+            write!(f, " [error: {error}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes a program option of a loaded plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginOptionInfo {
+    name: String,
+    description: String,
+    ty: ProgramOptionType,
+}
+impl PluginOptionInfo {
+    /// Returns the name of the program option.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of the program option.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the type of the program option.
+    pub fn ty(&self) -> ProgramOptionType {
+        self.ty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_inventory() {
+        let info = PluginInfo {
+            name: String::from("math_plugin"),
+            path: Some(std::path::PathBuf::from("/plugins/libmath.so")),
+            description: Some(String::from("A plugin providing math functions")),
+            version: Some(PluginVersion::create(0, 1, 2, 3)),
+            api_version: Some(2),
+            modules: vec![
+                PluginModuleInfo {
+                    name: String::from("math"),
+                    description: Some(String::from("The math functions")),
+                    error: None,
+                },
+                PluginModuleInfo {
+                    name: String::from("math_gpu"),
+                    description: None,
+                    error: Some(String::from("no device")),
+                },
+            ],
+            options: vec![],
+        };
+        assert_eq!(
+            info.to_string(),
+            "math_plugin 0.1.2.3 (/plugins/libmath.so)\n  math: The math functions\n  math_gpu [error: no device]"
+        );
+        assert_eq!(info.modules()[1].error(), Some("no device"));
+
+        // every loaded plugin is reported
+        PluginManager::load(None).unwrap();
+        let inventory = PluginManager::inventory();
+        let names: Vec<String> = inventory.iter().map(|p| p.name().to_string()).collect();
+        assert_eq!(names, PluginManager::names());
+    }
+
+    /// Builds the `wasmedge-test-plugin` crate once, and returns the path of the plugin library.
+    #[cfg(unix)]
+    fn build_test_plugin() -> std::path::PathBuf {
+        static PLUGIN_FILE: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+
+        PLUGIN_FILE
+            .get_or_init(|| {
+                // the test binary is in `<target dir>/<profile>/deps`; the plugin is built into a
+                // target directory of its own, so that the build does not wait for the lock of the
+                // target directory held by the cargo running the tests
+                let exe = std::env::current_exe().unwrap();
+                let target_dir = exe.ancestors().nth(3).unwrap().join("wasmedge-test-plugin");
+                let output = std::process::Command::new(env!("CARGO"))
+                    .args([
+                        "build",
+                        "-p",
+                        "wasmedge-test-plugin",
+                        "--message-format=json",
+                    ])
+                    .arg("--target-dir")
+                    .arg(&target_dir)
+                    .current_dir(env!("CARGO_MANIFEST_DIR"))
+                    .stderr(std::process::Stdio::inherit())
+                    .output()
+                    .unwrap();
+                assert!(output.status.success());
+
+                // the path of the library is reported by cargo, whatever the profile is
+                let filename = format!(
+                    "{}wasmedge_test_plugin.{}",
+                    std::env::consts::DLL_PREFIX,
+                    std::env::consts::DLL_EXTENSION
+                );
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+                    .filter(|message| message["reason"] == "compiler-artifact")
+                    .flat_map(|message| {
+                        message["filenames"].as_array().cloned().unwrap_or_default()
+                    })
+                    .filter_map(|path| path.as_str().map(std::path::PathBuf::from))
+                    .find(|path| path.file_name() == Some(filename.as_ref()))
+                    .expect("cargo reports the plugin library")
+            })
+            .clone()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_plugin_inventory_of_loaded_plugin() {
+        use crate::{params, vm::SyncInst, WasmVal};
+        use std::collections::HashMap;
+
+        let plugin_file = build_test_plugin();
+        // the value set before loading is written when the plugin is loaded
+        PluginManager::set_option("test_plugin", "test-scale", "3").unwrap();
+        PluginManager::load(Some(&plugin_file)).unwrap();
+
+        // the metadata is read from the descriptor of the plugin
+        let inventory = PluginManager::inventory();
+        let info = inventory
+            .iter()
+            .find(|info| info.name() == "test_plugin")
+            .unwrap();
+        assert_eq!(info.version(), Some(PluginVersion::create(1, 2, 3, 4)));
+        assert_eq!(info.path(), Some(plugin_file.as_path()));
+        assert_eq!(info.description(), Some("A plugin for the tests"));
+        let options: Vec<(&str, ProgramOptionType)> = info
+            .options()
+            .iter()
+            .map(|option| (option.name(), option.ty()))
+            .collect();
+        assert_eq!(
+            options,
+            [
+                ("test-scale", ProgramOptionType::I32),
+                ("test-verbose", ProgramOptionType::Toggle)
+            ]
+        );
+
+        // the modules are not instantiated unless checked
+        let modules = info.modules();
+        assert_eq!(modules.len(), 2);
+        let math = modules.iter().find(|m| m.name() == "test_math").unwrap();
+        assert_eq!(math.description(), Some("The math functions"));
+        assert!(modules.iter().all(|m| m.error().is_none()));
+
+        // the error of each module is reported when checked
+        let inventory = PluginManager::inventory_checked();
+        let modules = inventory
+            .iter()
+            .find(|info| info.name() == "test_plugin")
+            .unwrap()
+            .modules();
+        let math = modules.iter().find(|m| m.name() == "test_math").unwrap();
+        assert_eq!(math.error(), None);
+        let broken = modules.iter().find(|m| m.name() == "test_broken").unwrap();
+        assert!(broken.error().is_some());
+
+        // the options are set in the loaded plugin
+        assert!(PluginManager::set_option("test_plugin", "test-scale", "x").is_err());
+        assert!(PluginManager::set_option("test_plugin", "test-verbose", "on").is_err());
+        let mut instance =
+            PluginManager::create_plugin_instance("test_plugin", "test_math").unwrap();
+        let mut func = instance.get_func_mut("scale").unwrap();
+        let mut store = Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap();
+        let result = store.executor().call_func(&mut func, params!(7)).unwrap();
+        assert_eq!(result[0].to_i32(), 21);
+    }
+
+    #[cfg(feature = "wasi_nn")]
+    #[test]
+    fn test_nn_preload_set() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            GraphEncoding::from_str("whisper").unwrap(),
+            GraphEncoding::Whisper
+        );
+        assert_eq!(GraphEncoding::Burn.to_string(), "Burn");
+
+        // unique per process, so that concurrent test runs do not remove the file of each other
+        let model =
+            std::env::temp_dir().join(format!("test_nn_preload_set_{}.gguf", std::process::id()));
+        std::fs::write(&model, b"model").unwrap();
+
+        let mut preloads = NNPreloadSet::new();
+        preloads
+            .add(NNPreload::new(
+                "default",
+                GraphEncoding::GGML,
+                ExecutionTarget::AUTO,
+                &model,
+            ))
+            .unwrap();
+        preloads
+            .add(NNPreload::new(
+                "whisper",
+                GraphEncoding::Whisper,
+                ExecutionTarget::CPU,
+                "not-found.bin",
+            ))
+            .unwrap();
+        assert_eq!(preloads.len(), 2);
+        assert_eq!(preloads.aliases(), ["default", "whisper"]);
+
+        // the aliases must be valid and unique
+        assert_eq!(
+            preloads.add(NNPreload::new(
+                "default",
+                GraphEncoding::GGML,
+                ExecutionTarget::CPU,
+                &model
+            )),
+            Err(NNPreloadProblem::DuplicateAlias(String::from("default")))
+        );
+        assert_eq!(
+            preloads.add(NNPreload::new(
+                "a:b",
+                GraphEncoding::GGML,
+                ExecutionTarget::CPU,
+                &model
+            )),
+            Err(NNPreloadProblem::InvalidAlias(String::from("a:b")))
+        );
+        assert_eq!(preloads.len(), 2);
+
+        // the problems are reported per preload
+        let problems = preloads.validate();
+        assert!(problems.contains(&NNPreloadProblem::NotFoundFile {
+            alias: String::from("whisper"),
+            path: std::path::PathBuf::from("not-found.bin"),
+        }));
+        assert!(problems.iter().all(|x| x.alias() != Some("default")));
+        assert!(preloads.apply().is_err());
+
+        let removed = preloads.remove("whisper").unwrap();
+        assert_eq!(removed.backend(), &GraphEncoding::Whisper);
+        assert!(preloads.get("whisper").is_none());
+        assert!(preloads.validate().iter().all(|x| x.alias().is_none()));
+
+        std::fs::remove_file(&model).unwrap();
+    }
+
+    #[test]
+    fn test_plugin_option_before_load() {
+        // the values for a plugin which is not loaded are kept, the latest one for each option
+        PluginManager::set_option("not_loaded_plugin", "level", "1").unwrap();
+        PluginManager::set_option("not_loaded_plugin", "level", "2").unwrap();
+        {
+            let values = OPTION_VALUES.lock().unwrap();
+            let option = &values[&(String::from("not_loaded_plugin"), String::from("level"))];
+            assert_eq!(option.value, "2");
+            assert!(!option.written);
+        }
+
+        // and still kept after other plugins are loaded
+        write_pending_options().unwrap();
+        let values = OPTION_VALUES.lock().unwrap();
+        assert!(values.contains_key(&(String::from("not_loaded_plugin"), String::from("level"))));
+    }
+
+    #[test]
+    fn test_plugin_set() {
+        // the file names are matched against the allowed plugin names
+        let key = |path: &str| plugin_file_key(std::path::Path::new(path));
+        assert_eq!(
+            key("/usr/local/lib/wasmedge/libwasmedgePluginWasiNN.so").as_deref(),
+            Some("wasinn")
+        );
+        assert_eq!(
+            key("libwasmedge_process.so").as_deref(),
+            Some("wasmedgeprocess")
+        );
+        assert_eq!(plugin_name_key("wasi_nn"), "wasinn");
+        assert_eq!(plugin_name_key("wasmedge_process"), "wasmedgeprocess");
+
+        let plugins = PluginSet::new()
+            .allow("wasi_nn")
+            .allow_version("math_plugin", PluginVersion::create(0, 1, 0, 0))
+            .allow("wasi_nn");
+        assert_eq!(plugins.names(), ["wasi_nn", "math_plugin"]);
+
+        // an allowed plugin which is not loaded is an error
+        let result = PluginSet::new()
+            .with_file("/not/a/plugin.so")
+            .allow("not_a_plugin")
+            .load();
+        assert!(result.is_err());
+
+        // nothing is registered if no plugin is allowed
+        let mut store = Store::new(
+            None,
+            std::collections::HashMap::<String, &mut crate::ImportObject<()>>::new(),
+        )
+        .unwrap();
+        let registered = PluginSet::new().register(&mut store).unwrap();
+        assert!(registered.is_empty());
+        assert_eq!(store.named_instance_count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_plugin_set_verifies_files() {
+        let plugin_file = build_test_plugin();
+        let dir = tempfile::tempdir().unwrap();
+        let link = |name: &str| {
+            let path = dir.path().join(format!(
+                "{}{name}.{}",
+                std::env::consts::DLL_PREFIX,
+                std::env::consts::DLL_EXTENSION
+            ));
+            std::os::unix::fs::symlink(&plugin_file, &path).unwrap();
+            path
+        };
+
+        // a file whose name does not match an allowed plugin is rejected before loading
+        let result = PluginSet::new()
+            .with_file(&plugin_file)
+            .allow("test_plugin")
+            .load();
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::NotAllowed(plugin_file.display().to_string()))
+        );
+
+        // the plugin held by a loaded file must be allowed
+        let result = PluginSet::new()
+            .with_file(link("wasmedgePluginWasiNN"))
+            .allow("wasi_nn")
+            .load();
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::NotAllowed(String::from("test_plugin")))
+        );
+
+        // and have the expected version
+        let test_plugin = link("test_plugin");
+        let plugins = PluginSet::new()
+            .with_file(&test_plugin)
+            .allow_version("test_plugin", PluginVersion::create(1, 2, 3, 5));
+        let mismatch = WasmEdgeError::Plugin(PluginError::VersionMismatch {
+            name: String::from("test_plugin"),
+            expected: PluginVersion::create(1, 2, 3, 5).to_string(),
+            found: PluginVersion::create(1, 2, 3, 4).to_string(),
+        });
+        assert_eq!(*plugins.load().unwrap_err(), mismatch);
+
+        // the rejected plugin stays loaded, but is not registered
+        let mut store = Store::new(
+            None,
+            std::collections::HashMap::<String, &mut crate::ImportObject<()>>::new(),
+        )
+        .unwrap();
+        assert_eq!(*plugins.register(&mut store).unwrap_err(), mismatch);
+        assert_eq!(store.named_instance_count(), 0);
+
+        // an allowed plugin must be provided by the files of the set
+        let result = PluginSet::new()
+            .with_file(&test_plugin)
+            .allow("test_plugin")
+            .allow("wasi_nn")
+            .load();
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::NotFound(String::from("wasi_nn")))
+        );
+
+        let plugins = PluginSet::new()
+            .with_file(&test_plugin)
+            .allow_version("test_plugin", PluginVersion::create(1, 2, 3, 4));
+        plugins.load().unwrap();
+        assert!(PluginManager::names().contains(&String::from("test_plugin")));
+    }
+}
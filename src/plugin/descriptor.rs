@@ -0,0 +1,308 @@
+//! Defines the wasmedge_plugin macro and its support types.
+
+use crate::{ImportObject, WasmEdgeResult};
+use std::{panic::AssertUnwindSafe, sync::OnceLock};
+use wasmedge_sys::{
+    self as sys, ffi,
+    plugin::{ProgramOption, ProgramOptionValue},
+};
+
+pub use sys::plugin::{ModuleInstanceCreateFn, PluginDescriptor};
+
+/// Holds the descriptor of the plugin defined by [wasmedge_plugin](crate::wasmedge_plugin) for the lifetime of the process.
+#[derive(Debug)]
+struct DescriptorCell(OnceLock<Option<PluginDescriptor>>);
+// The descriptor is not modified by the plugin after it is initialized.
+unsafe impl Send for DescriptorCell {}
+unsafe impl Sync for DescriptorCell {}
+
+static DESCRIPTOR: DescriptorCell = DescriptorCell(OnceLock::new());
+
+/// Creates the descriptor on the first call, and returns the raw pointer to it, or a null pointer if fail to create the descriptor.
+pub fn get_or_init(
+    f: impl FnOnce() -> WasmEdgeResult<PluginDescriptor>,
+) -> *const ffi::WasmEdge_PluginDescriptor {
+    let descriptor = DESCRIPTOR.0.get_or_init(|| {
+        std::panic::catch_unwind(AssertUnwindSafe(f))
+            .ok()
+            .and_then(Result::ok)
+    });
+    match descriptor {
+        Some(descriptor) => descriptor.as_raw_ptr(),
+        None => std::ptr::null(),
+    }
+}
+
+/// Returns the descriptor of the plugin of the given name, if it is defined by [wasmedge_plugin](crate::wasmedge_plugin) in this binary and its descriptor is created.
+pub(crate) fn defined_descriptor(name: &str) -> Option<&'static ffi::WasmEdge_PluginDescriptor> {
+    let descriptor = unsafe { &*DESCRIPTOR.0.get()?.as_ref()?.as_raw_ptr() };
+    match unsafe { std::ffi::CStr::from_ptr(descriptor.Name) }.to_str() == Ok(name) {
+        true => Some(descriptor),
+        false => None,
+    }
+}
+
+/// Adds a program option declared in [wasmedge_plugin](crate::wasmedge_plugin) to the descriptor.
+pub fn add_program_option<T: ProgramOptionValue>(
+    descriptor: PluginDescriptor,
+    option: WasmEdgeResult<ProgramOption<T>>,
+) -> WasmEdgeResult<PluginDescriptor> {
+    Ok(descriptor.add_typed_program_option(option?))
+}
+
+/// Returns the current value of a program option of the plugin defined by [wasmedge_plugin](crate::wasmedge_plugin) in this crate.
+///
+/// The value is given by the WasmEdge runtime or [PluginManager::set_option](crate::plugin::PluginManager::set_option), or is the default value declared in [wasmedge_plugin](crate::wasmedge_plugin). This function is usually called by the functions creating the modules of the plugin.
+///
+/// `None` is returned if the plugin descriptor is not created yet, or no typed program option of the given name and type is declared.
+///
+/// # Argument
+///
+/// * `name` - The name of the program option.
+pub fn program_option<T: ProgramOptionValue>(name: impl AsRef<str>) -> Option<T> {
+    DESCRIPTOR.0.get()?.as_ref()?.program_option::<T>(name)
+}
+
+/// Creates a module instance by the given function, and hands over its ownership to the WasmEdge runtime. A null pointer is returned if the function fails or panics.
+pub fn create_module_instance<T>(
+    create: fn() -> WasmEdgeResult<ImportObject<T>>,
+) -> *mut ffi::WasmEdge_ModuleInstanceContext {
+    match std::panic::catch_unwind(create) {
+        Ok(Ok(import)) => {
+            let ctx = unsafe { import.as_raw() };
+            // the runtime deletes the module instance and its host data
+            std::mem::forget(import);
+            ctx
+        }
+        _ => std::ptr::null_mut(),
+    }
+}
+
+/// Defines a WasmEdge plugin and exports the `WasmEdge_Plugin_GetDescriptor` entry point of it.
+///
+/// Each module of the plugin is created by a function returning the [import object](crate::ImportObject) of the module, which is usually built by an [ImportObjectBuilder](crate::ImportObjectBuilder). The name of the import object should be the same as the name of the module. A program option is declared either with a default value, which makes a typed [program option](crate::plugin::ProgramOption) whose value can be read by [program_option](crate::plugin::program_option), or with a [type](crate::plugin::ProgramOptionType) only, which makes an option without storage.
+///
+/// The macro requires the `ffi` feature, and can be used only once in a crate, which is usually built as a `cdylib` and loaded by [PluginManager::load](crate::plugin::PluginManager::load). If the descriptor cannot be created, for example because a name contains a nul byte, or a module cannot be created, then the runtime receives a null pointer and reports the error.
+///
+/// # Example
+///
+/// ```ignore
+/// use wasmedge_sdk::{
+///     error::CoreError, wasmedge_plugin, CallingFrame, ImportObject, ImportObjectBuilder,
+///     Instance, WasmEdgeResult, WasmValue, plugin::program_option,
+/// };
+///
+/// fn add(
+///     scale: &mut i32,
+///     _inst: &mut Instance,
+///     _frame: &mut CallingFrame,
+///     inputs: Vec<WasmValue>,
+/// ) -> Result<Vec<WasmValue>, CoreError> {
+///     Ok(vec![WasmValue::from_i32((inputs[0].to_i32() + inputs[1].to_i32()) * *scale)])
+/// }
+///
+/// fn create_math() -> WasmEdgeResult<ImportObject<i32>> {
+///     let scale = program_option::<i32>("math-scale").unwrap_or(1);
+///     let mut builder = ImportObjectBuilder::new("math", scale)?;
+///     builder.with_func::<(i32, i32), i32>("add", add)?;
+///     Ok(builder.build())
+/// }
+///
+/// wasmedge_plugin! {
+///     name: "math_plugin",
+///     description: "A plugin providing math functions",
+///     version: (0, 1, 0, 0),
+///     modules: [
+///         { name: "math", description: "The math functions", create: create_math },
+///     ],
+///     options: [
+///         { name: "math-scale", description: "Scales the results", default: 1i32 },
+///         { name: "math-verbose", description: "Prints the calls", ty: Toggle },
+///     ],
+/// }
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+macro_rules! wasmedge_plugin {
+    (
+        name: $name:expr,
+        description: $desc:expr,
+        version: ($major:expr, $minor:expr, $patch:expr, $build:expr $(,)?),
+        modules: [
+            $( { name: $mod_name:expr, description: $mod_desc:expr, create: $create:expr $(,)? } ),* $(,)?
+        ]
+        $(, options: [
+            $( { $($option:tt)* } ),* $(,)?
+        ] )?
+        $(,)?
+    ) => {
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub extern "C" fn WasmEdge_Plugin_GetDescriptor(
+        ) -> *const $crate::plugin::ffi::WasmEdge_PluginDescriptor {
+            $crate::plugin::descriptor::get_or_init(|| {
+                let descriptor = $crate::plugin::descriptor::PluginDescriptor::create(
+                    $name,
+                    $desc,
+                    $crate::plugin::PluginVersion::create($major, $minor, $patch, $build),
+                )?;
+                $(
+                    let descriptor = descriptor.add_module_descriptor($mod_name, $mod_desc, Some({
+                        unsafe extern "C" fn create(
+                            _desc: *const $crate::plugin::ffi::WasmEdge_ModuleDescriptor,
+                        ) -> *mut $crate::plugin::ffi::WasmEdge_ModuleInstanceContext {
+                            $crate::plugin::descriptor::create_module_instance($create)
+                        }
+                        create as $crate::plugin::descriptor::ModuleInstanceCreateFn
+                    }))?;
+                )*
+                $($(
+                    let descriptor = $crate::__wasmedge_plugin_option!(descriptor, $($option)*)?;
+                )*)?
+                Ok(descriptor)
+            })
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __wasmedge_plugin_option {
+    ($descriptor:ident, name: $name:expr, description: $desc:expr, ty: $ty:ident $(,)?) => {
+        $descriptor.add_program_option($name, $desc, $crate::plugin::ProgramOptionType::$ty)
+    };
+    ($descriptor:ident, name: $name:expr, description: $desc:expr, default: $default:expr $(,)?) => {
+        $crate::plugin::descriptor::add_program_option(
+            $descriptor,
+            $crate::plugin::ProgramOption::new($name, $desc, $default),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{CoreError, PluginError, WasmEdgeError},
+        plugin::{program_option, PluginManager},
+        CallingFrame, ImportObject, ImportObjectBuilder, Instance, WasmEdgeResult, WasmValue,
+    };
+    use std::ffi::CStr;
+    use wasmedge_sys::{self as sys, AsInstance};
+
+    fn add(
+        _data: &mut i32,
+        _inst: &mut Instance,
+        _frame: &mut CallingFrame,
+        inputs: Vec<WasmValue>,
+    ) -> Result<Vec<WasmValue>, CoreError> {
+        Ok(vec![WasmValue::from_i32(
+            inputs[0].to_i32() + inputs[1].to_i32(),
+        )])
+    }
+
+    fn create_math() -> WasmEdgeResult<ImportObject<i32>> {
+        let scale = program_option::<i32>("math-scale").unwrap();
+        let mut builder = ImportObjectBuilder::new("math", scale)?;
+        builder.with_func::<(i32, i32), i32>("add", add)?;
+        Ok(builder.build())
+    }
+
+    wasmedge_plugin! {
+        name: "math_plugin",
+        description: "A plugin providing math functions",
+        version: (0, 1, 2, 3),
+        modules: [
+            { name: "math", description: "The math functions", create: create_math },
+        ],
+        options: [
+            { name: "math-verbose", description: "Prints the calls", ty: Toggle },
+            { name: "math-scale", description: "Scales the results", default: 1i32 },
+            { name: "math-prefix", description: "Prefixes the logs", default: String::from("math") },
+        ],
+    }
+
+    #[test]
+    fn test_plugin_descriptor() {
+        // the descriptor is not created before the runtime requests it
+        let result = PluginManager::set_option("math_plugin", "math-scale", "3");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::NotFound(String::from("math_plugin")))
+        );
+
+        let descriptor = WasmEdge_Plugin_GetDescriptor();
+        assert!(!descriptor.is_null());
+        // the descriptor is created only once
+        assert_eq!(descriptor, WasmEdge_Plugin_GetDescriptor());
+
+        let descriptor = unsafe { &*descriptor };
+        let name = unsafe { CStr::from_ptr(descriptor.Name) };
+        assert_eq!(name.to_str().unwrap(), "math_plugin");
+        assert_eq!(descriptor.Version.Major, 0);
+        assert_eq!(descriptor.Version.Minor, 1);
+        assert_eq!(descriptor.Version.Patch, 2);
+        assert_eq!(descriptor.Version.Build, 3);
+        assert_eq!(descriptor.ProgramOptionCount, 3);
+        let option = unsafe { &*descriptor.ProgramOptions.add(1) };
+        let name = unsafe { CStr::from_ptr(option.Name) };
+        assert_eq!(name.to_str().unwrap(), "math-scale");
+        assert!(!option.Storage.is_null());
+        assert_eq!(unsafe { *(option.DefaultValue as *const i32) }, 1);
+
+        // the typed options are readable by the plugin
+        assert_eq!(program_option::<i32>("math-scale"), Some(1));
+        PluginManager::set_option("math_plugin", "math-scale", "3").unwrap();
+        assert_eq!(program_option::<i32>("math-scale"), Some(3));
+        PluginManager::set_option("math_plugin", "math-prefix", "calc").unwrap();
+        assert_eq!(
+            program_option::<String>("math-prefix").as_deref(),
+            Some("calc")
+        );
+        PluginManager::set_option("math_plugin", "math-prefix", "math").unwrap();
+        assert_eq!(
+            program_option::<String>("math-prefix").as_deref(),
+            Some("math")
+        );
+        assert_eq!(program_option::<u32>("math-scale"), None);
+        assert_eq!(program_option::<bool>("math-verbose"), None);
+
+        // the invalid values and the unknown options are rejected
+        let result = PluginManager::set_option("math_plugin", "math-scale", "three");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::InvalidOptionValue {
+                name: String::from("math-scale"),
+                value: String::from("three"),
+            })
+        );
+        assert_eq!(program_option::<i32>("math-scale"), Some(3));
+        let result = PluginManager::set_option("math_plugin", "math-verbose", "on");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::NotFoundOption(String::from("math-verbose")))
+        );
+        let result = PluginManager::set_option("math_plugin", "math-unknown", "1");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Plugin(PluginError::NotFoundOption(String::from("math-unknown")))
+        );
+
+        // the runtime writes the given values into the storage
+        unsafe { *(option.Storage as *mut i32) = 5 };
+        assert_eq!(program_option::<i32>("math-scale"), Some(5));
+
+        // create the module instance as the runtime does
+        assert_eq!(descriptor.ModuleCount, 1);
+        let module = unsafe { &*descriptor.ModuleDescriptions };
+        let name = unsafe { CStr::from_ptr(module.Name) };
+        assert_eq!(name.to_str().unwrap(), "math");
+        let create = module.Create.unwrap();
+        let ctx = unsafe { create(module) };
+        assert!(!ctx.is_null());
+
+        let import = unsafe { sys::ImportModule::<i32>::from_raw(ctx) };
+        assert_eq!(import.name().unwrap(), "math");
+        assert_eq!(*import.get_host_data(), 5);
+        assert_eq!(import.func_names().unwrap(), ["add"]);
+    }
+}
@@ -0,0 +1,949 @@
+//! Defines the ProcessPolicy type and the `wasmedge_process` module created by it.
+
+use crate::{
+    error::{CoreError, CoreExecutionError},
+    CallingFrame, ImportObject, ImportObjectBuilder, Instance, WasmEdgeResult, WasmValue,
+};
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// The default timeout of a command, which is the same as the one of the wasmedge_process plug-in.
+const DEFAULT_TIMEOUT: Duration = Duration::from_millis(10_000);
+
+/// The default maximum size in bytes of the stdout or the stderr of a command.
+const DEFAULT_MAX_OUTPUT: usize = 16 * 1024 * 1024;
+
+/// Defines a command allowed by a [ProcessPolicy].
+#[derive(Debug, Clone)]
+struct AllowedCommand {
+    program: String,
+    arg_patterns: Option<Vec<String>>,
+}
+
+/// Defines which commands the wasm modules are allowed to run by the `wasmedge_process` module, and how they are run.
+///
+/// A [ProcessPolicy] creates a `wasmedge_process` module by [build](crate::plugin::ProcessPolicy::build), which provides the same host functions as the wasmedge_process plug-in, so the wasm modules written for the plug-in run without changes. Unlike the plug-in configured by [PluginManager::init_wasmedge_process](crate::plugin::PluginManager::init_wasmedge_process), which is shared by the whole process, each module created by a [ProcessPolicy] enforces its own policy, and the plug-in is not required to be installed or loaded. The module should be registered instead of, not together with, the module of the plug-in.
+///
+/// By default, no command is allowed. A command is run
+///
+/// * only if its program is allowed, and each of its arguments matches one of the patterns of the program, if any (see [allow_command_with_args](crate::plugin::ProcessPolicy::allow_command_with_args));
+///
+/// * with the environment variables inherited from the host by [inherit_env](crate::plugin::ProcessPolicy::inherit_env) and the ones set by the wasm module which are allowed by [allow_env](crate::plugin::ProcessPolicy::allow_env) only;
+///
+/// * in the current directory given by [with_current_dir](crate::plugin::ProcessPolicy::with_current_dir), if any;
+///
+/// * no longer than the timeout requested by the wasm module and the one given by [with_timeout](crate::plugin::ProcessPolicy::with_timeout), whichever is shorter. On Unix-like systems, the command runs in its own process group, and the processes it starts are killed together with it;
+///
+/// * with its stdout and stderr limited by [with_max_output](crate::plugin::ProcessPolicy::with_max_output).
+///
+/// Notice that a [ProcessPolicy] is not a sandbox. An allowed command can access anything the host process can, for example, files outside the current directory. Confine the commands by the operating system, for example, by a container, if needed.
+///
+/// If a command is denied, then `wasmedge_process_run` returns `-1`, the reason is written into the stderr of the command, and a [ProcessDenial] is recorded in the [ProcessDenialLog] returned by [denials](crate::plugin::ProcessPolicy::denials).
+///
+/// # Example
+///
+/// ```ignore
+/// let policy = ProcessPolicy::new()
+///     .allow_command_with_args("ffmpeg", ["-i", "*.mp4", "-y", "out/*"])
+///     .inherit_env("PATH")
+///     .with_current_dir("/srv/tenant-a")
+///     .with_timeout(Duration::from_secs(30));
+/// let denials = policy.denials();
+/// let mut process = policy.build()?;
+///
+/// let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+/// instances.insert(process.name().unwrap(), &mut process);
+/// let mut vm = Vm::new(Store::new(None, instances)?);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProcessPolicy {
+    commands: Vec<AllowedCommand>,
+    inherited_envs: Vec<String>,
+    allowed_envs: Vec<String>,
+    current_dir: Option<PathBuf>,
+    timeout: Duration,
+    max_output: usize,
+    denials: ProcessDenialLog,
+}
+impl Default for ProcessPolicy {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            inherited_envs: Vec::new(),
+            allowed_envs: Vec::new(),
+            current_dir: None,
+            timeout: DEFAULT_TIMEOUT,
+            max_output: DEFAULT_MAX_OUTPUT,
+            denials: ProcessDenialLog::default(),
+        }
+    }
+}
+impl ProcessPolicy {
+    /// Creates a new [ProcessPolicy] which denies all commands.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the given program to run with any arguments.
+    ///
+    /// # Argument
+    ///
+    /// * `program` - The program, which is compared with the program name given by the wasm module as is.
+    pub fn allow_command(mut self, program: impl AsRef<str>) -> Self {
+        self.insert(program.as_ref(), None);
+        self
+    }
+
+    /// Allows the given program to run with the arguments matching the given patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The program, which is compared with the program name given by the wasm module as is.
+    ///
+    /// * `arg_patterns` - The patterns of the allowed arguments. Each argument must match one of the patterns, in which `*` matches any sequence of characters except `/`. An argument containing `..`, for example `out/../secret`, `--output=..` or `file:..`, matches only the patterns containing `..` themselves, and so does an argument containing `\`, for example `out\..\secret`, with the patterns containing `\`.
+    pub fn allow_command_with_args(
+        mut self,
+        program: impl AsRef<str>,
+        arg_patterns: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        let patterns = arg_patterns
+            .into_iter()
+            .map(|x| x.as_ref().to_string())
+            .collect();
+        self.insert(program.as_ref(), Some(patterns));
+        self
+    }
+
+    fn insert(&mut self, program: &str, arg_patterns: Option<Vec<String>>) {
+        match self.commands.iter_mut().find(|x| x.program == program) {
+            Some(command) => command.arg_patterns = arg_patterns,
+            None => self.commands.push(AllowedCommand {
+                program: program.to_string(),
+                arg_patterns,
+            }),
+        }
+    }
+
+    /// Passes the environment variable of the host with the given name to the commands.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the environment variable.
+    pub fn inherit_env(mut self, name: impl AsRef<str>) -> Self {
+        self.inherited_envs.push(name.as_ref().to_string());
+        self
+    }
+
+    /// Allows the wasm modules to set the environment variable with the given name for the commands.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the environment variable.
+    pub fn allow_env(mut self, name: impl AsRef<str>) -> Self {
+        self.allowed_envs.push(name.as_ref().to_string());
+        self
+    }
+
+    /// Sets the current directory of the commands, in which the relative paths given to the commands are resolved. By default, the commands run in the current directory of the host process.
+    ///
+    /// The commands are not confined to the directory. They can still access the other paths, for example, by absolute paths, so restrict the arguments by [allow_command_with_args](crate::plugin::ProcessPolicy::allow_command_with_args) as well.
+    ///
+    /// # Argument
+    ///
+    /// * `dir` - The current directory.
+    pub fn with_current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets the maximum time a command can run, including the time its output is read. A command running longer is killed. The default is 10 seconds.
+    ///
+    /// # Argument
+    ///
+    /// * `timeout` - The maximum time.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum size in bytes of the stdout and the stderr of a command. A command producing more output is killed. The default is 16 MiB.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The maximum size in bytes.
+    pub fn with_max_output(mut self, bytes: usize) -> Self {
+        self.max_output = bytes;
+        self
+    }
+
+    /// Returns the [ProcessDenialLog] recording the commands denied by the modules created by this policy.
+    pub fn denials(&self) -> ProcessDenialLog {
+        self.denials.clone()
+    }
+
+    /// Creates a `wasmedge_process` module enforcing this policy.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the module, then an error is returned.
+    pub fn build(&self) -> WasmEdgeResult<ImportObject<ProcessHost>> {
+        let mut builder =
+            ImportObjectBuilder::new("wasmedge_process", ProcessHost::new(self.clone()))?;
+        builder
+            .with_func::<(i32, i32), ()>("wasmedge_process_set_prog_name", set_prog_name)?
+            .with_func::<(i32, i32), ()>("wasmedge_process_add_arg", add_arg)?
+            .with_func::<(i32, i32, i32, i32), ()>("wasmedge_process_add_env", add_env)?
+            .with_func::<(i32, i32), ()>("wasmedge_process_add_stdin", add_stdin)?
+            .with_func::<i32, ()>("wasmedge_process_set_timeout", set_timeout)?
+            .with_func::<(), i32>("wasmedge_process_run", run)?
+            .with_func::<(), i32>("wasmedge_process_get_exit_code", get_exit_code)?
+            .with_func::<(), i32>("wasmedge_process_get_stdout_len", get_stdout_len)?
+            .with_func::<i32, ()>("wasmedge_process_get_stdout", get_stdout)?
+            .with_func::<(), i32>("wasmedge_process_get_stderr_len", get_stderr_len)?
+            .with_func::<i32, ()>("wasmedge_process_get_stderr", get_stderr)?;
+        Ok(builder.build())
+    }
+
+    /// Checks the given command against this policy.
+    fn check(
+        &self,
+        program: &str,
+        args: &[String],
+        envs: &[(String, String)],
+    ) -> Option<ProcessDenial> {
+        let command = match self.commands.iter().find(|x| x.program == program) {
+            Some(command) => command,
+            None => {
+                return Some(ProcessDenial::CommandNotAllowed {
+                    program: program.to_string(),
+                })
+            }
+        };
+        if let Some(patterns) = &command.arg_patterns {
+            if let Some(arg) = args
+                .iter()
+                .find(|arg| !patterns.iter().any(|pattern| glob_match(pattern, arg)))
+            {
+                return Some(ProcessDenial::ArgumentNotAllowed {
+                    program: program.to_string(),
+                    arg: arg.clone(),
+                });
+            }
+        }
+        if let Some((name, _)) = envs
+            .iter()
+            .find(|(name, _)| !self.allowed_envs.contains(name))
+        {
+            return Some(ProcessDenial::EnvNotAllowed {
+                program: program.to_string(),
+                name: name.clone(),
+            });
+        }
+        None
+    }
+}
+
+/// Checks if the given argument matches the given pattern, in which `*` matches any sequence of characters except `/`.
+///
+/// An argument containing `..` or `\` matches only a pattern containing them as well, so that `*` cannot step out of a directory, whatever separates the path from the rest of the argument and whichever path separator the platform uses.
+fn glob_match(pattern: &str, arg: &str) -> bool {
+    if ["..", "\\"]
+        .iter()
+        .any(|needle| arg.contains(needle) && !pattern.contains(needle))
+    {
+        return false;
+    }
+    let patterns: Vec<&str> = pattern.split('/').collect();
+    let segments: Vec<&str> = arg.split('/').collect();
+    patterns.len() == segments.len()
+        && patterns
+            .iter()
+            .zip(segments)
+            .all(|(pattern, segment)| segment_match(pattern, segment))
+}
+
+/// Checks if the given text matches the given pattern, in which `*` matches any sequence of characters.
+fn segment_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match text.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let parts: Vec<&str> = parts.collect();
+    let (last, middle) = match parts.split_last() {
+        Some(x) => x,
+        // no `*` in the pattern
+        None => return rest.is_empty(),
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Describes a command denied by a [ProcessPolicy].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessDenial {
+    /// The program is not allowed.
+    CommandNotAllowed { program: String },
+    /// An argument matches none of the patterns of the program.
+    ArgumentNotAllowed { program: String, arg: String },
+    /// The wasm module sets an environment variable which is not allowed.
+    EnvNotAllowed { program: String, name: String },
+    /// The command runs longer than the timeout, and is killed.
+    Timeout { program: String, timeout: Duration },
+    /// The command produces more output than the limit, and is killed.
+    OutputLimitExceeded { program: String, limit: usize },
+}
+impl ProcessDenial {
+    /// Returns the program of the denied command.
+    pub fn program(&self) -> &str {
+        match self {
+            ProcessDenial::CommandNotAllowed { program }
+            | ProcessDenial::ArgumentNotAllowed { program, .. }
+            | ProcessDenial::EnvNotAllowed { program, .. }
+            | ProcessDenial::Timeout { program, .. }
+            | ProcessDenial::OutputLimitExceeded { program, .. } => program,
+        }
+    }
+}
+impl std::fmt::Display for ProcessDenial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessDenial::CommandNotAllowed { program } => {
+                write!(f, "Permission denied: the command \"{program}\" is not allowed")
+            }
+            ProcessDenial::ArgumentNotAllowed { program, arg } => write!(
+                f,
+                "Permission denied: the argument \"{arg}\" of the command \"{program}\" is not allowed"
+            ),
+            ProcessDenial::EnvNotAllowed { program, name } => write!(
+                f,
+                "Permission denied: the environment variable \"{name}\" of the command \"{program}\" is not allowed"
+            ),
+            ProcessDenial::Timeout { program, timeout } => write!(
+                f,
+                "The command \"{program}\" is killed after the timeout of {} ms",
+                timeout.as_millis()
+            ),
+            ProcessDenial::OutputLimitExceeded { program, limit } => write!(
+                f,
+                "The command \"{program}\" is killed for exceeding the output limit of {limit} bytes"
+            ),
+        }
+    }
+}
+
+/// Records the commands denied by a [ProcessPolicy]. The clones of a [ProcessDenialLog] share the same records.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessDenialLog {
+    denials: Arc<Mutex<Vec<ProcessDenial>>>,
+}
+impl ProcessDenialLog {
+    fn push(&self, denial: ProcessDenial) {
+        self.denials
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(denial);
+    }
+
+    /// Returns all recorded denials in the order they occur.
+    pub fn denials(&self) -> Vec<ProcessDenial> {
+        self.denials
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Removes all recorded denials.
+    pub fn clear(&self) {
+        self.denials
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+}
+
+/// Holds the state of a `wasmedge_process` module created by [ProcessPolicy::build](crate::plugin::ProcessPolicy::build).
+#[derive(Debug)]
+pub struct ProcessHost {
+    policy: ProcessPolicy,
+    program: String,
+    args: Vec<String>,
+    envs: Vec<(String, String)>,
+    stdin: Vec<u8>,
+    timeout: Option<Duration>,
+    exit_code: i32,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+impl ProcessHost {
+    fn new(policy: ProcessPolicy) -> Self {
+        Self {
+            policy,
+            program: String::new(),
+            args: Vec::new(),
+            envs: Vec::new(),
+            stdin: Vec::new(),
+            timeout: None,
+            exit_code: 0,
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        }
+    }
+
+    /// Runs the command set by the wasm module, and returns `0` if the command is run, or `-1` if not. The command settings are reset.
+    fn run(&mut self) -> i32 {
+        let program = std::mem::take(&mut self.program);
+        let args = std::mem::take(&mut self.args);
+        let envs = std::mem::take(&mut self.envs);
+        let stdin = std::mem::take(&mut self.stdin);
+        let timeout = match self.timeout.take() {
+            Some(timeout) => timeout.min(self.policy.timeout),
+            None => self.policy.timeout,
+        };
+        self.exit_code = -1;
+        self.stdout.clear();
+        self.stderr.clear();
+
+        if let Some(denial) = self.policy.check(&program, &args, &envs) {
+            return self.deny(denial);
+        }
+
+        let mut command = Command::new(&program);
+        command
+            .args(&args)
+            .env_clear()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        for name in self.policy.inherited_envs.iter() {
+            if let Some(value) = std::env::var_os(name) {
+                command.env(name, value);
+            }
+        }
+        command.envs(envs);
+        if let Some(dir) = &self.policy.current_dir {
+            command.current_dir(dir);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+
+            // the processes started by the command are killed together with it
+            command.process_group(0);
+        }
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(err) => {
+                self.stderr =
+                    format!("Failed to run the command \"{program}\": {err}").into_bytes();
+                return -1;
+            }
+        };
+
+        if let Some(mut pipe) = child.stdin.take() {
+            std::thread::spawn(move || {
+                let _ = pipe.write_all(&stdin);
+            });
+        }
+        let exceeded = Arc::new(AtomicBool::new(false));
+        let limit = self.policy.max_output;
+        let stdout = OutputReader::spawn(child.stdout.take(), limit, exceeded.clone());
+        let stderr = OutputReader::spawn(child.stderr.take(), limit, exceeded.clone());
+
+        let deadline = Instant::now() + timeout;
+        let mut denial = None;
+        let exited = loop {
+            match has_exited(&mut child) {
+                Ok(true) => break true,
+                Ok(false) => {}
+                Err(_) => break false,
+            }
+            if exceeded.load(Ordering::SeqCst) {
+                denial = Some(ProcessDenial::OutputLimitExceeded {
+                    program: program.clone(),
+                    limit,
+                });
+            } else if Instant::now() >= deadline {
+                denial = Some(ProcessDenial::Timeout {
+                    program: program.clone(),
+                    timeout,
+                });
+            }
+            if denial.is_some() {
+                break false;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        };
+
+        // the processes started by the command may keep the pipes open after it exits
+        let closed = denial.is_none() && stdout.wait(deadline) && stderr.wait(deadline);
+        if denial.is_none() && exceeded.load(Ordering::SeqCst) {
+            denial = Some(ProcessDenial::OutputLimitExceeded {
+                program: program.clone(),
+                limit,
+            });
+        } else if denial.is_none() && !closed {
+            denial = Some(ProcessDenial::Timeout {
+                program: program.clone(),
+                timeout,
+            });
+        }
+        if !closed || !exited {
+            kill(&mut child);
+        }
+        let status = child.wait().ok().filter(|_| exited);
+
+        // the output read before the command is killed is kept
+        self.stdout = stdout.take();
+        self.stderr = stderr.take();
+        if let Some(denial) = denial {
+            return self.deny(denial);
+        }
+        match status {
+            Some(status) => {
+                self.exit_code = status.code().unwrap_or(-1);
+                0
+            }
+            None => -1,
+        }
+    }
+
+    fn deny(&mut self, denial: ProcessDenial) -> i32 {
+        self.stderr = denial.to_string().into_bytes();
+        self.policy.denials.push(denial);
+        -1
+    }
+}
+
+/// Checks if the given command has exited.
+///
+/// On Unix-like systems the command is not reaped, so that its process group cannot be reused before the other processes in the group are killed.
+fn has_exited(child: &mut Child) -> std::io::Result<bool> {
+    #[cfg(unix)]
+    {
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        let res = unsafe {
+            libc::waitid(
+                libc::P_PID,
+                child.id() as libc::id_t,
+                &mut info,
+                libc::WEXITED | libc::WNOHANG | libc::WNOWAIT,
+            )
+        };
+        if res == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+        // `info` is left zeroed if the command is still running
+        Ok(info.si_signo == libc::SIGCHLD)
+    }
+    #[cfg(not(unix))]
+    {
+        child.try_wait().map(|status| status.is_some())
+    }
+}
+
+/// Kills the given command and, on Unix-like systems, the other processes in its process group.
+///
+/// The command must not have been reaped yet, see [has_exited], otherwise the process group may have been reused.
+fn kill(child: &mut Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Reads the stdout or the stderr of a command by a background thread.
+///
+/// The thread ends when the pipe is closed. If the pipe is kept open by a process which escapes from the process group of the command, then the thread keeps reading, but the output is no longer waited for.
+struct OutputReader {
+    output: Arc<Mutex<Vec<u8>>>,
+    done: mpsc::Receiver<()>,
+}
+impl OutputReader {
+    /// Starts reading the given pipe. At most `limit` bytes are kept, and `exceeded` is set if the pipe has more.
+    fn spawn<R: Read + Send + 'static>(
+        pipe: Option<R>,
+        limit: usize,
+        exceeded: Arc<AtomicBool>,
+    ) -> Self {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let (sender, done) = mpsc::channel();
+        let shared = output.clone();
+        std::thread::spawn(move || {
+            if let Some(mut pipe) = pipe {
+                let mut buf = [0u8; 8192];
+                loop {
+                    let len = match pipe.read(&mut buf) {
+                        Ok(0) => break,
+                        Ok(len) => len,
+                        Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                        Err(_) => break,
+                    };
+                    let mut output = shared.lock().unwrap_or_else(|err| err.into_inner());
+                    if output.len() + len > limit {
+                        let rest = limit - output.len();
+                        output.extend_from_slice(&buf[..rest]);
+                        exceeded.store(true, Ordering::SeqCst);
+                        break;
+                    }
+                    output.extend_from_slice(&buf[..len]);
+                }
+            }
+            let _ = sender.send(());
+        });
+        Self { output, done }
+    }
+
+    /// Waits until the pipe is closed or the deadline passes, and returns whether the pipe is closed.
+    fn wait(&self, deadline: Instant) -> bool {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        self.done.recv_timeout(timeout).is_ok()
+    }
+
+    /// Takes the output read so far.
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.output.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+}
+
+/// Reads a UTF-8 string from the memory of the caller.
+fn read_string(frame: &CallingFrame, offset: i32, len: i32) -> Result<String, CoreError> {
+    String::from_utf8(read_bytes(frame, offset, len)?)
+        .map_err(|_| CoreError::Execution(CoreExecutionError::HostFuncFailed))
+}
+
+/// Reads bytes from the memory of the caller.
+fn read_bytes(frame: &CallingFrame, offset: i32, len: i32) -> Result<Vec<u8>, CoreError> {
+    let memory = frame
+        .memory_ref(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::HostFuncFailed))?;
+    memory
+        .get_data(offset as u32, len as u32)
+        .map_err(|_| CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+}
+
+/// Writes bytes into the memory of the caller.
+fn write_bytes(frame: &mut CallingFrame, data: &[u8], offset: i32) -> Result<(), CoreError> {
+    let mut memory = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::HostFuncFailed))?;
+    memory
+        .set_data(data, offset as u32)
+        .map_err(|_| CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+}
+
+fn set_prog_name(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    host.program = read_string(frame, args[0].to_i32(), args[1].to_i32())?;
+    Ok(vec![])
+}
+
+fn add_arg(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let arg = read_string(frame, args[0].to_i32(), args[1].to_i32())?;
+    host.args.push(arg);
+    Ok(vec![])
+}
+
+fn add_env(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let name = read_string(frame, args[0].to_i32(), args[1].to_i32())?;
+    let value = read_string(frame, args[2].to_i32(), args[3].to_i32())?;
+    host.envs.push((name, value));
+    Ok(vec![])
+}
+
+fn add_stdin(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let data = read_bytes(frame, args[0].to_i32(), args[1].to_i32())?;
+    host.stdin.extend(data);
+    Ok(vec![])
+}
+
+fn set_timeout(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    host.timeout = Some(Duration::from_millis(args[0].to_i32() as u32 as u64));
+    Ok(vec![])
+}
+
+fn run(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    _args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Ok(vec![WasmValue::from_i32(host.run())])
+}
+
+fn get_exit_code(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    _args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Ok(vec![WasmValue::from_i32(host.exit_code)])
+}
+
+fn get_stdout_len(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    _args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Ok(vec![WasmValue::from_i32(host.stdout.len() as i32)])
+}
+
+fn get_stdout(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    write_bytes(frame, &host.stdout, args[0].to_i32())?;
+    Ok(vec![])
+}
+
+fn get_stderr_len(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    _args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Ok(vec![WasmValue::from_i32(host.stderr.len() as i32)])
+}
+
+fn get_stderr(
+    host: &mut ProcessHost,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    write_bytes(frame, &host.stderr, args[0].to_i32())?;
+    Ok(vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasmedge_sys::AsInstance;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("-y", "-y"));
+        assert!(!glob_match("-y", "-yy"));
+        assert!(glob_match("*.mp4", "in.mp4"));
+        assert!(!glob_match("*.mp4", "in.mp4.sh"));
+        assert!(glob_match("out/*", "out/a.mp4"));
+        assert!(glob_match("a*b*c", "aXXbYYc"));
+        assert!(!glob_match("a*b*c", "aXXcYYb"));
+        assert!(glob_match("*", ""));
+
+        // `*` does not step out of a directory
+        assert!(!glob_match("out/*", "out/a/b.mp4"));
+        assert!(!glob_match("out/*", "out/.."));
+        assert!(!glob_match("out/*", "out/../secret"));
+        assert!(!glob_match("*.mp4", "../in.mp4"));
+        assert!(!glob_match("*.mp4", "/etc/in.mp4"));
+        assert!(!glob_match("--output=*", "--output=.."));
+        assert!(glob_match("--output=*", "--output=a.mp4"));
+        assert!(glob_match("../shared/*", "../shared/a.mp4"));
+        assert!(!glob_match("*", "file:.."));
+        assert!(!glob_match("file:*", "file:../secret"));
+        assert!(!glob_match("out/*", "out/..\\secret"));
+        assert!(!glob_match("*.mp4", "..\\in.mp4"));
+        assert!(!glob_match("out/*", "out/a\\b.mp4"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_policy() {
+        let policy = ProcessPolicy::new()
+            .allow_command_with_args("echo", ["hello", "-n"])
+            .allow_command("sleep")
+            .allow_env("GREETING")
+            .with_timeout(Duration::from_millis(200));
+        let denials = policy.denials();
+
+        let process = policy.build().unwrap();
+        assert_eq!(process.name().unwrap(), "wasmedge_process");
+        assert_eq!(process.func_len(), 11);
+
+        let mut host = ProcessHost::new(policy);
+
+        // an allowed command
+        host.program = String::from("echo");
+        host.args = vec![String::from("-n"), String::from("hello")];
+        assert_eq!(host.run(), 0);
+        assert_eq!(host.exit_code, 0);
+        assert_eq!(host.stdout, b"hello");
+        assert!(denials.denials().is_empty());
+
+        // a command which is not allowed
+        host.program = String::from("rm");
+        assert_eq!(host.run(), -1);
+        assert_eq!(
+            denials.denials(),
+            [ProcessDenial::CommandNotAllowed {
+                program: String::from("rm")
+            }]
+        );
+        assert!(String::from_utf8_lossy(&host.stderr).starts_with("Permission denied"));
+
+        // an argument which is not allowed
+        host.program = String::from("echo");
+        host.args = vec![String::from("bye")];
+        assert_eq!(host.run(), -1);
+        // an environment variable which is not allowed
+        host.program = String::from("echo");
+        host.envs = vec![(String::from("HOME"), String::from("/"))];
+        assert_eq!(host.run(), -1);
+
+        // the timeout of the policy caps the one of the wasm module
+        host.program = String::from("sleep");
+        host.args = vec![String::from("5")];
+        host.timeout = Some(Duration::from_secs(10));
+        assert_eq!(host.run(), -1);
+
+        let denials = denials.denials();
+        assert_eq!(denials.len(), 4);
+        assert!(matches!(
+            denials[1],
+            ProcessDenial::ArgumentNotAllowed { .. }
+        ));
+        assert!(matches!(denials[2], ProcessDenial::EnvNotAllowed { .. }));
+        assert!(matches!(denials[3], ProcessDenial::Timeout { .. }));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_policy_kills_process_group() {
+        let policy = ProcessPolicy::new()
+            .allow_command("sh")
+            .with_timeout(Duration::from_millis(200));
+        let denials = policy.denials();
+        let mut host = ProcessHost::new(policy);
+
+        // a background process keeps the stdout open after the command exits
+        host.program = String::from("sh");
+        host.args = vec![
+            String::from("-c"),
+            String::from("(sleep 5; echo late) & echo started"),
+        ];
+        let start = Instant::now();
+        assert_eq!(host.run(), -1);
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(host.stdout, b"started\n");
+        assert!(matches!(
+            denials.denials()[..],
+            [ProcessDenial::Timeout { .. }]
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_policy_in_wasm() {
+        use crate::{params, vm::SyncInst, wat2wasm, Module, Store, Vm, WasmVal};
+        use std::collections::HashMap;
+
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (import "wasmedge_process" "wasmedge_process_set_prog_name" (func $set_prog_name (param i32 i32)))
+            (import "wasmedge_process" "wasmedge_process_add_arg" (func $add_arg (param i32 i32)))
+            (import "wasmedge_process" "wasmedge_process_run" (func $run (result i32)))
+            (import "wasmedge_process" "wasmedge_process_get_exit_code" (func $get_exit_code (result i32)))
+            (import "wasmedge_process" "wasmedge_process_get_stdout_len" (func $get_stdout_len (result i32)))
+            (import "wasmedge_process" "wasmedge_process_get_stdout" (func $get_stdout (param i32)))
+            (import "wasmedge_process" "wasmedge_process_get_stderr_len" (func $get_stderr_len (result i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "echo")
+            (data (i32.const 8) "-n")
+            (data (i32.const 16) "hello")
+            (data (i32.const 24) "rm")
+            (func (export "echo") (result i32)
+                (call $set_prog_name (i32.const 0) (i32.const 4))
+                (call $add_arg (i32.const 8) (i32.const 2))
+                (call $add_arg (i32.const 16) (i32.const 5))
+                (if (i32.ne (call $run) (i32.const 0))
+                    (then (return (i32.const -1))))
+                (if (i32.ne (call $get_exit_code) (i32.const 0))
+                    (then (return (i32.const -2))))
+                (call $get_stdout (i32.const 64))
+                (call $get_stdout_len))
+            (func (export "rm") (result i32)
+                (call $set_prog_name (i32.const 24) (i32.const 2))
+                (call $add_arg (i32.const 16) (i32.const 5))
+                (if (i32.ne (call $run) (i32.const -1))
+                    (then (return (i32.const 0))))
+                (call $get_stderr_len))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let policy = ProcessPolicy::new().allow_command_with_args("echo", ["-n", "hello"]);
+        let denials = policy.denials();
+        let mut process = policy.build().unwrap();
+        let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+        instances.insert(process.name().unwrap(), &mut process);
+        let mut vm = Vm::new(Store::new(None, instances).unwrap());
+        vm.register_module(None, module).unwrap();
+
+        // the allowed command runs, and its output is copied into the memory of the wasm module
+        let result = vm.run_func(None, "echo", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 5);
+        let memory = vm
+            .active_module()
+            .unwrap()
+            .get_memory_ref("memory")
+            .unwrap();
+        assert_eq!(memory.get_data(64, 5).unwrap(), b"hello");
+
+        // the denied command is not run, and the reason is given by the stderr
+        let result = vm.run_func(None, "rm", params!()).unwrap();
+        assert!(result[0].to_i32() > 0);
+        assert_eq!(
+            denials.denials(),
+            [ProcessDenial::CommandNotAllowed {
+                program: String::from("rm")
+            }]
+        );
+    }
+}
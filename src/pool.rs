@@ -0,0 +1,610 @@
+//! Defines InstancePre, InstancePool, PooledInstance, and PoolMetrics.
+
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    error::{LinkerError, WasmEdgeError},
+    limits::pages_to_bytes,
+    store::name_stub_trap,
+    ExternalInstanceType, Instance, Module, Mutability, SegmentKind, Store, WasmEdgeResult,
+    WasmValue,
+};
+use sys::AsInstance;
+use wasmedge_sys as sys;
+
+/// A [module](crate::Module) whose imports are resolved against a [store](crate::Store), ready to be instantiated repeatedly.
+///
+/// The imports are resolved and checked once when the [InstancePre] is created. Each call to [instantiate](crate::InstancePre::instantiate) then creates a fresh anonymous [module instance](crate::Instance) linked to the same import objects, without registering them again.
+#[derive(Debug)]
+pub struct InstancePre<'inst, T: ?Sized> {
+    store: Store<'inst, T>,
+    module: Module,
+    snapshot: Option<Option<Arc<Snapshot>>>,
+}
+impl<'inst, T: AsInstance + ?Sized> InstancePre<'inst, T> {
+    /// Creates a new [InstancePre].
+    ///
+    /// If [import stubs](crate::ImportStubs) are enabled in the given store, then the stubs for the missing imports are created here.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The [store](crate::Store) holding the import objects of the module.
+    ///
+    /// * `module` - The validated [module](crate::Module) to be instantiated.
+    ///
+    /// # Error
+    ///
+    /// If any import of the module cannot be resolved, then [LinkerError::UnresolvedImports](crate::error::LinkerError) is returned.
+    pub fn new(mut store: Store<'inst, T>, module: Module) -> WasmEdgeResult<Self> {
+        store.resolve_imports(&module, None)?;
+
+        let problems = store.check_imports(&module);
+        if !problems.is_empty() {
+            return Err(Box::new(WasmEdgeError::Linker(
+                LinkerError::UnresolvedImports(problems.iter().map(|x| x.to_string()).collect()),
+            )));
+        }
+
+        Ok(Self {
+            store,
+            module,
+            snapshot: None,
+        })
+    }
+
+    /// Creates a fresh anonymous [module instance](crate::Instance) of the module.
+    ///
+    /// The [resource limiter](crate::ResourceLimiter) of the store, if any, is consulted.
+    ///
+    /// # Error
+    ///
+    /// If fail to instantiate the module, then an error is returned.
+    pub fn instantiate(&mut self) -> WasmEdgeResult<Instance> {
+        self.store
+            .instantiate_limited(&self.module, None, 0)
+            .map(|(instance, _)| instance)
+    }
+
+    /// Runs an exported wasm function in the given [module instance](crate::Instance).
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The module instance created by this [InstancePre].
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the wasm function, then an error is returned.
+    pub fn run_func(
+        &mut self,
+        instance: &mut Instance,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let mut func = instance.get_func_mut(func_name.as_ref())?;
+        name_stub_trap(|| self.store.executor().call_func(&mut func, args))
+    }
+
+    /// Returns the [module](crate::Module) to be instantiated.
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Returns a reference to the [store](crate::Store) holding the import objects.
+    pub fn store(&self) -> &Store<'inst, T> {
+        &self.store
+    }
+
+    /// Returns a mutable reference to the [store](crate::Store) holding the import objects.
+    pub fn store_mut(&mut self) -> &mut Store<'inst, T> {
+        &mut self.store
+    }
+
+    /// Returns the snapshot shared by the instances of the module, which is taken from the given instance right after its instantiation if this is the first call, or `None` if the instances cannot be reset.
+    fn snapshot(&mut self, instance: &Instance) -> Option<Arc<Snapshot>> {
+        let module = &self.module;
+        self.snapshot
+            .get_or_insert_with(|| Snapshot::take(instance, module).map(Arc::new))
+            .clone()
+    }
+}
+
+/// A bounded pool of [module instances](crate::Instance) created by an [InstancePre].
+///
+/// An instance is taken from the pool by [acquire](crate::InstancePool::acquire), and given back by [release](crate::InstancePool::release). If recycling is enabled, then a released instance is reset to the state right after its instantiation and kept for the next [acquire](crate::InstancePool::acquire), otherwise it is dropped.
+///
+/// The state to reset to is taken once from the first instance created by the pool, and shared by all instances, so the instances must start from the same state. This holds unless the start function of the module depends on the results of the host functions it imports.
+///
+/// An instance can be reset only if all of its memories, tables, and mutable globals are defined by the module and exported, the module has no passive data or element segments, and its memories and tables have not grown since instantiation. The other instances are dropped on release, so a pool of them creates a new instance for almost every [acquire](crate::InstancePool::acquire). Use [PooledInstance::is_resettable](crate::PooledInstance::is_resettable) to check a module.
+///
+/// Note that most modules compiled from Rust or C keep their shadow stack pointer in an unexported mutable global (`__stack_pointer`), which cannot be restored, so their instances are never recycled. A passive segment cannot be restored either once the wasm code has dropped it by `data.drop` or `elem.drop`.
+#[derive(Debug)]
+pub struct InstancePool<'inst, T: ?Sized> {
+    pre: InstancePre<'inst, T>,
+    max_size: usize,
+    recycle: bool,
+    idle: Vec<PooledInstance>,
+    live: usize,
+    metrics: PoolMetrics,
+}
+impl<'inst, T: AsInstance + ?Sized> InstancePool<'inst, T> {
+    /// Creates a new empty [InstancePool] with recycling enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `pre` - The [InstancePre] creating the instances.
+    ///
+    /// * `max_size` - The maximum number of instances living at the same time, including the idle ones.
+    pub fn new(pre: InstancePre<'inst, T>, max_size: usize) -> Self {
+        Self {
+            pre,
+            max_size,
+            recycle: true,
+            idle: Vec::new(),
+            live: 0,
+            metrics: PoolMetrics::default(),
+        }
+    }
+
+    /// Enables or disables recycling of the released instances.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether to recycle the released instances.
+    pub fn with_recycling(self, enable: bool) -> Self {
+        Self {
+            recycle: enable,
+            ..self
+        }
+    }
+
+    /// Creates idle instances until the pool holds at least `count` idle instances or is full.
+    ///
+    /// # Argument
+    ///
+    /// * `count` - The number of idle instances.
+    ///
+    /// # Error
+    ///
+    /// If fail to create an instance, then an error is returned.
+    pub fn prefill(&mut self, count: usize) -> WasmEdgeResult<()> {
+        while self.idle.len() < count && self.live < self.max_size {
+            let instance = self.create()?;
+            self.idle.push(instance);
+        }
+        Ok(())
+    }
+
+    /// Takes an instance from the pool. An idle instance is reused if available, otherwise a new one is created.
+    ///
+    /// # Error
+    ///
+    /// If the pool is exhausted, or fail to create an instance, then an error is returned.
+    pub fn acquire(&mut self) -> WasmEdgeResult<PooledInstance> {
+        if let Some(instance) = self.idle.pop() {
+            self.metrics.reused += 1;
+            return Ok(instance);
+        }
+        if self.live >= self.max_size {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "The instance pool is exhausted ({} instances)",
+                self.max_size
+            ))));
+        }
+        self.create()
+    }
+
+    /// Gives back an instance taken from this pool by [acquire](crate::InstancePool::acquire).
+    ///
+    /// The instance is reset and kept as an idle instance if recycling is enabled and the instance can be reset, otherwise it is dropped.
+    ///
+    /// # Argument
+    ///
+    /// * `instance` - The instance to be released.
+    pub fn release(&mut self, mut instance: PooledInstance) {
+        if self.recycle && instance.reset() {
+            self.metrics.recycled += 1;
+            self.idle.push(instance);
+        } else {
+            self.metrics.discarded += 1;
+            self.live -= 1;
+        }
+    }
+
+    /// Runs an exported wasm function in the given instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The instance taken from this pool.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the wasm function, then an error is returned.
+    pub fn run_func(
+        &mut self,
+        instance: &mut PooledInstance,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.pre.run_func(&mut instance.inner, func_name, args)
+    }
+
+    /// Returns the metrics of this pool.
+    pub fn metrics(&self) -> PoolMetrics {
+        PoolMetrics {
+            in_use: self.live - self.idle.len(),
+            idle: self.idle.len(),
+            ..self.metrics
+        }
+    }
+
+    /// Returns the maximum number of instances living at the same time.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns a reference to the [InstancePre] of this pool.
+    pub fn pre(&self) -> &InstancePre<'inst, T> {
+        &self.pre
+    }
+
+    /// Returns a mutable reference to the [InstancePre] of this pool.
+    pub fn pre_mut(&mut self) -> &mut InstancePre<'inst, T> {
+        &mut self.pre
+    }
+
+    fn create(&mut self) -> WasmEdgeResult<PooledInstance> {
+        let inner = self.pre.instantiate()?;
+        let snapshot = match self.recycle {
+            true => self.pre.snapshot(&inner),
+            false => None,
+        };
+        self.live += 1;
+        self.metrics.created += 1;
+        Ok(PooledInstance { inner, snapshot })
+    }
+}
+
+/// A [module instance](crate::Instance) taken from an [InstancePool].
+#[derive(Debug)]
+pub struct PooledInstance {
+    inner: Instance,
+    snapshot: Option<Arc<Snapshot>>,
+}
+impl PooledInstance {
+    /// Returns whether this instance can be reset when it is released.
+    ///
+    /// An instance of a module with an unexported mutable global, such as `__stack_pointer`, or with a passive data or element segment cannot be reset.
+    pub fn is_resettable(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    fn reset(&mut self) -> bool {
+        match &self.snapshot {
+            Some(snapshot) => snapshot.restore(&mut self.inner).unwrap_or(false),
+            None => false,
+        }
+    }
+}
+impl AsRef<Instance> for PooledInstance {
+    fn as_ref(&self) -> &Instance {
+        &self.inner
+    }
+}
+impl AsMut<Instance> for PooledInstance {
+    fn as_mut(&mut self) -> &mut Instance {
+        &mut self.inner
+    }
+}
+
+/// The metrics of an [InstancePool].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolMetrics {
+    created: u64,
+    reused: u64,
+    recycled: u64,
+    discarded: u64,
+    in_use: usize,
+    idle: usize,
+}
+impl PoolMetrics {
+    /// Returns the number of instances created by the pool.
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /// Returns the number of acquisitions served by an idle instance.
+    pub fn reused(&self) -> u64 {
+        self.reused
+    }
+
+    /// Returns the number of released instances which are reset and kept.
+    pub fn recycled(&self) -> u64 {
+        self.recycled
+    }
+
+    /// Returns the number of released instances which are dropped.
+    pub fn discarded(&self) -> u64 {
+        self.discarded
+    }
+
+    /// Returns the number of instances currently taken from the pool.
+    pub fn in_use(&self) -> usize {
+        self.in_use
+    }
+
+    /// Returns the number of idle instances in the pool.
+    pub fn idle(&self) -> usize {
+        self.idle
+    }
+}
+
+/// The state of the exported memories, tables, and mutable globals of an instance.
+#[derive(Debug)]
+struct Snapshot {
+    memories: Vec<(String, u32, Vec<u8>)>,
+    tables: Vec<(String, Vec<WasmValue>)>,
+    globals: Vec<(String, WasmValue)>,
+}
+impl Snapshot {
+    /// Takes the snapshot of the given instance, or returns `None` if the instance cannot be reset.
+    fn take(instance: &Instance, module: &Module) -> Option<Self> {
+        let info = module.info()?;
+
+        // the imported state is shared with other instances
+        for import in module.imports() {
+            match import.ty().ok()? {
+                ExternalInstanceType::Func(_) => {}
+                ExternalInstanceType::Global(ty) if ty.mutability() == Mutability::Const => {}
+                _ => return None,
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut memories = Vec::new();
+        for name in instance.mem_names().unwrap_or_default() {
+            let memory = instance.get_memory_ref(&name).ok()?;
+            if seen.insert(unsafe { memory.as_ptr() } as usize) {
+                let pages = memory.size();
+                let len = u32::try_from(pages_to_bytes(pages)).ok()?;
+                memories.push((name, pages, memory.get_data(0, len).ok()?));
+            }
+        }
+
+        let mut tables = Vec::new();
+        for name in instance.table_names().unwrap_or_default() {
+            let table = instance.get_table(&name).ok()?;
+            if seen.insert(unsafe { table.as_ptr() } as usize) {
+                let elements = (0..table.capacity() as u32)
+                    .map(|idx| table.get_data(idx))
+                    .collect::<WasmEdgeResult<Vec<_>>>()
+                    .ok()?;
+                tables.push((name, elements));
+            }
+        }
+
+        let mut globals = Vec::new();
+        for name in instance.global_names().unwrap_or_default() {
+            let global = instance.get_global(&name).ok()?;
+            if global.ty().ok()?.mutability() == Mutability::Var
+                && seen.insert(unsafe { global.as_ptr() } as usize)
+            {
+                globals.push((name, global.get_value()));
+            }
+        }
+
+        // a dropped passive segment cannot be restored
+        if info
+            .data_segments()
+            .iter()
+            .any(|segment| segment.kind() == SegmentKind::Passive)
+            || info
+                .element_segments()
+                .iter()
+                .any(|segment| segment.kind() == SegmentKind::Passive)
+        {
+            return None;
+        }
+
+        // the state which is not exported cannot be restored
+        let mutable_globals = info
+            .globals()
+            .iter()
+            .filter(|ty| ty.mutability() == Mutability::Var)
+            .count();
+        if memories.len() != info.memories().len()
+            || tables.len() != info.tables().len()
+            || globals.len() != mutable_globals
+        {
+            return None;
+        }
+
+        Some(Self {
+            memories,
+            tables,
+            globals,
+        })
+    }
+
+    /// Restores the given instance, and returns `false` if its memories or tables have grown.
+    fn restore(&self, instance: &mut Instance) -> WasmEdgeResult<bool> {
+        for (name, pages, data) in &self.memories {
+            let mut memory = instance.get_memory_mut(name)?;
+            if memory.size() != *pages {
+                return Ok(false);
+            }
+            memory.set_data(data, 0)?;
+        }
+        for (name, elements) in &self.tables {
+            let mut table = instance.get_table_mut(name)?;
+            if table.capacity() != elements.len() {
+                return Ok(false);
+            }
+            for (idx, element) in elements.iter().enumerate() {
+                table.set_data(*element, idx as u32)?;
+            }
+        }
+        for (name, value) in &self.globals {
+            instance.get_global_mut(name)?.set_value(*value)?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{params, vm::SyncInst, wat2wasm, WasmVal};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_instance_pool() {
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (memory (export "memory") 1)
+            (global $counter (export "counter") (mut i32) (i32.const 0))
+            (data (i32.const 0) "\2a")
+            (func (export "bump") (result i32)
+                (i32.store8 (i32.const 0) (i32.add (i32.load8_u (i32.const 0)) (i32.const 1)))
+                (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+                (global.get $counter))
+            (func (export "load") (result i32)
+                (i32.load8_u (i32.const 0)))
+            (func (export "grow") (result i32)
+                (memory.grow (i32.const 1)))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let store = Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap();
+        let pre = InstancePre::new(store, module).unwrap();
+        let mut pool = InstancePool::new(pre, 2);
+        pool.prefill(1).unwrap();
+        assert_eq!(pool.metrics().created(), 1);
+        assert_eq!(pool.metrics().idle(), 1);
+
+        // the state is reset when the instance is recycled
+        let mut first = pool.acquire().unwrap();
+        assert!(first.is_resettable());
+        let result = pool.run_func(&mut first, "bump", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 1);
+        let result = pool.run_func(&mut first, "load", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 43);
+        pool.release(first);
+        assert_eq!(pool.metrics().recycled(), 1);
+
+        let mut first = pool.acquire().unwrap();
+        assert_eq!(pool.metrics().reused(), 2);
+        let snapshot = first.snapshot.clone().unwrap();
+        let result = pool.run_func(&mut first, "bump", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 1);
+        let result = pool.run_func(&mut first, "load", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 43);
+
+        // the pool is bounded
+        let mut second = pool.acquire().unwrap();
+
+        // the instances share one snapshot
+        assert!(Arc::ptr_eq(&snapshot, second.snapshot.as_ref().unwrap()));
+        let result = pool.run_func(&mut second, "load", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 42);
+        assert!(pool.acquire().is_err());
+        let metrics = pool.metrics();
+        assert_eq!(metrics.created(), 2);
+        assert_eq!(metrics.in_use(), 2);
+        assert_eq!(metrics.idle(), 0);
+
+        // a grown instance is dropped on release
+        let result = pool.run_func(&mut second, "grow", params!()).unwrap();
+        assert_eq!(result[0].to_i32(), 1);
+        pool.release(second);
+        pool.release(first);
+        let metrics = pool.metrics();
+        assert_eq!(metrics.discarded(), 1);
+        assert_eq!(metrics.recycled(), 2);
+        assert_eq!(metrics.in_use(), 0);
+        assert_eq!(metrics.idle(), 1);
+
+        // unresolved imports are reported when the InstancePre is created
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(br#"(module (import "env" "f" (func)))"#).unwrap(),
+        )
+        .unwrap();
+        let store = Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap();
+        let result = InstancePre::new(store, module);
+        assert!(matches!(
+            *result.unwrap_err(),
+            WasmEdgeError::Linker(LinkerError::UnresolvedImports(ref problems)) if problems.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_instance_pool_unresettable() {
+        let modules: [&[u8]; 3] = [
+            // the shadow stack pointer of a module compiled from Rust or C
+            br#"
+        (module
+            (memory (export "memory") 1)
+            (global $__stack_pointer (mut i32) (i32.const 1024))
+            (func (export "run") (result i32)
+                (global.set $__stack_pointer (i32.sub (global.get $__stack_pointer) (i32.const 16)))
+                (global.get $__stack_pointer))
+        )
+"#,
+            // a passive data segment dropped by the wasm code
+            br#"
+        (module
+            (memory (export "memory") 1)
+            (data $init "\2a")
+            (func (export "run") (result i32)
+                (memory.init $init (i32.const 0) (i32.const 0) (i32.const 1))
+                (data.drop $init)
+                (i32.load8_u (i32.const 0)))
+        )
+"#,
+            // a passive element segment dropped by the wasm code
+            br#"
+        (module
+            (table (export "table") 1 funcref)
+            (elem $init func $f)
+            (func $f (result i32) (i32.const 42))
+            (func (export "run") (result i32)
+                (table.init $init (i32.const 0) (i32.const 0) (i32.const 1))
+                (elem.drop $init)
+                (call_indirect (result i32) (i32.const 0)))
+        )
+"#,
+        ];
+
+        for wat in modules {
+            let module = Module::from_bytes(None, wat2wasm(wat).unwrap()).unwrap();
+            let store = Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap();
+            let pre = InstancePre::new(store, module).unwrap();
+            let mut pool = InstancePool::new(pre, 1);
+
+            // every acquired instance starts from the state right after its instantiation
+            for _ in 0..2 {
+                let mut instance = pool.acquire().unwrap();
+                assert!(!instance.is_resettable());
+                let result = pool.run_func(&mut instance, "run", params!()).unwrap();
+                assert!(matches!(result[0].to_i32(), 1008 | 42));
+                pool.release(instance);
+            }
+            let metrics = pool.metrics();
+            assert_eq!(metrics.created(), 2);
+            assert_eq!(metrics.recycled(), 0);
+            assert_eq!(metrics.discarded(), 2);
+        }
+    }
+}
@@ -1,9 +1,11 @@
 //! Defines WasmEdge Statistics struct.
 
-use crate::WasmEdgeResult;
+use crate::{error::CoreError, CallingFrame, WasmEdgeResult};
 use wasmedge_sys as sys;
 
 /// Used to collect statistics of the WasmEdge runtime, such as the count of instructions in execution.
+///
+/// The clones of a [Statistics] share the same data. A host function reaches the statistics of the running executor with [Statistics::from_calling_frame] to [charge](crate::Statistics::charge) the cost of the work it does.
 #[derive(Debug, Clone)]
 pub struct Statistics {
     pub(crate) inner: sys::Statistics,
@@ -19,6 +21,15 @@ impl Statistics {
         Ok(Self { inner })
     }
 
+    /// Returns the [statistics](crate::Statistics) of the executor running the given calling frame, or `None` if the executor collects no statistics, for example, if the [store](crate::Store) is not created by [Store::with_statistics](crate::Store::with_statistics).
+    ///
+    /// # Argument
+    ///
+    /// * `frame` - The calling frame of a host function.
+    pub fn from_calling_frame(frame: &CallingFrame) -> Option<Self> {
+        frame.statistics().map(|inner| Self { inner })
+    }
+
     /// Returns the instruction count in execution.
     pub fn count(&self) -> u64 {
         self.inner.instr_count()
@@ -49,9 +60,31 @@ impl Statistics {
         self.inner.instr_per_sec()
     }
 
-    /// Returns the total cost in execution.
+    /// Returns the total cost in execution, including the cost charged by host functions.
     pub fn cost(&self) -> u64 {
-        self.inner.cost_in_total()
+        self.inner
+            .cost_in_total()
+            .saturating_add(self.inner.charged_cost())
+    }
+
+    /// Returns the cost charged by host functions through [Statistics::charge](crate::Statistics::charge).
+    pub fn charged_cost(&self) -> u64 {
+        self.inner.charged_cost()
+    }
+
+    /// Charges the given cost from inside a host function, which is counted against the [cost limit](crate::Statistics::set_cost_limit) like the cost of the wasm instructions.
+    ///
+    /// The host function gets the statistics with [Statistics::from_calling_frame]. The cost is only charged if the limit is not exceeded.
+    ///
+    /// # Argument
+    ///
+    /// * `cost` - The cost to charge.
+    ///
+    /// # Error
+    ///
+    /// If the total cost exceeds the cost limit, then a [CostLimitExceeded](crate::error::CoreCommonError::CostLimitExceeded) error is returned, which can be returned from the host function directly to abort the execution.
+    pub fn charge(&self, cost: u64) -> Result<(), CoreError> {
+        self.inner.charge(cost)
     }
 
     /// Sets the cost of instructions.
@@ -71,4 +104,71 @@ impl Statistics {
     pub fn set_cost_limit(&mut self, limit: u64) {
         self.inner.set_cost_limit(limit)
     }
+
+    /// Clears the data collected in execution, including the cost charged by host functions. The cost limit is kept.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Returns a snapshot of the data collected in execution so far.
+    pub fn snapshot(&self) -> StatisticsSnapshot {
+        StatisticsSnapshot {
+            count: self.count(),
+            cost: self.cost(),
+            count_per_second: self.count_per_second(),
+        }
+    }
+}
+
+/// Defines a snapshot of the data in a [Statistics].
+///
+/// The difference between two snapshots describes the execution in between, for example, a single function call. See [StatisticsSnapshot::since].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatisticsSnapshot {
+    count: u64,
+    cost: u64,
+    count_per_second: f64,
+}
+impl StatisticsSnapshot {
+    /// Returns the instruction count.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the total cost.
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    /// Returns the instruction count per second, which could be `NaN` if the execution time is 0.
+    pub fn count_per_second(&self) -> f64 {
+        self.count_per_second
+    }
+
+    /// Returns the data collected between the given earlier snapshot and this snapshot.
+    ///
+    /// # Argument
+    ///
+    /// * `earlier` - The snapshot taken before this snapshot from the same [Statistics].
+    pub fn since(&self, earlier: &StatisticsSnapshot) -> StatisticsSnapshot {
+        let count = self.count.saturating_sub(earlier.count);
+        let time = self.seconds() - earlier.seconds();
+
+        StatisticsSnapshot {
+            count,
+            cost: self.cost.saturating_sub(earlier.cost),
+            count_per_second: match time > 0.0 {
+                true => count as f64 / time,
+                false => f64::NAN,
+            },
+        }
+    }
+
+    /// Returns the execution time in seconds derived from the instruction count and the count per second.
+    fn seconds(&self) -> f64 {
+        match self.count_per_second.is_normal() && self.count_per_second > 0.0 {
+            true => self.count as f64 / self.count_per_second,
+            false => 0.0,
+        }
+    }
 }
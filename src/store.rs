@@ -1,9 +1,21 @@
 //! Defines WasmEdge Store struct.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::{Arc, Weak},
+};
 
-use crate::{config::Config, Module, WasmEdgeResult};
-use sys::{AsInstance, Instance};
+use crate::{
+    config::{Config, RuntimeConfigOptions},
+    error::{MemError, StoreError, TableError, WasmEdgeError},
+    limits::pages_to_bytes,
+    linker::{forwarding_namespace, ForwardTarget, HostFunc, HostFuncData, NamespaceData},
+    Extern, ExternalInstanceType, ImportType, Module, ResourceLimiter, Statistics, ValType,
+    WasmEdgeResult, WasmValue,
+};
+use sys::{instance::function::AsFunc, AsInstance, Instance};
 use wasmedge_sys as sys;
 
 /// The [Store] is a collection of registered modules and assists wasm modules in finding the import modules they need.
@@ -12,7 +24,22 @@ pub struct Store<'inst, T: ?Sized> {
     pub(crate) inner: sys::Store,
     pub(crate) instances: HashMap<String, &'inst mut T>,
     pub(crate) wasm_instance_map: HashMap<String, Instance>,
+    pub(crate) defined_instance_map: HashMap<String, sys::ImportModule<NamespaceData>>,
     pub(crate) executor: sys::Executor,
+    pub(crate) config: Option<Config>,
+    pub(crate) statistics: Option<Statistics>,
+    pub(crate) limiter: Option<Box<dyn ResourceLimiter>>,
+    // the executor last created to cap the memories at the given number of pages, reused while the cap does not change
+    pub(crate) capped_executor: Option<(u32, sys::Executor)>,
+    pub(crate) unexported_memory: HashMap<String, usize>,
+    pub(crate) import_stubs: ImportStubs,
+    pub(crate) dependencies: HashMap<String, Vec<String>>,
+    // the anonymous module instances created by this store, each observed by a token which expires
+    // when the instance is dropped, with the names of the modules it imports from
+    pub(crate) anonymous_instances: Vec<(Weak<()>, Vec<String>)>,
+    // the namespaces forwarding to the reloadable wasm module instances, which are kept in
+    // wasm_instance_map but not registered in the underlying store
+    pub(crate) reloadable: HashMap<String, (sys::ImportModule<NamespaceData>, ForwardTarget)>,
 }
 
 impl<T: ?Sized> Debug for Store<'_, T> {
@@ -21,6 +48,11 @@ impl<T: ?Sized> Debug for Store<'_, T> {
             .field("inner", &self.inner)
             .field("instance_map", &self.instances.keys())
             .field("wasm_instance_map", &self.wasm_instance_map.keys())
+            .field("reloadable", &self.reloadable.keys())
+            .field("defined_instance_map", &self.defined_instance_map.keys())
+            .field("import_stubs", &self.import_stubs)
+            .field("statistics", &self.statistics)
+            .field("limiter", &self.limiter.is_some())
             .field("wasm_instance_map", &self.executor)
             .finish()
     }
@@ -36,8 +68,55 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         config: Option<&Config>,
         instances: HashMap<String, &'inst mut T>,
     ) -> WasmEdgeResult<Self> {
+        Self::create(config, instances, None)
+    }
+
+    /// Creates a new [Store] whose executor collects the runtime data into the given [statistics](crate::Statistics).
+    ///
+    /// The instruction counting, cost measuring and time measuring options of the given [config](crate::config::Config) determine which data are collected, see [StatisticsConfigOptions](crate::config::StatisticsConfigOptions). At least one of them must be enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration of the executor.
+    ///
+    /// * `instances` - The import module instances to register.
+    ///
+    /// * `statistics` - The [statistics](crate::Statistics) shared with the executor.
+    ///
+    /// # Error
+    ///
+    /// If none of the statistics options of the given config is enabled, or fail to create a new [Store], then an error is returned.
+    pub fn with_statistics(
+        config: Option<&Config>,
+        instances: HashMap<String, &'inst mut T>,
+        statistics: Statistics,
+    ) -> WasmEdgeResult<Self> {
+        // without any of the options the executor silently collects nothing
+        let collecting = config.is_some_and(|cfg| {
+            cfg.instruction_counting_enabled()
+                || cfg.cost_measuring_enabled()
+                || cfg.time_measuring_enabled()
+        });
+        if !collecting {
+            return Err(Box::new(WasmEdgeError::Operation(String::from(
+                "Collecting statistics requires a config enabling instruction counting, cost measuring or time measuring",
+            ))));
+        }
+
+        Self::create(config, instances, Some(statistics))
+    }
+
+    fn create(
+        config: Option<&Config>,
+        instances: HashMap<String, &'inst mut T>,
+        statistics: Option<Statistics>,
+    ) -> WasmEdgeResult<Self> {
+        let mut statistics = statistics;
         let mut store = sys::Store::create()?;
-        let mut executor = sys::Executor::create(config.map(|cfg| cfg.inner.as_ref()), None)?;
+        let mut executor = sys::Executor::create(
+            config.map(|cfg| cfg.inner.as_ref()),
+            statistics.as_mut().map(|stat| &mut stat.inner),
+        )?;
 
         for v in instances.values() {
             executor.register_import_module(&mut store, *v)?;
@@ -47,7 +126,17 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
             inner: store,
             instances,
             wasm_instance_map: Default::default(),
+            defined_instance_map: Default::default(),
             executor,
+            config: config.cloned(),
+            statistics,
+            limiter: None,
+            capped_executor: None,
+            unexported_memory: HashMap::new(),
+            import_stubs: Default::default(),
+            dependencies: HashMap::new(),
+            anonymous_instances: Vec::new(),
+            reloadable: HashMap::new(),
         })
     }
 
@@ -63,11 +152,22 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
     ///
     /// If fail to register the given [module](crate::Module), then an error is returned.
     pub fn register_active_module(&mut self, module: &Module) -> WasmEdgeResult<Instance> {
-        let Store {
-            inner, executor, ..
-        } = self;
-        let inner = executor.register_active_module(inner, &module.inner)?;
-        Ok(inner)
+        self.instantiate_active(module, None, 0)
+            .map(|(instance, _)| instance)
+    }
+
+    /// Instantiates the given module as an anonymous module instance, and returns the instance and the size in bytes counted for its unexported memories.
+    ///
+    /// `others` is the total size in bytes of the memories of the module instances which are created by this store but not kept in it, such as the ones kept by a [Vm](crate::Vm).
+    pub(crate) fn instantiate_active(
+        &mut self,
+        module: &Module,
+        resolver: Option<&Resolver>,
+        others: usize,
+    ) -> WasmEdgeResult<(Instance, usize)> {
+        let created = self.resolve_imports(module, resolver)?;
+        let result = self.instantiate_limited(module, None, others);
+        self.rollback_on_error(created, result)
     }
 
     pub fn register_named_module(
@@ -75,21 +175,700 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         name: impl AsRef<str>,
         module: &Module,
     ) -> WasmEdgeResult<()> {
+        self.instantiate_named(name.as_ref(), module, None, 0)
+    }
+
+    /// Instantiates the given module as a named module instance. See [Store::instantiate_active] for `others`.
+    pub(crate) fn instantiate_named(
+        &mut self,
+        name: &str,
+        module: &Module,
+        resolver: Option<&Resolver>,
+        others: usize,
+    ) -> WasmEdgeResult<()> {
+        let created = self.resolve_imports(module, resolver)?;
+        let result =
+            self.instantiate_limited(module, Some(name), others)
+                .map(|(instance, unexported)| {
+                    self.wasm_instance_map.insert(name.to_string(), instance);
+                    self.unexported_memory.insert(name.to_string(), unexported);
+                    self.dependencies
+                        .insert(name.to_string(), import_module_names(module));
+                });
+        self.rollback_on_error(created, result)
+    }
+
+    /// Registers and instantiates a WasmEdge [compiled module](crate::Module) into this [store](crate::Store) as a reloadable named [module instance](crate::Instance), which can be replaced by [replace_named_module](crate::Store::replace_named_module) while other module instances import from it.
+    ///
+    /// The name is registered for a namespace of forwarding functions, one for each function exported by the module instance, which call the function of the same name in the current instance. So the module instances importing from the name call the new instance after a replacement. The memories, tables and globals of a reloadable module instance are not exported under the name, so a module importing them from it fails to be instantiated. A call through a forwarding function runs on a new stack of the executor.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the module instance.
+    ///
+    /// * `module` - The validated [module](crate::Module) to be registered.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the given [module](crate::Module), then an error is returned.
+    pub fn register_reloadable_module(
+        &mut self,
+        name: impl AsRef<str>,
+        module: &Module,
+    ) -> WasmEdgeResult<()> {
+        self.instantiate_reloadable(name.as_ref(), module, 0)
+    }
+
+    /// Instantiates the given module as a reloadable named module instance. See [Store::instantiate_active] for `others`.
+    pub(crate) fn instantiate_reloadable(
+        &mut self,
+        name: &str,
+        module: &Module,
+        others: usize,
+    ) -> WasmEdgeResult<()> {
+        let created = self.resolve_imports(module, None)?;
+        let result =
+            self.instantiate_staged(name, module, others)
+                .and_then(|(instance, unexported)| {
+                    let (namespace, target) = forwarding_namespace(name, &instance, false)?;
+                    self.executor
+                        .register_import_module(&mut self.inner, &namespace)?;
+                    self.wasm_instance_map.insert(name.to_string(), instance);
+                    self.unexported_memory.insert(name.to_string(), unexported);
+                    self.dependencies
+                        .insert(name.to_string(), import_module_names(module));
+                    self.reloadable
+                        .insert(name.to_string(), (namespace, target));
+                    Ok(())
+                });
+        self.rollback_on_error(created, result)
+    }
+
+    /// Checks if the named module instance is registered by [register_reloadable_module](crate::Store::register_reloadable_module).
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the module instance.
+    pub fn is_reloadable(&self, name: impl AsRef<str>) -> bool {
+        self.reloadable.contains_key(name.as_ref())
+    }
+
+    /// Consults the resource limiter and instantiates the given module, as a named module instance if a name is given. Returns the new instance and the size in bytes counted for its unexported memories.
+    pub(crate) fn instantiate_limited(
+        &mut self,
+        module: &Module,
+        name: Option<&str>,
+        others: usize,
+    ) -> WasmEdgeResult<(Instance, usize)> {
+        self.instantiate_limited_into(None, module, name, others)
+    }
+
+    /// Like [Store::instantiate_limited], but instantiates the module into the given underlying store instead of the one of this store, if any.
+    fn instantiate_limited_into(
+        &mut self,
+        target: Option<&mut sys::Store>,
+        module: &Module,
+        name: Option<&str>,
+        others: usize,
+    ) -> WasmEdgeResult<(Instance, usize)> {
+        let (cap, unexported) = self.check_instantiation(module, others)?;
+        // the runtime fixes the page limit of a memory at its instantiation, so the memories are
+        // capped by instantiating the module with an executor configured for the cap
+        if let Some(cap) = cap {
+            if !matches!(self.capped_executor, Some((pages, _)) if pages == cap) {
+                self.capped_executor = Some((cap, self.create_capped_executor(cap)?));
+            }
+        }
         let Store {
             inner,
             executor,
-            wasm_instance_map,
+            capped_executor,
             ..
         } = self;
-        let name = name.as_ref().to_string();
-        let inst = executor.register_named_module(inner, &module.inner, &name)?;
-        wasm_instance_map.insert(name, inst);
+        let executor = match (cap, capped_executor) {
+            (Some(_), Some((_, capped))) => capped,
+            _ => executor,
+        };
+        let store = target.unwrap_or(inner);
+        let instance = match name {
+            Some(name) => executor.register_named_module(store, &module.inner, name)?,
+            None => executor.register_active_module(store, &module.inner)?,
+        };
+        if name.is_none() {
+            self.track_anonymous(&instance, import_module_names(module));
+        }
+        Ok((instance, unexported))
+    }
+
+    /// Records the modules an anonymous module instance created by this store imports from, so that they are not unregistered or replaced while the instance is alive.
+    fn track_anonymous(&mut self, instance: &Instance, imports: Vec<String>) {
+        self.anonymous_instances
+            .retain(|(token, _)| token.strong_count() > 0);
+        if !imports.is_empty() {
+            self.anonymous_instances
+                .push((instance.liveness(), imports));
+        }
+    }
+
+    /// Creates an executor like the one of this store, which caps the memories at the given number of pages.
+    fn create_capped_executor(&self, pages: u32) -> WasmEdgeResult<sys::Executor> {
+        let options = match &self.config {
+            Some(config) => config.to_options(),
+            None => Config {
+                inner: Arc::new(sys::Config::create()?),
+            }
+            .to_options(),
+        };
+        let config = options
+            .with_runtime_config(RuntimeConfigOptions::default().max_memory_pages(pages))
+            .build()?;
+        let mut statistics = self.statistics.clone();
+        sys::Executor::create(
+            Some(config.inner.as_ref()),
+            statistics.as_mut().map(|stat| &mut stat.inner),
+        )
+    }
+
+    /// Returns the number of pages the runtime caps the memories at without a resource limiter, which is 65536 pages by default.
+    fn max_memory_pages(&self) -> u32 {
+        self.config
+            .as_ref()
+            .map_or(65536, |config| config.max_memory_pages())
+    }
+
+    /// Unregisters the namespaces created for the imports of a module if the module fails to be instantiated, so that the registration can be retried.
+    fn rollback_on_error<R>(
+        &mut self,
+        created: Vec<String>,
+        result: WasmEdgeResult<R>,
+    ) -> WasmEdgeResult<R> {
+        if result.is_err() {
+            // dropping a module instance unregisters it from the underlying store
+            for name in created {
+                self.defined_instance_map.remove(&name);
+                self.dependencies.remove(&name);
+            }
+        }
+        result
+    }
+
+    /// Registers an owned named [module instance](crate::Instance), such as a plugin module instance, into this [store](crate::Store) by its name.
+    pub(crate) fn register_owned_instance(&mut self, instance: Instance) -> WasmEdgeResult<String> {
+        let name = instance.name().ok_or_else(|| {
+            Box::new(WasmEdgeError::Operation(String::from(
+                "Fail to register an anonymous module instance",
+            )))
+        })?;
+        self.executor
+            .register_import_module(&mut self.inner, &instance)?;
+        self.wasm_instance_map.insert(name.clone(), instance);
+        Ok(name)
+    }
+
+    /// Replaces a named wasm [module instance](crate::Instance) with a new instance of the given [module](crate::Module).
+    ///
+    /// The new module is instantiated first, and then the migration closure is called with the old and the new instances, for example, to copy the exported globals and memory regions from the old instance to the new one. If both succeed, then the name is switched to the new instance, so that [Vm::run_func](crate::Vm::run_func), the other lookups by name and the modules registered later resolve the name to it, and the old instance is dropped. Otherwise, nothing is changed.
+    ///
+    /// The module instances which import from a module instance registered by [register_reloadable_module](crate::Store::register_reloadable_module) call the new instance after the replacement, so the new module must export every function of the old one with the same type. The other module instances are linked to the module instances they import from directly, so a module instance registered otherwise cannot be replaced while it has [dependents](crate::Store::dependents) or [anonymous dependents](crate::Store::anonymous_dependents).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the module instance to replace.
+    ///
+    /// * `module` - The validated [module](crate::Module) to instantiate.
+    ///
+    /// * `migrate` - The closure called with the old and the new instances.
+    ///
+    /// # Error
+    ///
+    /// * If no named wasm module instance of the given name is found, then [StoreError::NotFoundModule](crate::error::StoreError::NotFoundModule) is returned.
+    ///
+    /// * If the module instance is not reloadable, and any other module instance created by this [store](crate::Store) imports from it, then [StoreError::InUse](crate::error::StoreError::InUse) is returned.
+    ///
+    /// * If the module instance is reloadable, and the new module does not export a function of the old one with the same type, then an error is returned.
+    ///
+    /// * If fail to instantiate the module, or the migration closure returns an error, then the error is returned.
+    pub fn replace_named_module<F>(
+        &mut self,
+        name: impl AsRef<str>,
+        module: &Module,
+        migrate: F,
+    ) -> WasmEdgeResult<()>
+    where
+        F: FnOnce(&mut Instance, &mut Instance) -> WasmEdgeResult<()>,
+    {
+        self.replace_named(name.as_ref(), module, migrate, 0)
+    }
+
+    /// Replaces a named wasm module instance. See [Store::instantiate_active] for `others`.
+    pub(crate) fn replace_named<F>(
+        &mut self,
+        name: &str,
+        module: &Module,
+        migrate: F,
+        others: usize,
+    ) -> WasmEdgeResult<()>
+    where
+        F: FnOnce(&mut Instance, &mut Instance) -> WasmEdgeResult<()>,
+    {
+        if !self.wasm_instance_map.contains_key(name) {
+            return Err(Box::new(WasmEdgeError::Store(StoreError::NotFoundModule(
+                name.into(),
+            ))));
+        }
+        let reloadable = self.reloadable.contains_key(name);
+        if !reloadable {
+            self.check_unused(name)?;
+        }
+
+        let created = self.resolve_imports(module, None)?;
+        let result =
+            self.instantiate_staged(name, module, others)
+                .and_then(|(mut new, unexported)| {
+                    if let Some((namespace, _)) = self.reloadable.get(name) {
+                        check_forwarded_funcs(name, namespace, &new)?;
+                    }
+                    if let Some(old) = self.wasm_instance_map.get_mut(name) {
+                        migrate(old, &mut new)?;
+                    }
+                    if !reloadable {
+                        self.relink(name, &new)?;
+                    }
+                    Ok((new, unexported))
+                });
+        let (new, unexported) = self.rollback_on_error(created, result)?;
+
+        if let Some((_, target)) = self.reloadable.get(name) {
+            target.set(&new);
+        }
+        // the old instance is dropped after the name is switched to the new one
+        self.wasm_instance_map.insert(name.to_string(), new);
+        self.unexported_memory.insert(name.to_string(), unexported);
+        self.dependencies
+            .insert(name.to_string(), import_module_names(module));
+        Ok(())
+    }
+
+    /// Replaces the underlying store with a new one, in which the given instance is registered under the given name, and the other registered module instances under their names, so that the old instance is unregistered only if all registrations succeed.
+    fn relink(&mut self, name: &str, new: &Instance) -> WasmEdgeResult<()> {
+        let mut store = sys::Store::create()?;
+        for other in self.inner.module_names().unwrap_or_default() {
+            if other != name {
+                let instance = self.inner.module(&other)?;
+                self.executor
+                    .register_import_module(&mut store, &*instance)?;
+            }
+        }
+        self.executor.register_import_module(&mut store, new)?;
+        self.inner = store;
+        Ok(())
+    }
+
+    /// Instantiates the given module as a named module instance in a staging store, which holds the module instances it imports from, because the name is still taken in this store by the instance to replace. Returns the new instance and the size in bytes counted for its unexported memories.
+    fn instantiate_staged(
+        &mut self,
+        name: &str,
+        module: &Module,
+        others: usize,
+    ) -> WasmEdgeResult<(Instance, usize)> {
+        let mut staging = sys::Store::create()?;
+        for import_name in import_module_names(module) {
+            // a missing import module is reported by the instantiation
+            if let Ok(instance) = self.inner.module(&import_name) {
+                self.executor
+                    .register_import_module(&mut staging, &*instance)?;
+            }
+        }
+        self.instantiate_limited_into(Some(&mut staging), module, Some(name), others)
+    }
+
+    /// Returns the names of the named wasm [module instances](crate::Instance) which import from the module instance of the given name.
+    ///
+    /// The anonymous module instances are counted by [anonymous_dependents](crate::Store::anonymous_dependents).
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the module instance.
+    pub fn dependents(&self, name: impl AsRef<str>) -> Vec<String> {
+        let name = name.as_ref();
+        let mut dependents: Vec<String> = self
+            .dependencies
+            .iter()
+            .filter(|(dependent, dependencies)| {
+                dependent.as_str() != name && dependencies.iter().any(|x| x == name)
+            })
+            .map(|(dependent, _)| dependent.clone())
+            .collect();
+        dependents.sort();
+        dependents
+    }
+
+    /// Returns the number of the anonymous [module instances](crate::Instance) created by this [store](crate::Store) which import from the module instance of the given name, and are not dropped yet.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the module instance.
+    pub fn anonymous_dependents(&self, name: impl AsRef<str>) -> usize {
+        let name = name.as_ref();
+        self.anonymous_instances
+            .iter()
+            .filter(|(token, imports)| {
+                token.strong_count() > 0 && imports.iter().any(|x| x == name)
+            })
+            .count()
+    }
+
+    /// Returns [StoreError::InUse](crate::error::StoreError::InUse) if any module instance created by this store imports from the module instance of the given name.
+    fn check_unused(&self, name: &str) -> WasmEdgeResult<()> {
+        let mut dependents = self.dependents(name);
+        let anonymous = self.anonymous_dependents(name);
+        if anonymous > 0 {
+            dependents.push(format!("({anonymous} anonymous module instances)"));
+        }
+        if !dependents.is_empty() {
+            return Err(Box::new(WasmEdgeError::Store(StoreError::InUse {
+                name: name.into(),
+                dependents,
+            })));
+        }
         Ok(())
     }
 
+    /// Unregisters the named [module instance](crate::Instance) from this [store](crate::Store), and drops it to free its memories and other resources.
+    ///
+    /// The named wasm module instances and the namespaces created by resolvers, [stubs](crate::ImportStubs) and [Linker](crate::Linker) can be unregistered. The import objects passed to [Store::new](crate::Store::new) are borrowed by this [store](crate::Store), so they cannot be unregistered.
+    ///
+    /// The module instance is unregistered only if no other module instance created by this [store](crate::Store) imports from it, because dropping it would leave their imports dangling. An anonymous module instance created by this [store](crate::Store) keeps the module instances it imports from in use until it is dropped.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the module instance.
+    ///
+    /// # Error
+    ///
+    /// * If any other module instance created by this [store](crate::Store) imports from the module instance, then [StoreError::InUse](crate::error::StoreError::InUse) is returned.
+    ///
+    /// * If the module instance is not found, then [StoreError::NotFoundModule](crate::error::StoreError::NotFoundModule) is returned.
+    ///
+    /// * If the module instance is an import object passed to [Store::new](crate::Store::new), then an error is returned.
+    pub fn unregister(&mut self, name: impl AsRef<str>) -> WasmEdgeResult<()> {
+        let name = name.as_ref();
+        self.check_unused(name)?;
+
+        // dropping a module instance unregisters it from the underlying store
+        if self.wasm_instance_map.remove(name).is_some() {
+            self.reloadable.remove(name);
+            self.dependencies.remove(name);
+            self.unexported_memory.remove(name);
+            Ok(())
+        } else if self.defined_instance_map.remove(name).is_some() {
+            self.dependencies.remove(name);
+            Ok(())
+        } else if self.instances.contains_key(name) {
+            Err(Box::new(WasmEdgeError::Operation(format!(
+                "The import object ({name}) is borrowed by the store, and cannot be unregistered"
+            ))))
+        } else {
+            Err(Box::new(WasmEdgeError::Store(StoreError::NotFoundModule(
+                name.into(),
+            ))))
+        }
+    }
+
+    /// Registers and instantiates a WasmEdge [compiled module](crate::Module) into this [store](crate::Store) as an anonymous active [module instance](crate::Instance), and returns the module instance. The imports from the namespaces not registered in this [store](crate::Store) are resolved by the given resolver.
+    ///
+    /// The resolver is called once for each import which is not provided by this [store](crate::Store), in the order of [Module::imports](crate::Module::imports). That includes the imports missing from a registered namespace. The returned items for an unknown namespace are grouped by the module names of the imports into new named module instances, which are registered into this [store](crate::Store) before the module is instantiated, and unregistered again if the instantiation fails. The returned items for a namespace created by a resolver, [stubs](crate::ImportStubs) or a [Linker](crate::Linker) are added to it, and kept even if the instantiation fails. The namespaces provided by import objects and wasm module instances cannot be extended, so an item returned for them is an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The validated [module](crate::Module) to be registered.
+    ///
+    /// * `resolver` - The callback returning the host item for a given import, or `None` if the import cannot be resolved.
+    ///
+    /// # Error
+    ///
+    /// * If the resolver returns an item for a namespace provided by an import object or a wasm module instance, then [StoreError::SealedNamespace](crate::error::StoreError::SealedNamespace) is returned.
+    ///
+    /// * If fail to register the resolved items or the given [module](crate::Module), then an error is returned.
+    pub fn register_active_module_with_resolver<F>(
+        &mut self,
+        module: &Module,
+        resolver: F,
+    ) -> WasmEdgeResult<Instance>
+    where
+        F: Fn(&ImportType) -> Option<Extern>,
+    {
+        self.instantiate_active(module, Some(&resolver), 0)
+            .map(|(instance, _)| instance)
+    }
+
+    /// Registers and instantiates a WasmEdge [compiled module](crate::Module) into this [store](crate::Store) as a named [module instance](crate::Instance). The imports from the namespaces not registered in this [store](crate::Store) are resolved by the given resolver.
+    ///
+    /// See [register_active_module_with_resolver](crate::Store::register_active_module_with_resolver) for the resolution rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the module instance.
+    ///
+    /// * `module` - The validated [module](crate::Module) to be registered.
+    ///
+    /// * `resolver` - The callback returning the host item for a given import, or `None` if the import cannot be resolved.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the resolved items or the given [module](crate::Module), then an error is returned.
+    pub fn register_named_module_with_resolver<F>(
+        &mut self,
+        name: impl AsRef<str>,
+        module: &Module,
+        resolver: F,
+    ) -> WasmEdgeResult<()>
+    where
+        F: Fn(&ImportType) -> Option<Extern>,
+    {
+        self.instantiate_named(name.as_ref(), module, Some(&resolver), 0)
+    }
+
+    /// Resolves the imports of the given module which are not provided by this store, first by the given resolver and then by the [stubs](crate::ImportStubs) if enabled.
+    ///
+    /// The items for a namespace owned by this store are added to it, and the items for an unknown namespace are grouped into a new namespace. Returns the names of the new namespaces.
+    pub(crate) fn resolve_imports(
+        &mut self,
+        module: &Module,
+        resolver: Option<&Resolver>,
+    ) -> WasmEdgeResult<Vec<String>> {
+        if resolver.is_none() && !self.import_stubs.is_enabled() {
+            return Ok(Vec::new());
+        }
+
+        let mut created: Vec<(String, sys::ImportModule<NamespaceData>)> = Vec::new();
+        for import in module.imports() {
+            let mod_name = import.module_name().to_string();
+            let name = import.name().to_string();
+            let ty = match import.ty() {
+                Ok(ty) => ty,
+                // an import of which the type cannot be read can still be provided by name
+                Err(_) if self.exports_name(&mod_name, &name) => continue,
+                Err(error) => {
+                    return Err(Box::new(WasmEdgeError::Store(
+                        StoreError::UnreadableImport {
+                            module_name: mod_name,
+                            name,
+                            reason: error.to_string(),
+                        },
+                    )));
+                }
+            };
+            let provided = match self.inner.module(&mod_name) {
+                Ok(instance) => exports_item(&*instance, &name, &ty),
+                Err(_) => created
+                    .iter()
+                    .any(|(x, namespace)| *x == mod_name && exports_item(namespace, &name, &ty)),
+            };
+            if provided {
+                continue;
+            }
+
+            let item = match resolver.and_then(|resolver| resolver(&import)) {
+                Some(item) => item,
+                None => match self.create_stub(&mod_name, &name, ty)? {
+                    Some(item) => item,
+                    None => continue,
+                },
+            };
+
+            if let Some(namespace) = self.defined_instance_map.get_mut(&mod_name) {
+                item.add_to(namespace, name);
+            } else if self.inner.contains(&mod_name) {
+                return Err(Box::new(WasmEdgeError::Store(
+                    StoreError::SealedNamespace {
+                        module_name: mod_name,
+                        name,
+                    },
+                )));
+            } else if let Some((_, namespace)) = created.iter_mut().find(|(x, _)| *x == mod_name) {
+                item.add_to(namespace, name);
+            } else {
+                let mut namespace = sys::ImportModule::create(&mod_name, Box::new(Vec::new()))?;
+                item.add_to(&mut namespace, name);
+                created.push((mod_name, namespace));
+            }
+        }
+
+        let mut names = Vec::new();
+        for (name, namespace) in created {
+            if let Err(err) = self
+                .executor
+                .register_import_module(&mut self.inner, &namespace)
+            {
+                return self.rollback_on_error(names, Err(err));
+            }
+            self.defined_instance_map.insert(name.clone(), namespace);
+            names.push(name);
+        }
+
+        Ok(names)
+    }
+
+    /// Sets which kinds of unresolved imports are replaced by stubs when a [module](crate::Module) is registered into this [store](crate::Store). By default, no stub is created.
+    ///
+    /// The imports not provided by this [store](crate::Store) and not resolved by a resolver are replaced by stubs of the imported types, following the same rules as the items returned by a resolver, see [register_active_module_with_resolver](crate::Store::register_active_module_with_resolver). So a stub is also added for an item missing from a namespace created by a resolver, stubs or a [Linker](crate::Linker). Stub globals, memories and tables are created with zero values and the minimum limits of the imported types.
+    ///
+    /// A stub function traps when called. The calls by [Vm](crate::Vm) and [InstancePool](crate::InstancePool) report the trap as [StoreError::StubCalled](crate::error::StoreError::StubCalled) naming the import. The calls through the [executor](crate::Store::executor) directly report it as [WasmEdgeError::User](crate::error::WasmEdgeError) with the code [ImportStubs::TRAP_CODE](crate::ImportStubs::TRAP_CODE).
+    ///
+    /// # Argument
+    ///
+    /// * `stubs` - The kinds of imports to stub.
+    pub fn set_import_stubs(&mut self, stubs: ImportStubs) {
+        self.import_stubs = stubs;
+    }
+
+    /// Returns the kinds of unresolved imports replaced by stubs.
+    pub fn import_stubs(&self) -> ImportStubs {
+        self.import_stubs
+    }
+
+    /// Checks if the registered namespace of the given name exports an item of the given name.
+    fn exports_name(&self, mod_name: &str, name: &str) -> bool {
+        self.inner.module(mod_name).is_ok_and(|instance| {
+            instance.get_func(name).is_ok()
+                || instance.get_table(name).is_ok()
+                || instance.get_memory_ref(name).is_ok()
+                || instance.get_global(name).is_ok()
+        })
+    }
+
+    /// Creates a stub for the given import if the kind of the import is enabled in [ImportStubs].
+    fn create_stub(
+        &self,
+        mod_name: &str,
+        name: &str,
+        ty: ExternalInstanceType,
+    ) -> WasmEdgeResult<Option<Extern>> {
+        let stubs = self.import_stubs;
+        let item = match ty {
+            ExternalInstanceType::Func(ty) if stubs.funcs => {
+                // the stub function traps with the names of the import, which live as long as the namespace owning the function
+                let import = Box::new((mod_name.to_string(), name.to_string()));
+                let func = unsafe {
+                    sys::Function::create_with_custom_wrapper(
+                        &ty,
+                        stub_trap_wrapper,
+                        std::ptr::null_mut(),
+                        &*import as *const (String, String) as *mut std::ffi::c_void,
+                        0,
+                    )
+                }?;
+                Extern::HostFunc(HostFunc {
+                    func,
+                    data: HostFuncData::Stub(import),
+                })
+            }
+            ExternalInstanceType::Global(ty) if stubs.globals => {
+                let value = match ty.value_ty() {
+                    ValType::I32 => WasmValue::from_i32(0),
+                    ValType::I64 => WasmValue::from_i64(0),
+                    ValType::F32 => WasmValue::from_f32(0.0),
+                    ValType::F64 => WasmValue::from_f64(0.0),
+                    ValType::V128 => WasmValue::from_v128(0),
+                    ValType::ExternRef => WasmValue::null_extern_ref(),
+                    ValType::FuncRef | ValType::UnsupportedRef => return Ok(None),
+                };
+                Extern::Global(sys::Global::create(&ty, value)?)
+            }
+            ExternalInstanceType::Memory(ty) if stubs.memories => {
+                Extern::Memory(sys::Memory::create(&ty)?)
+            }
+            ExternalInstanceType::Table(ty) if stubs.tables => {
+                Extern::Table(sys::Table::create(ty)?)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(item))
+    }
+
+    /// Checks if the imports of the given [module](crate::Module) can be satisfied by the named [module instances](crate::Instance) registered in this [store](crate::Store), and returns all the missing or mismatched imports.
+    ///
+    /// An empty vector means that all imports can be resolved.
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The [module](crate::Module) to check.
+    pub fn check_imports(&self, module: &Module) -> Vec<ImportProblem> {
+        let mut problems = Vec::new();
+        for import in module.imports() {
+            let module_name = import.module_name().to_string();
+            let name = import.name().to_string();
+            let expected = match import.ty() {
+                Ok(ty) => ty,
+                Err(error) => {
+                    problems.push(ImportProblem::UnreadableType {
+                        module_name,
+                        name,
+                        reason: error.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let instance = match self.inner.module(&module_name) {
+                Ok(instance) => instance,
+                Err(_) => {
+                    problems.push(ImportProblem::MissingModule {
+                        module_name,
+                        name,
+                        expected,
+                    });
+                    continue;
+                }
+            };
+
+            let actual = match &expected {
+                ExternalInstanceType::Func(_) => instance
+                    .get_func(&name)
+                    .ok()
+                    .and_then(|func| func.ty())
+                    .map(ExternalInstanceType::Func),
+                ExternalInstanceType::Table(_) => instance
+                    .get_table(&name)
+                    .ok()
+                    .and_then(|table| table.ty().ok())
+                    .map(ExternalInstanceType::Table),
+                ExternalInstanceType::Memory(_) => instance
+                    .get_memory_ref(&name)
+                    .ok()
+                    .and_then(|memory| memory.ty().ok())
+                    .map(ExternalInstanceType::Memory),
+                ExternalInstanceType::Global(_) => instance
+                    .get_global(&name)
+                    .ok()
+                    .and_then(|global| global.ty().ok())
+                    .map(ExternalInstanceType::Global),
+            };
+
+            match actual {
+                None => problems.push(ImportProblem::MissingItem {
+                    module_name,
+                    name,
+                    expected,
+                }),
+                Some(actual) if !is_import_compatible(&expected, &actual) => {
+                    problems.push(ImportProblem::Mismatched {
+                        module_name,
+                        name,
+                        expected,
+                        actual,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        problems
+    }
+
     /// Returns the number of the named [module instances](crate::Instance) in this [store](crate::Store).
     pub fn named_instance_count(&self) -> usize {
-        self.instances.len() + self.wasm_instance_map.len()
+        self.instances.len() + self.wasm_instance_map.len() + self.defined_instance_map.len()
     }
 
     /// Returns the names of all registered named [module instances](crate::Instance).
@@ -97,6 +876,7 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         self.instances
             .keys()
             .chain(self.wasm_instance_map.keys())
+            .chain(self.defined_instance_map.keys())
             .cloned()
             .collect()
     }
@@ -109,7 +889,9 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
     ///
     pub fn contains(&self, mod_name: impl AsRef<str>) -> bool {
         let mod_name = mod_name.as_ref().to_string();
-        self.instances.contains_key(&mod_name) || self.wasm_instance_map.contains_key(&mod_name)
+        self.instances.contains_key(&mod_name)
+            || self.wasm_instance_map.contains_key(&mod_name)
+            || self.defined_instance_map.contains_key(&mod_name)
     }
 
     pub fn get_instance_and_executor(
@@ -135,4 +917,721 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
     pub fn executor(&mut self) -> &mut sys::Executor {
         &mut self.executor
     }
+
+    /// Returns the [statistics](crate::Statistics) of this [store](crate::Store), or `None` if the store is not created by [Store::with_statistics](crate::Store::with_statistics).
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    /// Returns the mutable [statistics](crate::Statistics) of this [store](crate::Store), or `None` if the store is not created by [Store::with_statistics](crate::Store::with_statistics).
+    pub fn statistics_mut(&mut self) -> Option<&mut Statistics> {
+        self.statistics.as_mut()
+    }
+
+    /// Attaches a [resource limiter](crate::ResourceLimiter) to this [store](crate::Store), replacing the previous one.
+    ///
+    /// See [ResourceLimiter](crate::ResourceLimiter) for when the limiter is consulted.
+    ///
+    /// # Argument
+    ///
+    /// * `limiter` - The resource limiter.
+    pub fn set_resource_limiter(&mut self, limiter: impl ResourceLimiter + 'static) {
+        self.limiter = Some(Box::new(limiter));
+    }
+
+    /// Detaches the [resource limiter](crate::ResourceLimiter) from this [store](crate::Store), and returns it.
+    pub fn take_resource_limiter(&mut self) -> Option<Box<dyn ResourceLimiter>> {
+        self.limiter.take()
+    }
+
+    /// Returns the total size in bytes of the memories of the named wasm [module instances](crate::Instance) in this [store](crate::Store).
+    ///
+    /// A memory exported by several module instances is counted once. A memory not exported by its module instance cannot be looked up, so it is counted with its initial size, see [ResourceLimiter](crate::ResourceLimiter). The memories of the import objects passed to [Store::new](crate::Store::new) are not counted.
+    pub fn memory_usage(&self) -> usize {
+        self.memory_usage_in(&mut HashSet::new())
+    }
+
+    /// Returns the total size in bytes of the memories of the named wasm module instances which are not in `seen`.
+    pub(crate) fn memory_usage_in(
+        &self,
+        seen: &mut HashSet<*const sys::ffi::WasmEdge_MemoryInstanceContext>,
+    ) -> usize {
+        let mut total = 0;
+        for inst in self.wasm_instance_map.values() {
+            total += memory_bytes(inst, seen);
+        }
+        for inst in self.defined_instance_map.values() {
+            total += memory_bytes(inst, seen);
+        }
+        total + self.unexported_memory.values().sum::<usize>()
+    }
+
+    /// Grows an exported memory of a named wasm [module instance](crate::Instance) by the given number of pages, and returns the previous size in pages.
+    ///
+    /// The [resource limiter](crate::ResourceLimiter) of this store, if any, is consulted before growing.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the module instance.
+    ///
+    /// * `mem_name` - The exported name of the memory.
+    ///
+    /// * `pages` - The number of pages to grow.
+    ///
+    /// # Error
+    ///
+    /// If the module instance or the memory is not found, the growth is denied by the resource limiter, or fail to grow the memory, then an error is returned.
+    pub fn grow_memory(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        mem_name: impl AsRef<str>,
+        pages: u32,
+    ) -> WasmEdgeResult<u32> {
+        let store_total = self.memory_usage();
+        self.grow_named_memory(mod_name, mem_name, pages, store_total)
+    }
+
+    pub(crate) fn grow_named_memory(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        mem_name: impl AsRef<str>,
+        pages: u32,
+        store_total: usize,
+    ) -> WasmEdgeResult<u32> {
+        let mod_name = mod_name.as_ref();
+        let Store {
+            wasm_instance_map,
+            defined_instance_map,
+            limiter,
+            ..
+        } = self;
+        if let Some(inst) = wasm_instance_map.get_mut(mod_name) {
+            grow_memory(limiter, inst, mem_name, pages, store_total)
+        } else if let Some(inst) = defined_instance_map.get_mut(mod_name) {
+            grow_memory(limiter, inst, mem_name, pages, store_total)
+        } else {
+            Err(Box::new(WasmEdgeError::Store(StoreError::NotFoundModule(
+                mod_name.into(),
+            ))))
+        }
+    }
+
+    /// Grows an exported table of a named wasm [module instance](crate::Instance) by the given number of elements, and returns the previous number of elements.
+    ///
+    /// The [resource limiter](crate::ResourceLimiter) of this store, if any, is consulted before growing.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the module instance.
+    ///
+    /// * `table_name` - The exported name of the table.
+    ///
+    /// * `delta` - The number of elements to grow.
+    ///
+    /// # Error
+    ///
+    /// If the module instance or the table is not found, the growth is denied by the resource limiter, or fail to grow the table, then an error is returned.
+    pub fn grow_table(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        table_name: impl AsRef<str>,
+        delta: u32,
+    ) -> WasmEdgeResult<u32> {
+        let mod_name = mod_name.as_ref();
+        let Store {
+            wasm_instance_map,
+            defined_instance_map,
+            limiter,
+            ..
+        } = self;
+        if let Some(inst) = wasm_instance_map.get_mut(mod_name) {
+            grow_table(limiter, inst, table_name, delta)
+        } else if let Some(inst) = defined_instance_map.get_mut(mod_name) {
+            grow_table(limiter, inst, table_name, delta)
+        } else {
+            Err(Box::new(WasmEdgeError::Store(StoreError::NotFoundModule(
+                mod_name.into(),
+            ))))
+        }
+    }
+
+    /// Consults the resource limiter for the memories and tables defined by the module.
+    ///
+    /// Returns the number of pages the memories of the module are capped at, if any, and the size in bytes counted for the unexported memories, which is their initial size. See [Store::instantiate_active] for `others`.
+    pub(crate) fn check_instantiation(
+        &mut self,
+        module: &Module,
+        others: usize,
+    ) -> WasmEdgeResult<(Option<u32>, usize)> {
+        let info = match (&self.limiter, module.info()) {
+            (_, Some(info)) => info,
+            (Some(_), None) => {
+                return Err(Box::new(WasmEdgeError::Operation(String::from(
+                    "The resource limiter cannot check the memories and tables of a module without module info",
+                ))))
+            }
+            (None, None) => return Ok((None, 0)),
+        };
+        let mut store_total = self.memory_usage().saturating_add(others);
+        let cap = self
+            .limiter
+            .as_ref()
+            .and_then(|limiter| limiter.memory_pages_cap(store_total))
+            .map(|cap| cap.min(self.max_memory_pages()));
+
+        let mut unexported = 0usize;
+        for (index, ty) in info.memories().iter().enumerate() {
+            let desired = pages_to_bytes(ty.minimum());
+            // an unexported memory cannot be looked up later, so it is counted with its initial size
+            if !info.memory_exported(index) {
+                unexported = unexported.saturating_add(desired);
+            }
+            if let Some(limiter) = self.limiter.as_mut() {
+                let maximum = ty.maximum().map(pages_to_bytes);
+                if !limiter.memory_growing(0, desired, maximum, store_total) {
+                    return Err(Box::new(WasmEdgeError::Mem(MemError::GrowDenied)));
+                }
+            }
+            store_total = store_total.saturating_add(desired);
+        }
+        if let Some(limiter) = self.limiter.as_mut() {
+            for ty in info.tables() {
+                if !limiter.table_growing(0, ty.minimum(), ty.maximum()) {
+                    return Err(Box::new(WasmEdgeError::Table(TableError::GrowDenied)));
+                }
+            }
+        }
+        Ok((cap, unexported))
+    }
+}
+
+/// Returns the distinct names of the modules the given module imports from.
+pub(crate) fn import_module_names(module: &Module) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for import in module.imports() {
+        let name = import.module_name();
+        if !names.iter().any(|x| *x == name) {
+            names.push(name.into_owned());
+        }
+    }
+    names
+}
+
+/// Returns the total size in bytes of the exported memories of the given instance which are not in `seen`.
+pub(crate) fn memory_bytes<I: AsInstance>(
+    inst: &I,
+    seen: &mut HashSet<*const sys::ffi::WasmEdge_MemoryInstanceContext>,
+) -> usize {
+    inst.mem_names()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|name| inst.get_memory_ref(name).ok())
+        .filter(|mem| seen.insert(unsafe { mem.as_ptr() }))
+        .map(|mem| pages_to_bytes(mem.size()))
+        .sum()
+}
+
+/// Grows the exported memory of the given instance after consulting the resource limiter.
+pub(crate) fn grow_memory<I: AsInstance>(
+    limiter: &mut Option<Box<dyn ResourceLimiter>>,
+    inst: &mut I,
+    mem_name: impl AsRef<str>,
+    pages: u32,
+    store_total: usize,
+) -> WasmEdgeResult<u32> {
+    let mut mem = inst.get_memory_mut(mem_name)?;
+    let current = mem.size();
+    if let Some(limiter) = limiter {
+        let desired = pages_to_bytes(current.saturating_add(pages));
+        let maximum = mem.ty()?.maximum().map(pages_to_bytes);
+        if !limiter.memory_growing(pages_to_bytes(current), desired, maximum, store_total) {
+            return Err(Box::new(WasmEdgeError::Mem(MemError::GrowDenied)));
+        }
+    }
+    mem.grow(pages)?;
+    Ok(current)
+}
+
+/// Grows the exported table of the given instance after consulting the resource limiter.
+pub(crate) fn grow_table<I: AsInstance>(
+    limiter: &mut Option<Box<dyn ResourceLimiter>>,
+    inst: &mut I,
+    table_name: impl AsRef<str>,
+    delta: u32,
+) -> WasmEdgeResult<u32> {
+    let mut table = inst.get_table_mut(table_name)?;
+    let current = table.capacity() as u32;
+    if let Some(limiter) = limiter {
+        let maximum = table.ty()?.maximum();
+        if !limiter.table_growing(current, current.saturating_add(delta), maximum) {
+            return Err(Box::new(WasmEdgeError::Table(TableError::GrowDenied)));
+        }
+    }
+    table.grow(delta)?;
+    Ok(current)
+}
+
+/// The callback resolving an import missing from a [store](crate::Store).
+pub(crate) type Resolver = dyn Fn(&ImportType) -> Option<Extern>;
+
+/// Checks if the given module instance exports an item of the given name and kind.
+/// Checks if the given new instance exports every function of the forwarding namespace of a reloadable module instance with the same type.
+fn check_forwarded_funcs(
+    name: &str,
+    namespace: &sys::ImportModule<NamespaceData>,
+    new: &Instance,
+) -> WasmEdgeResult<()> {
+    for func_name in namespace.func_names().unwrap_or_default() {
+        let expected = namespace.get_func(&func_name)?.ty();
+        let actual = new.get_func(&func_name).ok().and_then(|func| func.ty());
+        if actual.is_none() || actual != expected {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "The new module instance does not export the function ({func_name}) of the reloadable module instance ({name}) with the same type"
+            ))));
+        }
+    }
+    Ok(())
+}
+
+fn exports_item<I: AsInstance>(instance: &I, name: &str, ty: &ExternalInstanceType) -> bool {
+    match ty {
+        ExternalInstanceType::Func(_) => instance.get_func(name).is_ok(),
+        ExternalInstanceType::Table(_) => instance.get_table(name).is_ok(),
+        ExternalInstanceType::Memory(_) => instance.get_memory_ref(name).is_ok(),
+        ExternalInstanceType::Global(_) => instance.get_global(name).is_ok(),
+    }
+}
+
+thread_local! {
+    /// The import of the stub function which trapped last on this thread.
+    static STUB_TRAP: RefCell<Option<(String, String)>> = const { RefCell::new(None) };
+}
+
+/// The function wrapper of the stub functions, which records the import names stored in the host data pointer and traps with [ImportStubs::TRAP_CODE].
+unsafe extern "C" fn stub_trap_wrapper(
+    _key_ptr: *mut std::ffi::c_void,
+    data_ptr: *mut std::ffi::c_void,
+    _calling_frame_ctx: *const sys::ffi::WasmEdge_CallingFrameContext,
+    _params: *const sys::ffi::WasmEdge_Value,
+    _param_len: u32,
+    _returns: *mut sys::ffi::WasmEdge_Value,
+    _return_len: u32,
+) -> sys::ffi::WasmEdge_Result {
+    let import = &*(data_ptr as *const (String, String));
+    STUB_TRAP.with(|trap| *trap.borrow_mut() = Some(import.clone()));
+    sys::ffi::WasmEdge_ResultGen(
+        sys::ffi::WasmEdge_ErrCategory_UserLevelError,
+        ImportStubs::TRAP_CODE,
+    )
+}
+
+/// Runs the given call of a wasm function, and reports the trap of a stub function as [StoreError::StubCalled] naming the import.
+///
+/// A trap with [ImportStubs::TRAP_CODE] is only reported as [StoreError::StubCalled] if a stub function trapped during the call, so the same code returned by another host function is kept.
+pub(crate) fn name_stub_trap<R>(call: impl FnOnce() -> WasmEdgeResult<R>) -> WasmEdgeResult<R> {
+    STUB_TRAP.with(|trap| trap.borrow_mut().take());
+    let result = call();
+    let trap = STUB_TRAP.with(|trap| trap.borrow_mut().take());
+    match (result, trap) {
+        (Err(err), Some((module_name, name)))
+            if *err == WasmEdgeError::User(ImportStubs::TRAP_CODE) =>
+        {
+            Err(Box::new(WasmEdgeError::Store(StoreError::StubCalled {
+                module_name,
+                name,
+            })))
+        }
+        (result, _) => result,
+    }
+}
+
+/// Defines the kinds of unresolved imports replaced by stubs. See [Store::set_import_stubs](crate::Store::set_import_stubs).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportStubs {
+    funcs: bool,
+    globals: bool,
+    memories: bool,
+    tables: bool,
+}
+impl ImportStubs {
+    /// The user error code of the trap of a stub function, when the stub function is called through the [executor](crate::Store::executor) directly.
+    pub const TRAP_CODE: u32 = 0x00FF_FFFF;
+
+    /// Creates a new [ImportStubs] with all kinds of imports disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [ImportStubs] with all kinds of imports enabled.
+    pub fn all() -> Self {
+        Self {
+            funcs: true,
+            globals: true,
+            memories: true,
+            tables: true,
+        }
+    }
+
+    /// Enables or disables the stub functions trapping when called.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn with_funcs(self, enable: bool) -> Self {
+        Self {
+            funcs: enable,
+            ..self
+        }
+    }
+
+    /// Enables or disables the stub globals with zero values.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn with_globals(self, enable: bool) -> Self {
+        Self {
+            globals: enable,
+            ..self
+        }
+    }
+
+    /// Enables or disables the stub memories with the minimum limits of the imported types.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn with_memories(self, enable: bool) -> Self {
+        Self {
+            memories: enable,
+            ..self
+        }
+    }
+
+    /// Enables or disables the stub tables with the minimum limits of the imported types.
+    ///
+    /// # Argument
+    ///
+    /// * `enable` - Whether the option turns on or not.
+    pub fn with_tables(self, enable: bool) -> Self {
+        Self {
+            tables: enable,
+            ..self
+        }
+    }
+
+    /// Checks if the stub functions are enabled.
+    pub fn funcs_enabled(&self) -> bool {
+        self.funcs
+    }
+
+    /// Checks if the stub globals are enabled.
+    pub fn globals_enabled(&self) -> bool {
+        self.globals
+    }
+
+    /// Checks if the stub memories are enabled.
+    pub fn memories_enabled(&self) -> bool {
+        self.memories
+    }
+
+    /// Checks if the stub tables are enabled.
+    pub fn tables_enabled(&self) -> bool {
+        self.tables
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.funcs || self.globals || self.memories || self.tables
+    }
+}
+
+/// Describes an import of a [module](crate::Module) that cannot be satisfied by a [store](crate::Store).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportProblem {
+    /// The module hosting the import is not registered.
+    MissingModule {
+        /// The name of the module hosting the import.
+        module_name: String,
+        /// The name of the import.
+        name: String,
+        /// The type expected by the importing module.
+        expected: ExternalInstanceType,
+    },
+    /// The module hosting the import is registered, but does not export an instance of the expected kind with the given name.
+    MissingItem {
+        /// The name of the module hosting the import.
+        module_name: String,
+        /// The name of the import.
+        name: String,
+        /// The type expected by the importing module.
+        expected: ExternalInstanceType,
+    },
+    /// The exported instance does not match the type expected by the importing module.
+    Mismatched {
+        /// The name of the module hosting the import.
+        module_name: String,
+        /// The name of the import.
+        name: String,
+        /// The type expected by the importing module.
+        expected: ExternalInstanceType,
+        /// The type of the exported instance.
+        actual: ExternalInstanceType,
+    },
+    /// The type of the import cannot be read from the module.
+    UnreadableType {
+        /// The name of the module hosting the import.
+        module_name: String,
+        /// The name of the import.
+        name: String,
+        /// The reason why the type cannot be read.
+        reason: String,
+    },
+}
+impl ImportProblem {
+    /// Returns the name of the module hosting the import.
+    pub fn module_name(&self) -> &str {
+        match self {
+            ImportProblem::MissingModule { module_name, .. }
+            | ImportProblem::MissingItem { module_name, .. }
+            | ImportProblem::Mismatched { module_name, .. }
+            | ImportProblem::UnreadableType { module_name, .. } => module_name,
+        }
+    }
+
+    /// Returns the name of the import.
+    pub fn name(&self) -> &str {
+        match self {
+            ImportProblem::MissingModule { name, .. }
+            | ImportProblem::MissingItem { name, .. }
+            | ImportProblem::Mismatched { name, .. }
+            | ImportProblem::UnreadableType { name, .. } => name,
+        }
+    }
+
+    /// Returns the type expected by the importing module, or `None` if the type cannot be read.
+    pub fn expected(&self) -> Option<&ExternalInstanceType> {
+        match self {
+            ImportProblem::MissingModule { expected, .. }
+            | ImportProblem::MissingItem { expected, .. }
+            | ImportProblem::Mismatched { expected, .. } => Some(expected),
+            ImportProblem::UnreadableType { .. } => None,
+        }
+    }
+}
+impl std::fmt::Display for ImportProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportProblem::MissingModule {
+                module_name,
+                name,
+                expected,
+            } => write!(
+                f,
+                "unknown import: module `{module_name}` required by `{module_name}::{name}` of type {expected:?} is not registered"
+            ),
+            ImportProblem::MissingItem {
+                module_name,
+                name,
+                expected,
+            } => write!(
+                f,
+                "unknown import: module `{module_name}` does not export `{name}` of type {expected:?}"
+            ),
+            ImportProblem::Mismatched {
+                module_name,
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "incompatible import type: `{module_name}::{name}` expected {expected:?}, found {actual:?}"
+            ),
+            ImportProblem::UnreadableType {
+                module_name,
+                name,
+                reason,
+            } => write!(
+                f,
+                "unreadable import type: the type of `{module_name}::{name}` cannot be read: {reason}"
+            ),
+        }
+    }
+}
+
+/// Checks if an exported instance of the type `actual` can satisfy an import of the type `expected` by the import matching rules of WebAssembly.
+fn is_import_compatible(expected: &ExternalInstanceType, actual: &ExternalInstanceType) -> bool {
+    let limits_match =
+        |expected_min: u32, expected_max: Option<u32>, actual_min: u32, actual_max: Option<u32>| {
+            actual_min >= expected_min
+                && match (expected_max, actual_max) {
+                    (None, _) => true,
+                    (Some(expected_max), Some(actual_max)) => actual_max <= expected_max,
+                    (Some(_), None) => false,
+                }
+        };
+
+    match (expected, actual) {
+        (ExternalInstanceType::Func(expected), ExternalInstanceType::Func(actual)) => {
+            expected == actual
+        }
+        (ExternalInstanceType::Table(expected), ExternalInstanceType::Table(actual)) => {
+            expected.elem_ty() == actual.elem_ty()
+                && limits_match(
+                    expected.minimum(),
+                    expected.maximum(),
+                    actual.minimum(),
+                    actual.maximum(),
+                )
+        }
+        (ExternalInstanceType::Memory(expected), ExternalInstanceType::Memory(actual)) => {
+            expected.shared() == actual.shared()
+                && limits_match(
+                    expected.minimum(),
+                    expected.maximum(),
+                    actual.minimum(),
+                    actual.maximum(),
+                )
+        }
+        (ExternalInstanceType::Global(expected), ExternalInstanceType::Global(actual)) => {
+            expected == actual
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::CoreError, wat2wasm, CallingFrame, FuncType, ImportObjectBuilder, ValType, WasmValue,
+    };
+
+    fn real_add(
+        _data: &mut (),
+        _inst: &mut Instance,
+        _frame: &mut CallingFrame,
+        inputs: Vec<WasmValue>,
+    ) -> Result<Vec<WasmValue>, CoreError> {
+        let a = inputs[0].to_i32();
+        let b = inputs[1].to_i32();
+        Ok(vec![WasmValue::from_i32(a + b)])
+    }
+
+    #[test]
+    fn test_store_check_imports() {
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (import "extern" "add" (func (param i32 i32) (result i32)))
+            (import "extern" "sub" (func (param i32 i32) (result i32)))
+            (import "extern" "mul" (func (param i64 i64) (result i64)))
+            (import "missing" "div" (func (param i32 i32) (result i32)))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        // create an import object exporting `add` and `mul`
+        let mut import_builder = ImportObjectBuilder::new("extern", ()).unwrap();
+        import_builder
+            .with_func::<(i32, i32), i32>("add", real_add)
+            .unwrap()
+            .with_func::<(i32, i32), i32>("mul", real_add)
+            .unwrap();
+        let mut import_object = import_builder.build();
+
+        let mut instances = HashMap::new();
+        instances.insert(import_object.name().unwrap(), &mut import_object);
+        let store = Store::new(None, instances).unwrap();
+
+        let problems = store.check_imports(&module);
+        assert_eq!(problems.len(), 3);
+
+        // `extern::sub` is not exported
+        assert_eq!(
+            problems[0],
+            ImportProblem::MissingItem {
+                module_name: "extern".to_string(),
+                name: "sub".to_string(),
+                expected: ExternalInstanceType::Func(FuncType::new(
+                    vec![ValType::I32, ValType::I32],
+                    vec![ValType::I32]
+                )),
+            }
+        );
+
+        // `extern::mul` has a different signature
+        assert_eq!(
+            problems[1],
+            ImportProblem::Mismatched {
+                module_name: "extern".to_string(),
+                name: "mul".to_string(),
+                expected: ExternalInstanceType::Func(FuncType::new(
+                    vec![ValType::I64, ValType::I64],
+                    vec![ValType::I64]
+                )),
+                actual: ExternalInstanceType::Func(FuncType::new(
+                    vec![ValType::I32, ValType::I32],
+                    vec![ValType::I32]
+                )),
+            }
+        );
+
+        // `missing` is not registered
+        assert!(matches!(problems[2], ImportProblem::MissingModule { .. }));
+        assert_eq!(problems[2].module_name(), "missing");
+        assert_eq!(problems[2].name(), "div");
+
+        // the expected type is reported in the same form by all problems
+        for problem in problems.iter() {
+            let expected = format!("{:?}", problem.expected().unwrap());
+            assert!(problem.to_string().contains(&expected));
+        }
+    }
+
+    #[test]
+    fn test_store_unregister_with_anonymous_dependents() {
+        let lib =
+            wat2wasm(br#"(module (func (export "one") (result i32) (i32.const 1)))"#).unwrap();
+        let user = wat2wasm(br#"(module (import "lib" "one" (func (result i32))))"#).unwrap();
+
+        let mut store =
+            Store::new(None, HashMap::<String, &mut sys::ImportModule<()>>::new()).unwrap();
+        store
+            .register_named_module("lib", &Module::from_bytes(None, &lib).unwrap())
+            .unwrap();
+        let first = store
+            .register_active_module(&Module::from_bytes(None, &user).unwrap())
+            .unwrap();
+        let second = store
+            .register_active_module(&Module::from_bytes(None, &user).unwrap())
+            .unwrap();
+        assert!(store.dependents("lib").is_empty());
+        assert_eq!(store.anonymous_dependents("lib"), 2);
+
+        // the anonymous module instances keep the module instance they import from in use
+        let result = store.unregister("lib");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::InUse {
+                name: String::from("lib"),
+                dependents: vec![String::from("(2 anonymous module instances)")],
+            })
+        );
+        let result =
+            store.replace_named_module("lib", &Module::from_bytes(None, &lib).unwrap(), |_, _| {
+                Ok(())
+            });
+        assert!(result.is_err());
+
+        // until they are dropped
+        drop(first);
+        assert_eq!(store.anonymous_dependents("lib"), 1);
+        assert!(store.unregister("lib").is_err());
+        drop(second);
+        assert_eq!(store.anonymous_dependents("lib"), 0);
+        store.unregister("lib").unwrap();
+        assert!(!store.contains("lib"));
+    }
 }
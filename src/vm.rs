@@ -1,7 +1,14 @@
 //! Defines WasmEdge Vm struct.
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
 use crate::{
-    error::{VmError, WasmEdgeError},
-    ImportObject, Instance, Module, Store, WasmEdgeResult, WasmValue,
+    error::{StoreError, VmError, WasmEdgeError},
+    store::{grow_memory, grow_table, import_module_names, memory_bytes, name_stub_trap, Resolver},
+    Extern, ImportObject, ImportStubs, ImportType, Instance, Module, Statistics,
+    StatisticsSnapshot, Store, WasmEdgeResult, WasmValue,
 };
 use sys::AsInstance;
 use wasmedge_sys as sys;
@@ -73,6 +80,10 @@ impl SyncInst for Instance {}
 pub struct Vm<'inst, T: ?Sized + SyncInst> {
     store: Store<'inst, T>,
     active_instance: Option<sys::Instance>,
+    active_imports: Vec<String>,
+    active_unexported_memory: usize,
+    // the instance, the names of the modules it imports from, and the size counted for its unexported memories
+    instances: HashMap<InstanceHandle, (sys::Instance, Vec<String>, usize)>,
 }
 impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
     pub fn new(store: Store<'inst, T>) -> Self {
@@ -80,6 +91,9 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
         Vm {
             store,
             active_instance: None,
+            active_imports: Vec::new(),
+            active_unexported_memory: 0,
+            instances: HashMap::new(),
         }
     }
 
@@ -100,18 +114,198 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
         mod_name: Option<&str>,
         module: Module,
     ) -> WasmEdgeResult<&mut Self> {
+        self.instantiate_module(mod_name, &module, None)?;
+        Ok(self)
+    }
+
+    /// Registers a [wasm module](crate::Module) into this vm as a reloadable named module [instance](crate::Instance), which can be replaced by [replace_module](crate::Vm::replace_module) while other module instances import from it.
+    ///
+    /// See [Store::register_reloadable_module](crate::Store::register_reloadable_module) for the details.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name for the registered module.
+    ///
+    /// * `module` - The module to be registered.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the given [module](crate::Module), then an error is returned.
+    pub fn register_reloadable_module(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        module: Module,
+    ) -> WasmEdgeResult<&mut Self> {
+        let others = self.owned_memory_usage();
+        self.store
+            .instantiate_reloadable(mod_name.as_ref(), &module, others)?;
+        Ok(self)
+    }
+
+    /// Instantiates the given module as a named module instance or the active module instance, counting the memories of the instances kept by this vm against the resource limiter of the store.
+    fn instantiate_module(
+        &mut self,
+        mod_name: Option<&str>,
+        module: &Module,
+        resolver: Option<&Resolver>,
+    ) -> WasmEdgeResult<()> {
+        let others = self.owned_memory_usage();
         match mod_name {
             Some(name) => {
-                self.store.register_named_module(name, &module)?;
+                self.store
+                    .instantiate_named(name, module, resolver, others)?;
             }
             None => {
-                self.active_instance = Some(self.store.register_active_module(&module)?);
+                let (instance, unexported) =
+                    self.store.instantiate_active(module, resolver, others)?;
+                self.active_instance = Some(instance);
+                self.active_imports = import_module_names(module);
+                self.active_unexported_memory = unexported;
             }
         };
+        Ok(())
+    }
 
+    /// Replaces a named wasm module [instance](crate::Instance) in this vm with a new instance of the given [module](crate::Module), and migrates the state by the given closure.
+    ///
+    /// See [Store::replace_named_module](crate::Store::replace_named_module) for the details.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the module instance to replace.
+    ///
+    /// * `module` - The module to instantiate.
+    ///
+    /// * `migrate` - The closure called with the old and the new instances, for example, to copy the exported globals and memory regions.
+    ///
+    /// # Error
+    ///
+    /// If the module instance is not [reloadable](crate::Vm::register_reloadable_module), and the active module instance or any other module instance imports from it, then [StoreError::InUse](crate::error::StoreError::InUse) is returned. If the module instance is not found, fail to instantiate the module, or the migration closure returns an error, then an error is returned and the old instance is kept.
+    pub fn replace_module<F>(
+        &mut self,
+        mod_name: impl AsRef<str>,
+        module: Module,
+        migrate: F,
+    ) -> WasmEdgeResult<&mut Self>
+    where
+        F: FnOnce(&mut Instance, &mut Instance) -> WasmEdgeResult<()>,
+    {
+        let mod_name = mod_name.as_ref();
+        // the instances of this vm importing from a reloadable module instance call the new one
+        if !self.store.is_reloadable(mod_name) {
+            self.check_owned_dependents(mod_name)?;
+        }
+        let others = self.owned_memory_usage();
+        self.store
+            .replace_named(mod_name, &module, migrate, others)?;
         Ok(self)
     }
 
+    /// Instantiates a [wasm module](crate::Module) into this vm as a new anonymous module [instance](crate::Instance), and returns the handle of the instance.
+    ///
+    /// Unlike the active module instance registered by [register_module](crate::Vm::register_module), which is replaced by the next one, each call creates a separate instance, so a module can be instantiated many times for isolation. The instance is kept by this vm until it is dropped by [drop_instance](crate::Vm::drop_instance) or the vm is dropped.
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The module to instantiate.
+    ///
+    /// # Error
+    ///
+    /// If fail to instantiate the given [module](crate::Module), then an error is returned.
+    pub fn instantiate(&mut self, module: &Module) -> WasmEdgeResult<InstanceHandle> {
+        let others = self.owned_memory_usage();
+        let (instance, unexported) = self.store.instantiate_active(module, None, others)?;
+        let handle = InstanceHandle(NEXT_INSTANCE_HANDLE.fetch_add(1, Ordering::Relaxed));
+        self.instances
+            .insert(handle, (instance, import_module_names(module), unexported));
+        Ok(handle)
+    }
+
+    /// Returns a reference to the module [instance](crate::Instance) of the given handle, or `None` if the handle is dropped or created by another vm.
+    ///
+    /// # Argument
+    ///
+    /// * `handle` - The handle returned by [instantiate](crate::Vm::instantiate).
+    pub fn instance(&self, handle: InstanceHandle) -> Option<&Instance> {
+        self.instances.get(&handle).map(|(instance, ..)| instance)
+    }
+
+    /// Returns a mutable reference to the module [instance](crate::Instance) of the given handle, or `None` if the handle is dropped or created by another vm.
+    ///
+    /// # Argument
+    ///
+    /// * `handle` - The handle returned by [instantiate](crate::Vm::instantiate).
+    pub fn instance_mut(&mut self, handle: InstanceHandle) -> Option<&mut Instance> {
+        self.instances
+            .get_mut(&handle)
+            .map(|(instance, ..)| instance)
+    }
+
+    /// Returns the handles of all module instances created by [instantiate](crate::Vm::instantiate) and not dropped yet, in the order they are created.
+    pub fn instance_handles(&self) -> Vec<InstanceHandle> {
+        let mut handles: Vec<InstanceHandle> = self.instances.keys().copied().collect();
+        handles.sort();
+        handles
+    }
+
+    /// Drops the module [instance](crate::Instance) of the given handle to free its memories and other resources. The handle is invalid afterwards.
+    ///
+    /// # Argument
+    ///
+    /// * `handle` - The handle returned by [instantiate](crate::Vm::instantiate).
+    ///
+    /// # Error
+    ///
+    /// If the handle is already dropped or created by another vm, then [VmError::NotFoundInstance](crate::error::VmError::NotFoundInstance) is returned.
+    pub fn drop_instance(&mut self, handle: InstanceHandle) -> WasmEdgeResult<()> {
+        match self.instances.remove(&handle) {
+            Some(_) => Ok(()),
+            None => Err(Box::new(WasmEdgeError::Vm(VmError::NotFoundInstance(
+                handle.0,
+            )))),
+        }
+    }
+
+    /// Registers a [wasm module](crate::Module) into this vm as a named or active module [instance](crate::Instance). The imports from the namespaces not registered in this vm are resolved by the given resolver.
+    ///
+    /// See [Store::register_active_module_with_resolver](crate::Store::register_active_module_with_resolver) for the resolution rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name for the registered module. If `None`, then the module is registered as an active instance.
+    ///
+    /// * `module` - The module to be registered.
+    ///
+    /// * `resolver` - The callback returning the host item for a given import, or `None` if the import cannot be resolved.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the resolved items or the given [module](crate::Module), then an error is returned.
+    pub fn register_module_with_resolver<F>(
+        &mut self,
+        mod_name: Option<&str>,
+        module: Module,
+        resolver: F,
+    ) -> WasmEdgeResult<&mut Self>
+    where
+        F: Fn(&ImportType) -> Option<Extern>,
+    {
+        self.instantiate_module(mod_name, &module, Some(&resolver))?;
+        Ok(self)
+    }
+
+    /// Sets which kinds of unresolved imports are replaced by stubs when a module is registered into this vm. By default, no stub is created.
+    ///
+    /// See [Store::set_import_stubs](crate::Store::set_import_stubs) for details.
+    ///
+    /// # Argument
+    ///
+    /// * `stubs` - The kinds of imports to stub.
+    pub fn set_import_stubs(&mut self, stubs: ImportStubs) -> &mut Self {
+        self.store.set_import_stubs(stubs);
+        self
+    }
+
     /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance).
     ///
     /// # Arguments
@@ -157,7 +351,136 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
                 )
             }
         };
-        executor.call_func(&mut func, args)
+        name_stub_trap(|| executor.call_func(&mut func, args))
+    }
+
+    /// Runs an exported wasm function in the module [instance](crate::Instance) of the given handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle returned by [instantiate](crate::Vm::instantiate).
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If the handle is already dropped or created by another vm, then [VmError::NotFoundInstance](crate::error::VmError::NotFoundInstance) is returned. If fail to run the wasm function, then an error is returned.
+    pub fn run_func_in(
+        &mut self,
+        handle: InstanceHandle,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let (instance, ..) = self
+            .instances
+            .get_mut(&handle)
+            .ok_or_else(|| Box::new(WasmEdgeError::Vm(VmError::NotFoundInstance(handle.0))))?;
+        let mut func = instance.get_func_mut(func_name.as_ref())?;
+        name_stub_trap(|| self.store.executor().call_func(&mut func, args))
+    }
+
+    /// Runs an exported wasm function in the module [instance](crate::Instance) of the given handle, and returns the results together with the [statistics](crate::Statistics) collected during this call.
+    ///
+    /// The store of this vm must be created by [Store::with_statistics](crate::Store::with_statistics).
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle returned by [instantiate](crate::Vm::instantiate).
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If the store has no statistics, the handle is already dropped or created by another vm, or fail to run the wasm function, then an error is returned.
+    pub fn run_func_in_with_statistics(
+        &mut self,
+        handle: InstanceHandle,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<(Vec<WasmValue>, StatisticsSnapshot)> {
+        self.collect_statistics(|vm| vm.run_func_in(handle, func_name, args))
+    }
+
+    /// Runs an exported wasm function in the module [instance](crate::Instance) of the given handle with a timeout setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle returned by [instantiate](crate::Vm::instantiate).
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// * `timeout` - The maximum execution time of the function to be run.
+    ///
+    /// # Error
+    ///
+    /// If the handle is already dropped or created by another vm, then [VmError::NotFoundInstance](crate::error::VmError::NotFoundInstance) is returned. If fail to run the wasm function, then an error is returned.
+    #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+    pub fn run_func_in_with_timeout(
+        &mut self,
+        handle: InstanceHandle,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+        timeout: std::time::Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let (instance, ..) = self
+            .instances
+            .get_mut(&handle)
+            .ok_or_else(|| Box::new(WasmEdgeError::Vm(VmError::NotFoundInstance(handle.0))))?;
+        let mut func = instance.get_func_mut(func_name.as_ref())?;
+        let executor = self.store.executor();
+        name_stub_trap(|| executor.call_func_with_timeout(&mut func, args, timeout))
+    }
+
+    /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance), and returns the results together with the [statistics](crate::Statistics) collected during this call.
+    ///
+    /// The store of this vm must be created by [Store::with_statistics](crate::Store::with_statistics).
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the module instance, which holds the target function. If `None`, then the active module is used.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If the store has no statistics, or fail to run the wasm function, then an error is returned.
+    pub fn run_func_with_statistics(
+        &mut self,
+        mod_name: Option<&str>,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<(Vec<WasmValue>, StatisticsSnapshot)> {
+        self.collect_statistics(|vm| vm.run_func(mod_name, func_name, args))
+    }
+
+    /// Calls the given closure, and returns its result together with the statistics collected during the call.
+    fn collect_statistics<R>(
+        &mut self,
+        run: impl FnOnce(&mut Self) -> WasmEdgeResult<R>,
+    ) -> WasmEdgeResult<(R, StatisticsSnapshot)> {
+        let before = self
+            .statistics()
+            .map(|stat| stat.snapshot())
+            .ok_or_else(|| {
+                Box::new(WasmEdgeError::Operation(String::from(
+                    "The store of the vm is not created with statistics",
+                )))
+            })?;
+        let returns = run(self)?;
+        let after = self
+            .statistics()
+            .map(|stat| stat.snapshot())
+            .unwrap_or(before);
+
+        Ok((returns, after.since(&before)))
     }
 
     /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance) with a timeout setting
@@ -209,7 +532,7 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
                 )
             }
         };
-        executor.call_func_with_timeout(&mut func, args, timeout)
+        name_stub_trap(|| executor.call_func_with_timeout(&mut func, args, timeout))
     }
 
     /// Returns a reference to the internal [store](crate::Store) from this vm.
@@ -218,10 +541,127 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
     }
 
     /// Returns a mutable reference to the internal [store](crate::Store) from this vm.
+    ///
+    /// The [resource limiter](crate::ResourceLimiter) of the store does not see the memories of the active module instance and the instances created by [instantiate](crate::Vm::instantiate), which are kept by this vm, so the modules registered and the memories grown through the store directly are checked without them. Use the methods of this vm to count them.
     pub fn store_mut(&mut self) -> &mut Store<'inst, T> {
         &mut self.store
     }
 
+    /// Returns the [statistics](crate::Statistics) of the internal [store](crate::Store), or `None` if the store is not created by [Store::with_statistics](crate::Store::with_statistics).
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.store.statistics()
+    }
+
+    /// Returns the mutable [statistics](crate::Statistics) of the internal [store](crate::Store), or `None` if the store is not created by [Store::with_statistics](crate::Store::with_statistics).
+    pub fn statistics_mut(&mut self) -> Option<&mut Statistics> {
+        self.store.statistics_mut()
+    }
+
+    /// Returns the total size in bytes of the memories of the active, the named and the [instantiated](crate::Vm::instantiate) wasm [module instances](crate::Instance) in this vm.
+    ///
+    /// See [Store::memory_usage](crate::Store::memory_usage) for the memories which are counted.
+    pub fn memory_usage(&self) -> usize {
+        let mut seen = HashSet::new();
+        let total = self.store.memory_usage_in(&mut seen);
+        total + self.owned_memory_usage_in(&mut seen)
+    }
+
+    /// Returns the total size in bytes of the memories of the active module instance and the instances created by [instantiate](crate::Vm::instantiate), which are kept by this vm instead of the store.
+    fn owned_memory_usage(&self) -> usize {
+        let mut seen = HashSet::new();
+        self.store.memory_usage_in(&mut seen);
+        self.owned_memory_usage_in(&mut seen)
+    }
+
+    fn owned_memory_usage_in(
+        &self,
+        seen: &mut HashSet<*const sys::ffi::WasmEdge_MemoryInstanceContext>,
+    ) -> usize {
+        let mut total = 0;
+        if let Some(inst) = self.active_instance.as_ref() {
+            total += memory_bytes(inst, seen) + self.active_unexported_memory;
+        }
+        for (inst, _, unexported) in self.instances.values() {
+            total += memory_bytes(inst, seen) + unexported;
+        }
+        total
+    }
+
+    /// Grows an exported memory of a (named or active) wasm [module instance](crate::Instance) by the given number of pages, and returns the previous size in pages.
+    ///
+    /// The [resource limiter](crate::ResourceLimiter) of the internal [store](crate::Store), if any, is consulted before growing.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the module instance. If `None`, then the active module is used.
+    ///
+    /// * `mem_name` - The exported name of the memory.
+    ///
+    /// * `pages` - The number of pages to grow.
+    ///
+    /// # Error
+    ///
+    /// If the module instance or the memory is not found, the growth is denied by the resource limiter, or fail to grow the memory, then an error is returned.
+    pub fn grow_memory(
+        &mut self,
+        mod_name: Option<&str>,
+        mem_name: impl AsRef<str>,
+        pages: u32,
+    ) -> WasmEdgeResult<u32> {
+        let store_total = self.memory_usage();
+        match mod_name {
+            Some(mod_name) => self
+                .store
+                .grow_named_memory(mod_name, mem_name, pages, store_total),
+            None => {
+                let active_inst = self
+                    .active_instance
+                    .as_mut()
+                    .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?;
+                grow_memory(
+                    &mut self.store.limiter,
+                    active_inst,
+                    mem_name,
+                    pages,
+                    store_total,
+                )
+            }
+        }
+    }
+
+    /// Grows an exported table of a (named or active) wasm [module instance](crate::Instance) by the given number of elements, and returns the previous number of elements.
+    ///
+    /// The [resource limiter](crate::ResourceLimiter) of the internal [store](crate::Store), if any, is consulted before growing.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the module instance. If `None`, then the active module is used.
+    ///
+    /// * `table_name` - The exported name of the table.
+    ///
+    /// * `delta` - The number of elements to grow.
+    ///
+    /// # Error
+    ///
+    /// If the module instance or the table is not found, the growth is denied by the resource limiter, or fail to grow the table, then an error is returned.
+    pub fn grow_table(
+        &mut self,
+        mod_name: Option<&str>,
+        table_name: impl AsRef<str>,
+        delta: u32,
+    ) -> WasmEdgeResult<u32> {
+        match mod_name {
+            Some(mod_name) => self.store.grow_table(mod_name, table_name, delta),
+            None => {
+                let active_inst = self
+                    .active_instance
+                    .as_mut()
+                    .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?;
+                grow_table(&mut self.store.limiter, active_inst, table_name, delta)
+            }
+        }
+    }
+
     /// Returns a reference to the active [module instance](crate::Instance) from this vm.
     ///
     /// # Error
@@ -250,6 +690,44 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
         self.store.contains(mod_name)
     }
 
+    /// Unregisters the named [module instance](crate::Instance) from this vm, and drops it to free its memories and other resources.
+    ///
+    /// See [Store::unregister](crate::Store::unregister) for which module instances can be unregistered.
+    ///
+    /// # Argument
+    ///
+    /// * `mod_name` - The name of the module instance.
+    ///
+    /// # Error
+    ///
+    /// If the active module instance or any other named module instance imports from the module instance, then [StoreError::InUse](crate::error::StoreError::InUse) is returned. If the module instance is not found or cannot be unregistered, then an error is returned.
+    pub fn unregister_module(&mut self, mod_name: impl AsRef<str>) -> WasmEdgeResult<()> {
+        let mod_name = mod_name.as_ref();
+        self.check_owned_dependents(mod_name)?;
+        self.store.unregister(mod_name)
+    }
+
+    /// Returns [StoreError::InUse](crate::error::StoreError::InUse) if the active module instance or any anonymous module instance of this vm imports from the named module instance, naming them by their handles in the error.
+    fn check_owned_dependents(&self, mod_name: &str) -> WasmEdgeResult<()> {
+        let mut dependents = Vec::new();
+        if self.active_instance.is_some() && self.active_imports.iter().any(|x| x == mod_name) {
+            dependents.push(String::from("(active module)"));
+        }
+        for handle in self.instance_handles() {
+            if self.instances[&handle].1.iter().any(|x| x == mod_name) {
+                dependents.push(format!("({handle})"));
+            }
+        }
+        if !dependents.is_empty() {
+            dependents.extend(self.store.dependents(mod_name));
+            return Err(Box::new(WasmEdgeError::Store(StoreError::InUse {
+                name: mod_name.into(),
+                dependents,
+            })));
+        }
+        Ok(())
+    }
+
     /// Returns the count of the named [module instances](crate::Instance) this vm holds.
     pub fn named_instance_count(&self) -> usize {
         self.store.named_instance_count()
@@ -261,6 +739,20 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
     }
 }
 
+/// The next handle returned by [Vm::instantiate](crate::Vm::instantiate). The handles are unique in the process, so a handle of a vm never refers to an instance of another vm.
+static NEXT_INSTANCE_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Identifies an anonymous module [instance](crate::Instance) created by [Vm::instantiate](crate::Vm::instantiate).
+///
+/// A handle is only valid for the vm which creates it, until the instance is dropped by [Vm::drop_instance](crate::Vm::drop_instance).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InstanceHandle(u64);
+impl std::fmt::Display for InstanceHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "instance #{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -443,4 +935,813 @@ mod tests {
         assert_eq!(returns.len(), 1);
         assert_eq!(returns[0].to_i32(), 89);
     }
+
+    #[test]
+    fn test_vm_register_module_with_resolver() {
+        use std::sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        };
+
+        // the registry of the native functions keyed by name
+        let mut registry: HashMap<&str, fn(i32, i32) -> i32> = HashMap::new();
+        registry.insert("add", |a, b| a + b);
+        registry.insert("sub", |a, b| a - b);
+        let calls = Arc::new(AtomicU32::new(0));
+
+        // the resolver synthesizes a host function per import, which captures its native function
+        let resolver = |import: &ImportType| {
+            let real_func = *registry.get(import.name().as_ref())?;
+            let calls = calls.clone();
+            match import.ty().ok()? {
+                crate::ExternalInstanceType::Func(ty) => Extern::func(&ty, move |_frame, args| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    Ok(vec![WasmValue::from_i32(real_func(
+                        args[0].to_i32(),
+                        args[1].to_i32(),
+                    ))])
+                })
+                .ok(),
+                _ => None,
+            }
+        };
+
+        let add_bytes = wat2wasm(
+            br#"
+        (module
+            (import "env" "add" (func $add (param i32 i32) (result i32)))
+            (func (export "add") (param i32 i32) (result i32)
+                (call $add (local.get 0) (local.get 1)))
+        )
+"#,
+        )
+        .unwrap();
+        let calc_bytes = wat2wasm(
+            br#"
+        (module
+            (import "env" "add" (func $add (param i32 i32) (result i32)))
+            (import "env" "sub" (func $sub (param i32 i32) (result i32)))
+            (func (export "calc") (param i32 i32) (result i32)
+                (call $sub (call $add (local.get 0) (local.get 1)) (local.get 1)))
+        )
+"#,
+        )
+        .unwrap();
+        let broken_bytes = wat2wasm(
+            br#"
+        (module
+            (import "math" "add" (func (param i32 i32) (result i32)))
+            (import "missing" "mul" (func (param i32 i32) (result i32)))
+        )
+"#,
+        )
+        .unwrap();
+
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.register_module_with_resolver(
+            Some("adder"),
+            Module::from_bytes(None, add_bytes).unwrap(),
+            resolver,
+        )
+        .unwrap();
+        assert!(vm.contains_module("env"));
+
+        // `env::sub` is missing from the registered namespace `env`, and is added to it
+        vm.register_module_with_resolver(
+            None,
+            Module::from_bytes(None, calc_bytes).unwrap(),
+            resolver,
+        )
+        .unwrap();
+        let returns = vm.run_func(None, "calc", params!(3, 2)).unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        // the namespaces created for a module failing to instantiate are unregistered
+        let broken = Module::from_bytes(None, broken_bytes).unwrap();
+        let result = vm.register_module_with_resolver(None, broken.clone(), resolver);
+        assert!(result.is_err());
+        assert!(!vm.contains_module("math"));
+        let result = vm.register_module_with_resolver(None, broken, resolver);
+        assert!(result.is_err());
+        assert!(!vm.contains_module("math"));
+    }
+
+    #[test]
+    fn test_vm_import_stubs() {
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (import "env" "rare" (func $rare (param i32) (result i32)))
+            (import "env" "offset" (global $offset i32))
+            (import "env" "memory" (memory 1))
+            (func (export "probe") (result i32)
+                (i32.add (global.get $offset) (i32.load (i32.const 0))))
+            (func (export "call_rare") (result i32)
+                (call $rare (i32.const 1)))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        // the module cannot be registered without stubs
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        let result = vm.register_module(None, module.clone());
+        assert!(result.is_err());
+
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.set_import_stubs(ImportStubs::all());
+        let result = vm.register_module(None, module);
+        assert!(result.is_ok());
+
+        // the stub global and memory are zero-valued
+        let result = vm.run_func(None, "probe", params!());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 0);
+
+        // the stub function traps with the names of the import
+        let result = vm.run_func(None, "call_rare", params!());
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::StubCalled {
+                module_name: String::from("env"),
+                name: String::from("rare"),
+            })
+        );
+
+        // the items missing from the namespace created for the stubs are added to it
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (import "env" "rare" (func $rare (param i32) (result i32)))
+            (import "env" "rarer" (func $rarer (result i32)))
+            (func (export "call_rarer") (result i32)
+                (call $rarer))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(Some("rarer"), module).unwrap();
+        let result = vm.run_func(Some("rarer"), "call_rarer", params!());
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::StubCalled {
+                module_name: String::from("env"),
+                name: String::from("rarer"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_vm_statistics() {
+        use crate::config::{CommonConfigOptions, ConfigBuilder, StatisticsConfigOptions};
+
+        let config = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_statistics_config(
+                StatisticsConfigOptions::default()
+                    .count_instructions(true)
+                    .measure_cost(true)
+                    .measure_time(true),
+            )
+            .build()
+            .unwrap();
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (func (export "sum") (param $n i32) (result i32) (local $acc i32)
+                (block $done
+                    (loop $next
+                        (br_if $done (i32.eqz (local.get $n)))
+                        (local.set $acc (i32.add (local.get $acc) (local.get $n)))
+                        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                        (br $next)))
+                (local.get $acc))
+        )
+"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(Some(&config), wasm_bytes).unwrap();
+
+        // a vm without statistics cannot measure a call
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        assert!(vm.statistics().is_none());
+        vm.register_module(None, module.clone()).unwrap();
+        assert!(vm
+            .run_func_with_statistics(None, "sum", params!(10))
+            .is_err());
+
+        // a store collecting statistics requires the statistics options
+        assert!(Store::with_statistics(
+            None,
+            HashMap::<String, &mut dyn SyncInst>::new(),
+            Statistics::new().unwrap(),
+        )
+        .is_err());
+
+        let store = Store::with_statistics(
+            Some(&config),
+            HashMap::<String, &mut dyn SyncInst>::new(),
+            Statistics::new().unwrap(),
+        )
+        .unwrap();
+        let mut vm = Vm::new(store);
+        vm.register_module(None, module.clone()).unwrap();
+
+        // the statistics of a single call are the delta of the total
+        let (returns, small) = vm
+            .run_func_with_statistics(None, "sum", params!(10))
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 55);
+        assert!(small.count() > 0);
+        assert!(small.cost() > 0);
+        let (_, large) = vm
+            .run_func_with_statistics(None, "sum", params!(1000))
+            .unwrap();
+        assert!(large.count() > small.count());
+        assert_eq!(
+            vm.statistics().unwrap().count(),
+            small.count() + large.count()
+        );
+
+        // clear the statistics
+        vm.statistics_mut().unwrap().clear();
+        assert_eq!(vm.statistics().unwrap().count(), 0);
+
+        // the calls in an instance of a handle are measured the same way
+        let handle = vm.instantiate(&module).unwrap();
+        let (returns, stats) = vm
+            .run_func_in_with_statistics(handle, "sum", params!(10))
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 55);
+        assert_eq!(stats.count(), small.count());
+    }
+
+    #[test]
+    fn test_vm_host_func_cost() {
+        use crate::{
+            config::{CommonConfigOptions, ConfigBuilder, StatisticsConfigOptions},
+            error::{CoreCommonError, CoreError},
+            CallingFrame, CostTable, ImportObjectBuilder,
+        };
+
+        fn read(
+            _data: &mut (),
+            _inst: &mut Instance,
+            frame: &mut CallingFrame,
+            inputs: Vec<WasmValue>,
+        ) -> Result<Vec<WasmValue>, CoreError> {
+            // charge the cost proportional to the amount of work
+            let stat =
+                Statistics::from_calling_frame(frame).expect("the store collects statistics");
+            stat.charge(inputs[0].to_i32() as u64)?;
+            Ok(vec![])
+        }
+
+        let config = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_statistics_config(StatisticsConfigOptions::default().measure_cost(true))
+            .build()
+            .unwrap();
+        let module = Module::from_bytes(
+            Some(&config),
+            wat2wasm(
+                br#"
+        (module
+            (import "host" "read" (func $read (param i32)))
+            (func (export "run") (param i32)
+                (call $read (local.get 0)))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        // the instructions cost nothing, so only the costs of the host function are counted
+        let mut stat = Statistics::new().unwrap();
+        stat.set_cost_table(CostTable::new().with_default(0).to_vec().unwrap());
+        let mut builder = ImportObjectBuilder::new("host", ()).unwrap();
+        builder
+            .with_func_and_cost::<i32, ()>("read", read, 100)
+            .unwrap();
+        let mut import_object = builder.build();
+        let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+        instances.insert(import_object.name().unwrap(), &mut import_object);
+        let store = Store::with_statistics(Some(&config), instances, stat.clone()).unwrap();
+        let mut vm = Vm::new(store);
+        vm.register_module(None, module).unwrap();
+
+        // both the static and the charged cost are counted
+        let (_, delta) = vm
+            .run_func_with_statistics(None, "run", params!(50))
+            .unwrap();
+        assert_eq!(delta.cost(), 150);
+        assert_eq!(stat.charged_cost(), 50);
+
+        // the charged cost is counted against the cost limit
+        stat.clear();
+        stat.set_cost_limit(1000);
+        let result = vm.run_func(None, "run", params!(5000));
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Common(CoreCommonError::CostLimitExceeded))
+        );
+        assert_eq!(stat.charged_cost(), 0);
+    }
+
+    #[test]
+    fn test_vm_resource_limiter() {
+        use crate::{
+            error::{MemError, TableError},
+            StoreLimits,
+        };
+
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (memory (export "memory") 1 10)
+            (table (export "table") 2 4 funcref)
+            (func (export "grow") (param i32) (result i32)
+                (memory.grow (local.get 0)))
+        )
+"#,
+        )
+        .unwrap();
+
+        let store = Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap();
+        let mut vm = Vm::new(store);
+        vm.store_mut().set_resource_limiter(
+            StoreLimits::new()
+                .with_memory_size(4 * 65536)
+                .with_memories_total(6 * 65536)
+                .with_table_elements(4),
+        );
+
+        // the module instance is allowed to allocate its initial memory
+        vm.register_module(None, Module::from_bytes(None, &wasm_bytes).unwrap())
+            .unwrap();
+        assert_eq!(vm.memory_usage(), 65536);
+
+        // a single memory is limited to 4 pages, also for the wasm code
+        let result = vm.run_func(None, "grow", params!(4)).unwrap();
+        assert_eq!(result[0].to_i32(), -1);
+        assert_eq!(vm.grow_memory(None, "memory", 3).unwrap(), 1);
+        let result = vm.grow_memory(None, "memory", 1);
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::GrowDenied)
+        );
+        let result = vm.run_func(None, "grow", params!(1)).unwrap();
+        assert_eq!(result[0].to_i32(), -1);
+        assert_eq!(vm.memory_usage(), 4 * 65536);
+
+        // the memories of the vm are limited to 6 pages in total
+        vm.register_module(
+            Some("named"),
+            Module::from_bytes(None, &wasm_bytes).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(vm.store().memory_usage(), 65536);
+        assert_eq!(vm.memory_usage(), 5 * 65536);
+        assert_eq!(vm.grow_memory(Some("named"), "memory", 1).unwrap(), 1);
+        let result = vm.grow_memory(Some("named"), "memory", 1);
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::GrowDenied)
+        );
+
+        // the memory of a later module is capped at the rest of the total limit
+        let result = vm.run_func(Some("named"), "grow", params!(1)).unwrap();
+        assert_eq!(result[0].to_i32(), -1);
+        assert_eq!(vm.memory_usage(), 6 * 65536);
+
+        // the instances created by handle are counted as well
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        let result = vm.instantiate(&module);
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::GrowDenied)
+        );
+
+        // the tables are limited to 4 elements
+        assert_eq!(vm.grow_table(None, "table", 2).unwrap(), 2);
+        let result = vm.grow_table(None, "table", 1);
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Table(TableError::GrowDenied)
+        );
+
+        // a module whose initial memory exceeds the limit fails to instantiate
+        vm.store_mut()
+            .set_resource_limiter(StoreLimits::new().with_memory_size(0));
+        let result = vm.register_module(
+            Some("denied"),
+            Module::from_bytes(None, &wasm_bytes).unwrap(),
+        );
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::GrowDenied)
+        );
+        assert!(!vm.contains_module("denied"));
+
+        // the tables are checked with their initial size, whatever their maximum
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.store_mut()
+            .set_resource_limiter(StoreLimits::new().with_table_elements(4));
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(br#"(module (table (export "table") 2 funcref))"#).unwrap(),
+        )
+        .unwrap();
+        vm.register_module(None, module).unwrap();
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(br#"(module (table (export "table") 5 funcref))"#).unwrap(),
+        )
+        .unwrap();
+        let result = vm.register_module(None, module);
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Table(TableError::GrowDenied)
+        );
+
+        // an unexported memory is counted with its initial size
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.store_mut()
+            .set_resource_limiter(StoreLimits::new().with_memories_total(4 * 65536));
+        let module = Module::from_bytes(
+            None,
+            wat2wasm(
+                br#"
+        (module
+            (memory 1)
+            (func (export "grow") (param i32) (result i32)
+                (memory.grow (local.get 0)))
+        )
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        vm.register_module(Some("hidden"), module.clone()).unwrap();
+        assert_eq!(vm.memory_usage(), 65536);
+
+        // and capped at the rest of the total limit when its module is instantiated
+        let result = vm.run_func(Some("hidden"), "grow", params!(3)).unwrap();
+        assert_eq!(result[0].to_i32(), 1);
+        let result = vm.run_func(Some("hidden"), "grow", params!(1)).unwrap();
+        assert_eq!(result[0].to_i32(), -1);
+        vm.register_module(None, module).unwrap();
+        let result = vm.run_func(None, "grow", params!(3)).unwrap();
+        assert_eq!(result[0].to_i32(), -1);
+        let result = vm.run_func(None, "grow", params!(2)).unwrap();
+        assert_eq!(result[0].to_i32(), 1);
+        assert_eq!(vm.memory_usage(), 2 * 65536);
+    }
+
+    #[test]
+    fn test_vm_replace_module() {
+        let counter = |step: i32| {
+            wat2wasm(
+                format!(
+                    r#"
+            (module
+                (global $count (export "count") (mut i32) (i32.const 0))
+                (func (export "next") (result i32)
+                    (global.set $count (i32.add (global.get $count) (i32.const {step})))
+                    (global.get $count))
+            )
+            "#
+                )
+                .as_bytes(),
+            )
+            .unwrap()
+        };
+
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.register_module(
+            Some("counter"),
+            Module::from_bytes(None, counter(1)).unwrap(),
+        )
+        .unwrap();
+        vm.run_func(Some("counter"), "next", params!()).unwrap();
+        vm.run_func(Some("counter"), "next", params!()).unwrap();
+
+        // a failed migration keeps the old instance
+        let result = vm.replace_module(
+            "counter",
+            Module::from_bytes(None, counter(10)).unwrap(),
+            |_old, _new| Err(Box::new(WasmEdgeError::Operation(String::from("fail")))),
+        );
+        assert!(result.is_err());
+        let returns = vm.run_func(Some("counter"), "next", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+
+        // the state is migrated to the new instance
+        vm.replace_module(
+            "counter",
+            Module::from_bytes(None, counter(10)).unwrap(),
+            |old, new| {
+                let count = old.get_global("count")?.get_value();
+                new.get_global_mut("count")?.set_value(count)
+            },
+        )
+        .unwrap();
+        let returns = vm.run_func(Some("counter"), "next", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 13);
+
+        // a module instance which is not found cannot be replaced
+        let result = vm.replace_module(
+            "not_found",
+            Module::from_bytes(None, counter(1)).unwrap(),
+            |_, _| Ok(()),
+        );
+        assert!(result.is_err());
+
+        // a module registered later imports from the new instance
+        let importer = wat2wasm(
+            br#"
+            (module
+                (import "counter" "next" (func $next (result i32)))
+                (func (export "twice") (result i32) (drop (call $next)) (call $next))
+            )
+            "#,
+        )
+        .unwrap();
+        vm.register_module(
+            Some("importer"),
+            Module::from_bytes(None, &importer).unwrap(),
+        )
+        .unwrap();
+        let returns = vm.run_func(Some("importer"), "twice", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 33);
+
+        // a module instance with dependents cannot be replaced
+        let result = vm.replace_module(
+            "counter",
+            Module::from_bytes(None, counter(1)).unwrap(),
+            |_, _| Ok(()),
+        );
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::InUse {
+                name: String::from("counter"),
+                dependents: vec![String::from("importer")],
+            })
+        );
+        vm.register_module(None, Module::from_bytes(None, &importer).unwrap())
+            .unwrap();
+        vm.unregister_module("importer").unwrap();
+        let result = vm.replace_module(
+            "counter",
+            Module::from_bytes(None, counter(1)).unwrap(),
+            |_, _| Ok(()),
+        );
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::InUse {
+                name: String::from("counter"),
+                dependents: vec![String::from("(active module)")],
+            })
+        );
+        assert_eq!(vm.named_instance_count(), 1);
+    }
+
+    #[test]
+    fn test_vm_replace_reloadable_module() {
+        let counter = |step: i32| {
+            wat2wasm(
+                format!(
+                    r#"
+            (module
+                (global $count (export "count") (mut i32) (i32.const 0))
+                (func (export "next") (result i32)
+                    (global.set $count (i32.add (global.get $count) (i32.const {step})))
+                    (global.get $count))
+            )
+            "#
+                )
+                .as_bytes(),
+            )
+            .unwrap()
+        };
+        let importer = wat2wasm(
+            br#"
+            (module
+                (import "counter" "next" (func $next (result i32)))
+                (func (export "twice") (result i32) (drop (call $next)) (call $next))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.register_reloadable_module("counter", Module::from_bytes(None, counter(1)).unwrap())
+            .unwrap()
+            .register_module(
+                Some("importer"),
+                Module::from_bytes(None, &importer).unwrap(),
+            )
+            .unwrap()
+            .register_module(None, Module::from_bytes(None, &importer).unwrap())
+            .unwrap();
+        assert!(vm.store().is_reloadable("counter"));
+        let returns = vm.run_func(Some("importer"), "twice", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // the dependents call the new instance, which continues from the migrated state
+        vm.replace_module(
+            "counter",
+            Module::from_bytes(None, counter(10)).unwrap(),
+            |old, new| {
+                let count = old.get_global("count")?.get_value();
+                new.get_global_mut("count")?.set_value(count)
+            },
+        )
+        .unwrap();
+        let returns = vm.run_func(Some("importer"), "twice", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 22);
+        let returns = vm.run_func(None, "twice", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 42);
+        let returns = vm.run_func(Some("counter"), "next", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 52);
+
+        // the new module must export the functions the dependents may import
+        let result = vm.replace_module(
+            "counter",
+            Module::from_bytes(
+                None,
+                wat2wasm(br#"(module (func (export "next") (result i64) (i64.const 0)))"#).unwrap(),
+            )
+            .unwrap(),
+            |_, _| Ok(()),
+        );
+        assert!(result.is_err());
+        let returns = vm.run_func(Some("importer"), "twice", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 72);
+
+        // a reloadable module instance with dependents cannot be unregistered
+        let result = vm.unregister_module("counter");
+        assert!(result.is_err());
+        vm.unregister_module("importer").unwrap();
+        vm.register_module(None, Module::from_bytes(None, counter(1)).unwrap())
+            .unwrap();
+        vm.unregister_module("counter").unwrap();
+        assert!(!vm.contains_module("counter"));
+        assert!(!vm.store().is_reloadable("counter"));
+    }
+
+    #[test]
+    fn test_vm_unregister_module() {
+        let lib = wat2wasm(
+            br#"
+            (module
+                (memory (export "memory") 1)
+                (func (export "one") (result i32) (i32.const 1))
+            )
+            "#,
+        )
+        .unwrap();
+        let user = wat2wasm(
+            br#"
+            (module
+                (import "lib" "one" (func $one (result i32)))
+                (func (export "two") (result i32) (i32.add (call $one) (call $one)))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.register_module(Some("lib"), Module::from_bytes(None, &lib).unwrap())
+            .unwrap();
+        vm.register_module(Some("user"), Module::from_bytes(None, &user).unwrap())
+            .unwrap();
+        vm.register_module(None, Module::from_bytes(None, &user).unwrap())
+            .unwrap();
+        assert_eq!(vm.store().dependents("lib"), ["user"]);
+        assert_eq!(vm.memory_usage(), 65536);
+
+        // the module instance imported by the others cannot be unregistered
+        let result = vm.unregister_module("lib");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::InUse {
+                name: String::from("lib"),
+                dependents: vec![String::from("(active module)"), String::from("user")],
+            })
+        );
+
+        // unregister the dependents first
+        vm.register_module(None, Module::from_bytes(None, &lib).unwrap())
+            .unwrap();
+        vm.unregister_module("user").unwrap();
+        assert!(!vm.contains_module("user"));
+        assert!(vm.store().dependents("lib").is_empty());
+        assert_eq!(vm.memory_usage(), 2 * 65536);
+
+        vm.unregister_module("lib").unwrap();
+        assert!(!vm.contains_module("lib"));
+        assert_eq!(vm.memory_usage(), 65536);
+
+        // the name can be registered again
+        vm.register_module(Some("lib"), Module::from_bytes(None, &lib).unwrap())
+            .unwrap();
+        assert!(vm.contains_module("lib"));
+
+        let result = vm.unregister_module("not_found");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::NotFoundModule(String::from("not_found")))
+        );
+    }
+
+    #[test]
+    fn test_vm_instantiate() {
+        let lib = wat2wasm(
+            br#"
+            (module
+                (func (export "one") (result i32) (i32.const 1))
+            )
+            "#,
+        )
+        .unwrap();
+        let counter = wat2wasm(
+            br#"
+            (module
+                (import "lib" "one" (func $one (result i32)))
+                (memory (export "memory") 1)
+                (global $count (mut i32) (i32.const 0))
+                (func (export "incr") (result i32)
+                    (global.set $count (i32.add (global.get $count) (call $one)))
+                    (global.get $count))
+            )
+            "#,
+        )
+        .unwrap();
+
+        let mut vm =
+            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        vm.register_module(Some("lib"), Module::from_bytes(None, &lib).unwrap())
+            .unwrap();
+        let module = Module::from_bytes(None, &counter).unwrap();
+        let first = vm.instantiate(&module).unwrap();
+        let second = vm.instantiate(&module).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(vm.instance_handles(), [first, second]);
+        assert_eq!(vm.memory_usage(), 2 * 65536);
+
+        // the instances have independent states
+        for expected in 1..=3 {
+            let returns = vm.run_func_in(first, "incr", []).unwrap();
+            assert_eq!(returns[0].to_i32(), expected);
+        }
+        let returns = vm.run_func_in(second, "incr", []).unwrap();
+        assert_eq!(returns[0].to_i32(), 1);
+        assert!(vm.instance(first).unwrap().get_memory_ref("memory").is_ok());
+
+        // the imported module cannot be unregistered while the instances are alive
+        let result = vm.unregister_module("lib");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::InUse {
+                name: String::from("lib"),
+                dependents: vec![format!("({first})"), format!("({second})")],
+            })
+        );
+
+        // a dropped handle is invalid
+        vm.drop_instance(first).unwrap();
+        assert!(vm.instance(first).is_none());
+        assert_eq!(vm.instance_handles(), [second]);
+        assert_eq!(vm.memory_usage(), 65536);
+        let result = vm.run_func_in(first, "incr", []);
+        assert!(result.is_err());
+        let result = vm.drop_instance(first);
+        assert!(result.is_err());
+
+        let returns = vm.run_func_in(second, "incr", []).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+        #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+        {
+            let returns = vm
+                .run_func_in_with_timeout(second, "incr", [], std::time::Duration::from_secs(10))
+                .unwrap();
+            assert_eq!(returns[0].to_i32(), 3);
+        }
+        assert!(vm.run_func_in_with_statistics(second, "incr", []).is_err());
+
+        vm.drop_instance(second).unwrap();
+        vm.unregister_module("lib").unwrap();
+    }
 }